@@ -1,5 +1,6 @@
 #[cfg(feature = "config")]
 mod parser;
+mod syntax;
 #[cfg(feature = "config")]
 mod tokenizer;
 
@@ -15,6 +16,7 @@ use proc_macro2::Span;
 #[cfg(feature = "config")]
 use syn::LitStr;
 
+pub(crate) use self::syntax::{Syntax, SyntaxOverrides};
 #[cfg(feature = "config")]
 use self::tokenizer::TokenKind;
 #[cfg(feature = "config")]
@@ -89,6 +91,7 @@ pub fn config_path() -> PathBuf {
 
 /// Macro configuration.
 #[cfg_attr(not(feature = "_unreachable"), derive(Default))]
+#[derive(Clone)]
 pub(crate) struct Config {
     /// The escaper group to use
     /// when one cannot be inferred from the template's file extension.
@@ -111,6 +114,21 @@ pub(crate) struct Config {
 
     #[cfg_attr(not(feature = "_oxiplate"), allow(dead_code))]
     pub(crate) optimized_renderer: OptimizedRenderer,
+
+    /// Whether to collapse whitespace in the static text
+    /// of templates escaped for HTML.
+    pub(crate) minify_html: bool,
+
+    /// How to report escapers that don't match the HTML context of their writ.
+    pub(crate) escaper_mismatch: LintLevel,
+
+    /// Delimiters used to find tags in templates.
+    pub(crate) syntax: Syntax,
+
+    /// Delimiters to use instead of `syntax`
+    /// for templates in an escaper group,
+    /// with the key being the name of the group.
+    pub(crate) escaper_group_syntax: HashMap<String, SyntaxOverrides>,
 }
 
 #[cfg(feature = "_unreachable")]
@@ -122,6 +140,10 @@ impl Default for Config {
             require_specifying_escaper: Default::default(),
             infer_escaper_group_from_file_extension: InferEscaperGroupFromFileExtension::default(),
             optimized_renderer: OptimizedRenderer::default(),
+            minify_html: Default::default(),
+            escaper_mismatch: LintLevel::default(),
+            syntax: Syntax::default(),
+            escaper_group_syntax: HashMap::default(),
         }
     }
 }
@@ -133,6 +155,7 @@ pub(crate) struct EscaperGroup {
     pub(crate) escaper: String,
 }
 
+#[derive(Clone)]
 pub(crate) struct InferEscaperGroupFromFileExtension(bool);
 
 impl Default for InferEscaperGroupFromFileExtension {
@@ -149,6 +172,31 @@ impl Deref for InferEscaperGroupFromFileExtension {
     }
 }
 
+/// How a lint is reported.
+/// Proc macros can't emit warnings on stable Rust,
+/// so issues are either ignored or reported as errors.
+#[derive(Clone, Copy, Default)]
+pub(crate) enum LintLevel {
+    /// Ignore the issue.
+    Allow,
+
+    /// Report the issue as an error.
+    #[default]
+    Deny,
+}
+
+impl LintLevel {
+    /// Parse a level like `"deny"` from `/oxiplate.toml`.
+    #[cfg(feature = "config")]
+    fn parse(level: &str) -> Option<Self> {
+        match level {
+            "allow" => Some(Self::Allow),
+            "deny" => Some(Self::Deny),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct OptimizedRenderer(bool);
 
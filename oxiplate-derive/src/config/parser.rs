@@ -2,7 +2,10 @@ use std::collections::HashMap;
 use std::mem;
 
 use crate::config::tokenizer::TokenKind;
-use crate::config::{Config, EscaperGroup, InferEscaperGroupFromFileExtension, Token, TokenSlice};
+use crate::config::{
+    Config, EscaperGroup, InferEscaperGroupFromFileExtension, LintLevel, Syntax, SyntaxOverrides,
+    Token, TokenSlice,
+};
 use crate::parser::{Error, Parser as _, alt, cut, into, many0, opt, parse_all, take};
 use crate::{OptimizedRenderer, Source};
 
@@ -74,6 +77,31 @@ pub fn parse(tokens: TokenSlice) -> Res<Config> {
         Bool,
         "String value not allowed for `optimized_renderer`",
     );
+    set_field!(
+        "minify_html",
+        minify_html,
+        Bool,
+        "String value not allowed for `minify_html`",
+    );
+
+    if let Some(level) = data.remove("escaper_mismatch") {
+        let level = match level {
+            TableOrValue::Value(Value::String(level)) => LintLevel::parse(level.value),
+            _ => None,
+        };
+        let Some(level) = level else {
+            return Err(Error::unrecoverable(
+                "Expected `escaper_mismatch` to be `\"allow\"` or `\"deny\"`".to_string(),
+                source.unwrap_or_else(|| tokens.eof().source().clone()),
+            ));
+        };
+        config.escaper_mismatch = level;
+    }
+
+    if let Some(syntax) = data.remove("syntax") {
+        let overrides = parse_syntax(syntax, "syntax", &tokens)?;
+        config.syntax = Syntax::default().with_overrides(&overrides);
+    }
 
     if let Some(escaper_groups) = data.remove("escaper_groups") {
         parse_escaper_groups(escaper_groups, &tokens, &mut config)?;
@@ -111,6 +139,56 @@ fn check_table_for_invalid_keys<'a>(
     }
 }
 
+fn parse_syntax<'a>(
+    syntax: TableOrValue<'a>,
+    name: &str,
+    tokens: &TokenSlice<'a>,
+) -> Result<SyntaxOverrides, Error<'a>> {
+    let TableOrValue::Table(syntax) = syntax else {
+        return Err(Error::unrecoverable(
+            format!("Expected `{name}` to be a table, found a value"),
+            tokens.eof().source().clone(),
+        ));
+    };
+
+    let mut overrides = SyntaxOverrides::default();
+    for (kind, value) in syntax {
+        if let TableOrValue::Value(Value::Bool(value)) = value {
+            overrides
+                .set_whitespace(kind, value.value)
+                .map_err(|message| Error::unrecoverable(message, value.source.clone()))?;
+            continue;
+        }
+
+        let TableOrValue::Value(Value::Array(array)) = value else {
+            return Err(Error::unrecoverable(
+                format!(
+                    "Expected `{name}.{kind}` to be an array of start and end delimiters (e.g., \
+                     `[\"{{{{\", \"}}}}\"]`)"
+                ),
+                tokens.eof().source().clone(),
+            ));
+        };
+
+        let mut pair = Vec::with_capacity(array.values.len());
+        for value in &array.values {
+            let Value::String(value) = value else {
+                return Err(Error::unrecoverable(
+                    format!("Expected `{name}.{kind}` delimiters to be strings"),
+                    value.source().clone(),
+                ));
+            };
+            pair.push(value.value);
+        }
+
+        overrides
+            .set(kind, &pair)
+            .map_err(|message| Error::unrecoverable(message, array.source.clone()))?;
+    }
+
+    Ok(overrides)
+}
+
 fn parse_escaper_groups<'a>(
     escaper_groups: TableOrValue<'a>,
     tokens: &TokenSlice<'a>,
@@ -142,6 +220,17 @@ fn parse_escaper_groups<'a>(
             ));
         };
 
+        if let Some(syntax) = value.remove("syntax") {
+            let overrides = parse_syntax(
+                syntax,
+                &format!("escaper_groups.{escaper_group}.syntax"),
+                tokens,
+            )?;
+            config
+                .escaper_group_syntax
+                .insert(escaper_group.to_string(), overrides);
+        }
+
         if let Some(escaper) = value.remove("escaper") {
             let path = match escaper {
                 TableOrValue::Table(_) => {
@@ -164,6 +253,15 @@ fn parse_escaper_groups<'a>(
                     ));
                 }
                 TableOrValue::Value(Value::String(ref value)) => value.value,
+                TableOrValue::Value(Value::Array(_)) => {
+                    return Err(Error::unrecoverable(
+                        format!(
+                            "`escaper_groups.{escaper_group}.escaper` should be a path to an \
+                             escaper enum (e.g., `::your_package::Escaper`; array found"
+                        ),
+                        tokens.eof().source().clone(),
+                    ));
+                }
             };
             if !path.starts_with("::") {
                 return Err(Error::unrecoverable(
@@ -377,7 +475,7 @@ impl<'a> Item<'a> {
             many0((into(string), take(TokenKind::DotSeparator))),
             into(string),
             cut("`=` expected after key", take(TokenKind::Equal)),
-            cut("Boolean or string value expected after `=`", value),
+            cut("Boolean, string, or array value expected after `=`", value),
             opt(take(TokenKind::Comment)),
             cut(
                 "Newline or end of file expected after expression",
@@ -469,6 +567,50 @@ fn string(tokens: TokenSlice) -> Res<StringValue> {
 }
 
 fn value(tokens: TokenSlice) -> Res<Value> {
+    alt((array, scalar)).parse(tokens)
+}
+
+fn array(tokens: TokenSlice) -> Res<Value> {
+    let (tokens, (open_bracket, first_value, remaining_values, trailing_comma, close_bracket)) = (
+        take(TokenKind::BracketOpen),
+        opt(scalar),
+        many0((take(TokenKind::Comma), scalar)),
+        opt(take(TokenKind::Comma)),
+        cut(
+            "`]` expected after array values",
+            take(TokenKind::BracketClose),
+        ),
+    )
+        .parse(tokens)?;
+
+    let mut source = open_bracket.source().clone();
+    let mut values = Vec::with_capacity(remaining_values.len() + 1);
+    if let Some(first_value) = first_value {
+        source = source.merge(first_value.source(), "Value expected after `[`");
+        values.push(first_value);
+    } else if !remaining_values.is_empty() {
+        return Err(Error::unrecoverable(
+            "Value expected before `,`".to_string(),
+            remaining_values[0].0.source().clone(),
+        ));
+    }
+    for (comma, value) in remaining_values {
+        source = source
+            .merge(comma.source(), "`,` expected after value")
+            .merge(value.source(), "Value expected after `,`");
+        values.push(value);
+    }
+    let source = source
+        .merge_some(
+            trailing_comma.map(Token::source),
+            "`,` expected after value",
+        )
+        .merge(close_bracket.source(), "`]` expected after values");
+
+    Ok((tokens, Value::Array(ArrayValue { values, source })))
+}
+
+fn scalar(tokens: TokenSlice) -> Res<Value> {
     let (tokens, token) = tokens.take()?;
 
     let value = match token.kind() {
@@ -532,19 +674,27 @@ impl<'a> Key<'a> {
 
 #[derive(Debug)]
 enum Value<'a> {
+    Array(ArrayValue<'a>),
     Bool(BoolValue<'a>),
     String(StringValue<'a>),
 }
 
 impl<'a> Value<'a> {
-    pub fn source(&self) -> &'a Source<'a> {
+    pub fn source(&self) -> &Source<'a> {
         match self {
+            Self::Array(array_value) => &array_value.source,
             Self::Bool(bool_value) => bool_value.source(),
             Self::String(string_value) => string_value.source(),
         }
     }
 }
 
+#[derive(Debug)]
+struct ArrayValue<'a> {
+    values: Vec<Value<'a>>,
+    source: Source<'a>,
+}
+
 #[derive(Debug)]
 struct StringValue<'a> {
     value: &'a str,
@@ -0,0 +1,235 @@
+use crate::template::{TagKind, WhitespacePreference};
+
+/// Start and end delimiters for a kind of tag.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Delimiters {
+    pub(crate) start: String,
+    pub(crate) end: String,
+}
+
+impl Delimiters {
+    fn new(start: &str, end: &str) -> Self {
+        Self {
+            start: start.to_string(),
+            end: end.to_string(),
+        }
+    }
+
+    /// Build the delimiters from a `["start", "end"]` pair,
+    /// making sure they can be used to find tags.
+    pub(crate) fn try_from_pair(kind: &str, pair: &[&str]) -> Result<Self, String> {
+        let [start, end] = pair else {
+            return Err(format!(
+                "Expected `{kind}` delimiters to be a start and end pair like `[\"{{{{\", \
+                 \"}}}}\"]`"
+            ));
+        };
+
+        for delimiter in [start, end] {
+            if delimiter.is_empty() {
+                return Err(format!("`{kind}` delimiters cannot be empty"));
+            }
+
+            if delimiter.chars().any(char::is_whitespace) {
+                return Err(format!(
+                    "`{kind}` delimiter `{delimiter}` cannot contain whitespace"
+                ));
+            }
+        }
+
+        Ok(Self::new(start, end))
+    }
+
+    /// Error message for when the tag isn't closed correctly.
+    pub(crate) fn expected_end(&self) -> String {
+        let end = &self.end;
+        format!("Expected `{end}`, `-{end}`, `_{end}`, or `+{end}`")
+    }
+}
+
+/// Delimiters used to find tags in templates,
+/// along with how whitespace around statement and comment tags
+/// is handled when the tags don't specify it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Syntax {
+    pub(crate) writ: Delimiters,
+    pub(crate) statement: Delimiters,
+    pub(crate) comment: Delimiters,
+
+    /// Whether to remove the line break directly after statement and comment tags.
+    pub(crate) trim_blocks: bool,
+
+    /// Whether to remove the indentation before statement and comment tags
+    /// that start a line.
+    pub(crate) lstrip_blocks: bool,
+}
+
+impl Default for Syntax {
+    fn default() -> Self {
+        Self {
+            writ: Delimiters::new("{{", "}}"),
+            statement: Delimiters::new("{%", "%}"),
+            comment: Delimiters::new("{#", "#}"),
+            trim_blocks: false,
+            lstrip_blocks: false,
+        }
+    }
+}
+
+impl Syntax {
+    /// Get the delimiters for the provided kind of tag.
+    pub(crate) fn delimiters(&self, kind: &TagKind) -> &Delimiters {
+        match kind {
+            TagKind::Writ => &self.writ,
+            TagKind::Statement => &self.statement,
+            TagKind::Comment => &self.comment,
+        }
+    }
+
+    /// Whitespace preference for the start of a tag
+    /// that doesn't have a whitespace control character.
+    pub(crate) fn default_start_preference(&self, kind: &TagKind) -> WhitespacePreference {
+        if self.lstrip_blocks && *kind != TagKind::Writ {
+            WhitespacePreference::TrimLine
+        } else {
+            WhitespacePreference::Indifferent
+        }
+    }
+
+    /// Whitespace preference for the end of a tag
+    /// that doesn't have a whitespace control character.
+    pub(crate) fn default_end_preference(&self, kind: &TagKind) -> WhitespacePreference {
+        if self.trim_blocks && *kind != TagKind::Writ {
+            WhitespacePreference::TrimLine
+        } else {
+            WhitespacePreference::Indifferent
+        }
+    }
+
+    /// Whitespace adjustment tags are built from
+    /// the first character of the writ start delimiter
+    /// and the last character of the writ end delimiter,
+    /// like `{-}` and `{_}` for `{{` and `}}`.
+    pub(crate) fn whitespace_adjustment_tag_chars(&self) -> (char, char) {
+        let start = self.writ.start.chars().next();
+        let end = self.writ.end.chars().next_back();
+
+        let (Some(start), Some(end)) = (start, end) else {
+            unreachable!("Delimiters should never be empty");
+        };
+
+        (start, end)
+    }
+
+    /// Replace any delimiters that were overridden.
+    #[must_use]
+    pub(crate) fn with_overrides(&self, overrides: &SyntaxOverrides) -> Self {
+        Self {
+            writ: overrides.writ.as_ref().unwrap_or(&self.writ).clone(),
+            statement: overrides
+                .statement
+                .as_ref()
+                .unwrap_or(&self.statement)
+                .clone(),
+            comment: overrides.comment.as_ref().unwrap_or(&self.comment).clone(),
+            trim_blocks: overrides.trim_blocks.unwrap_or(self.trim_blocks),
+            lstrip_blocks: overrides.lstrip_blocks.unwrap_or(self.lstrip_blocks),
+        }
+    }
+
+    /// Make sure the start delimiters can be told apart from each other,
+    /// and the end delimiters from the whitespace control characters before them.
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        for (kind, delimiters) in [
+            ("writ", &self.writ),
+            ("statement", &self.statement),
+            ("comment", &self.comment),
+        ] {
+            let end = &delimiters.end;
+            if end.starts_with(['-', '_', '+']) {
+                return Err(format!(
+                    "`{kind}` end delimiter `{end}` cannot start with `-`, `_`, or `+` because \
+                     they control whitespace"
+                ));
+            }
+        }
+
+        let starts = [
+            ("writ", &self.writ.start),
+            ("statement", &self.statement.start),
+            ("comment", &self.comment.start),
+        ];
+
+        for (index, (kind, start)) in starts.iter().enumerate() {
+            for (other_kind, other_start) in &starts[index + 1..] {
+                if start.starts_with(other_start.as_str())
+                    || other_start.starts_with(start.as_str())
+                {
+                    return Err(format!(
+                        "`{kind}` start delimiter `{start}` and `{other_kind}` start delimiter \
+                         `{other_start}` cannot be told apart"
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Delimiters and whitespace handling to use instead of the ones configured more broadly,
+/// like for an escaper group or a single template.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SyntaxOverrides {
+    pub(crate) writ: Option<Delimiters>,
+    pub(crate) statement: Option<Delimiters>,
+    pub(crate) comment: Option<Delimiters>,
+    pub(crate) trim_blocks: Option<bool>,
+    pub(crate) lstrip_blocks: Option<bool>,
+}
+
+impl SyntaxOverrides {
+    /// Set the delimiters for the kind of tag with the provided name.
+    pub(crate) fn set(&mut self, kind: &str, pair: &[&str]) -> Result<(), String> {
+        let delimiters = Delimiters::try_from_pair(kind, pair)?;
+        let field = match kind {
+            "writ" => &mut self.writ,
+            "statement" => &mut self.statement,
+            "comment" => &mut self.comment,
+            _ => {
+                return Err(format!(
+                    "Unknown tag kind `{kind}`; expected `writ`, `statement`, or `comment`"
+                ));
+            }
+        };
+
+        if field.is_some() {
+            return Err(format!("`{kind}` delimiters are already set"));
+        }
+
+        *field = Some(delimiters);
+
+        Ok(())
+    }
+
+    /// Set the whitespace option with the provided name.
+    pub(crate) fn set_whitespace(&mut self, name: &str, value: bool) -> Result<(), String> {
+        let field = match name {
+            "trim_blocks" => &mut self.trim_blocks,
+            "lstrip_blocks" => &mut self.lstrip_blocks,
+            _ => {
+                return Err(format!(
+                    "Unknown whitespace option `{name}`; expected `trim_blocks` or `lstrip_blocks`"
+                ));
+            }
+        };
+
+        if field.is_some() {
+            return Err(format!("`{name}` is already set"));
+        }
+
+        *field = Some(value);
+
+        Ok(())
+    }
+}
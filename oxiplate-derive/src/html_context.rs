@@ -0,0 +1,542 @@
+//! Tracking where writs appear in HTML templates
+//! so the built-in HTML escaper can be chosen automatically.
+//!
+//! The static text around writs is lexed as it's built,
+//! loosely following <https://html.spec.whatwg.org/#tokenization>.
+//! Each branch of a statement starts from the context before the statement,
+//! and all branches need to end in the same context
+//! so the context after the statement is known.
+//! Macros and included templates are built as if they're in text,
+//! so they can only be used in text and need to end there.
+
+/// Path to the built-in HTML escaper group
+/// that writs are contextually escaped for.
+pub(crate) const HTML_ESCAPER: &str = "::oxiplate::escapers::html::HtmlEscaper";
+
+/// Attributes containing URLs,
+/// which can't start with a writ because it could change the URL's scheme.
+const URL_ATTRIBUTES: [&str; 20] = [
+    "action",
+    "archive",
+    "background",
+    "cite",
+    "classid",
+    "codebase",
+    "data",
+    "formaction",
+    "href",
+    "icon",
+    "longdesc",
+    "lowsrc",
+    "manifest",
+    "ping",
+    "poster",
+    "profile",
+    "src",
+    "srcset",
+    "usemap",
+    "xlink:href",
+];
+
+/// Where in an HTML document the next piece of output will be.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub(crate) enum HtmlContext {
+    /// Text between tags.
+    #[default]
+    Text,
+
+    /// Text within `<textarea>` or `<title>`,
+    /// which can contain character references but not tags.
+    EscapableRawText(&'static str),
+
+    /// Text within `<script>` or `<style>`,
+    /// which is output as-is until the element is closed.
+    RawText(&'static str),
+
+    /// Text within `<!-- -->`.
+    Comment,
+
+    /// End tags, doctypes, and other markup that lasts until `>`.
+    Declaration,
+
+    /// Name of a start tag like `<div`.
+    TagName(String),
+
+    /// Within a start tag, but outside of any attribute.
+    Tag(String),
+
+    /// Name of an attribute like `class`.
+    AttributeName(Attribute),
+
+    /// After an attribute's name and before its value or the next attribute.
+    AfterAttributeName(Attribute),
+
+    /// After an attribute's `=` and before its value.
+    BeforeAttributeValue(Attribute),
+
+    /// Value of an attribute.
+    AttributeValue {
+        attribute: Attribute,
+
+        /// Quote wrapping the value, if any.
+        quote: Option<char>,
+
+        /// Whether the value could still be the start of a URL's scheme,
+        /// because static text hasn't output `:`, `/`, `?`, or `#` in it yet.
+        /// Whitespace doesn't count because browsers strip it from URLs,
+        /// and writs don't count because they can output nothing.
+        may_be_scheme: bool,
+    },
+
+    /// Within a start tag after branches that ended at different points in it,
+    /// like `<input{% if disabled %} disabled{% endif %}`.
+    /// Each possible context is lexed until they are the same again.
+    Branched(Vec<HtmlContext>),
+}
+
+/// Attribute currently being lexed and the element it belongs to.
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) struct Attribute {
+    element: String,
+    name: String,
+}
+
+/// Contexts the branches of a statement like `if` or `match` end in,
+/// which need to match so the context after the statement is known.
+pub(crate) struct Branches {
+    /// Context before the statement, which each branch starts from.
+    start: HtmlContext,
+
+    /// Context the branches so far all end in.
+    end: Option<HtmlContext>,
+
+    /// Locations of the first two branches that ended in different contexts.
+    mismatch: Option<(String, String)>,
+}
+
+impl Branches {
+    pub(crate) fn new(start: &HtmlContext) -> Self {
+        Self {
+            start: start.clone(),
+            end: None,
+            mismatch: None,
+        }
+    }
+
+    /// Context to start building the next branch from.
+    pub(crate) fn start(&self) -> HtmlContext {
+        self.start.clone()
+    }
+
+    /// Record the context a branch ended in.
+    pub(crate) fn end(&mut self, context: &HtmlContext) {
+        let Some(end) = &self.end else {
+            self.end = Some(context.clone());
+            return;
+        };
+
+        if let Some(joined) = end.join(context) {
+            self.end = Some(joined);
+        } else if self.mismatch.is_none() {
+            self.mismatch = Some((end.location(), context.location()));
+        }
+    }
+
+    /// Record that the statement may output nothing,
+    /// like an `if` without an `else`.
+    pub(crate) fn end_unchanged(&mut self) {
+        let start = self.start.clone();
+        self.end(&start);
+    }
+
+    /// Context after the statement.
+    ///
+    /// # Errors
+    ///
+    /// Locations of the first two branches that ended in different contexts.
+    pub(crate) fn finish(self) -> Result<HtmlContext, (String, String)> {
+        if let Some(mismatch) = self.mismatch {
+            return Err(mismatch);
+        }
+
+        Ok(self.end.unwrap_or(self.start))
+    }
+}
+
+impl HtmlContext {
+    /// Update the context with static text that will be output.
+    pub(crate) fn push_str(&mut self, text: &str) {
+        if let Self::Branched(contexts) = self {
+            for context in &mut *contexts {
+                context.push_str(text);
+            }
+            *self = Self::merge(std::mem::take(contexts));
+            return;
+        }
+
+        let mut rest = text;
+        while let Some(char) = rest.chars().next() {
+            rest = match self {
+                Self::Text => self.after_text(rest),
+                Self::RawText(element) | Self::EscapableRawText(element) => {
+                    let element = *element;
+                    match find_end_tag(rest, element) {
+                        Some(end) => {
+                            *self = Self::Declaration;
+                            &rest[end..]
+                        }
+                        None => "",
+                    }
+                }
+                Self::Comment => match rest.find("-->") {
+                    Some(end) => {
+                        *self = Self::Text;
+                        &rest[end + 3..]
+                    }
+                    None => "",
+                },
+                _ => {
+                    self.push_tag_char(char);
+                    &rest[char.len_utf8()..]
+                }
+            };
+        }
+    }
+
+    /// Whether this is within a quoted attribute value,
+    /// where whitespace is part of the value.
+    pub(crate) fn is_quoted_attribute_value(&self) -> bool {
+        self.contexts().any(|context| {
+            matches!(
+                context,
+                Self::AttributeValue {
+                    quote: Some(_quote),
+                    ..
+                }
+            )
+        })
+    }
+
+    /// Name of the `HtmlEscaper` variant to escape writs with in this context,
+    /// or `None` for the group's default escaper.
+    ///
+    /// # Errors
+    ///
+    /// If there is no escaper that can safely escape values in this context.
+    pub(crate) fn escaper(&self) -> Result<Option<&'static str>, String> {
+        let (location, reason) = match self {
+            Self::Text | Self::EscapableRawText(_) => return Ok(None),
+            Self::Comment => return Ok(Some("comment")),
+            Self::AttributeValue {
+                attribute,
+                quote: Some(_quote),
+                may_be_scheme,
+            } => {
+                if attribute.name.starts_with("on") {
+                    (
+                        format!("within the `{}` event handler attribute", attribute.name),
+                        "",
+                    )
+                } else if *may_be_scheme && URL_ATTRIBUTES.contains(&attribute.name.as_str()) {
+                    (
+                        format!(
+                            "in the `{}` attribute before any `:`, `/`, `?`, or `#`",
+                            attribute.name
+                        ),
+                        " because the value could change the URL's scheme",
+                    )
+                } else {
+                    return Ok(Some("attr"));
+                }
+            }
+            _ => (self.location(), ""),
+        };
+
+        Err(format!(
+            "Writ is {location}, which has no safe HTML escaper{reason}. Move the writ into text \
+             or a quoted attribute value, or specify an escaper explicitly (e.g., `raw:`) if the \
+             value is known to be safe."
+        ))
+    }
+
+    /// Describe why the `HtmlEscaper` variant obviously doesn't match this context,
+    /// if it doesn't.
+    pub(crate) fn escaper_mismatch(&self, escaper: &str) -> Option<String> {
+        if !matches!(escaper, "text" | "attr" | "comment") {
+            return None;
+        }
+
+        let expected = match self {
+            Self::Text | Self::EscapableRawText(_) => Some("text"),
+            Self::Comment => Some("comment"),
+            Self::AttributeValue {
+                quote: Some(_quote),
+                ..
+            } => Some("attr"),
+            _ => None,
+        };
+
+        let location = self.location();
+        match expected {
+            Some(expected) if expected == escaper => None,
+            Some(expected) => Some(format!(
+                "The `{escaper}` escaper is used {location}, where `{expected}` is expected"
+            )),
+            None => Some(format!(
+                "The `{escaper}` escaper is used {location}, where no HTML escaper is safe. Move \
+                 the writ, or use `raw:` if the value is known to be safe"
+            )),
+        }
+    }
+
+    /// Context that's safe to continue from after either this context or `other`,
+    /// if they only differ in whether an attribute value could be a URL's scheme
+    /// or where they are within the same start tag.
+    fn join(&self, other: &Self) -> Option<Self> {
+        let is_same_value = matches!(
+            (self, other),
+            (
+                Self::AttributeValue { attribute, quote, .. },
+                Self::AttributeValue {
+                    attribute: other_attribute,
+                    quote: other_quote,
+                    ..
+                },
+            ) if attribute == other_attribute && quote == other_quote
+        );
+        let is_same_tag = self
+            .tag_element()
+            .is_some_and(|element| other.tag_element() == Some(element));
+
+        if self == other || is_same_value || is_same_tag {
+            Some(Self::merge(
+                self.contexts().chain(other.contexts()).cloned().collect(),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Combine the possible contexts, keeping each distinct one.
+    /// Attribute values that only differ in whether they could be a URL's scheme
+    /// are combined as if they could, so URL schemes stay protected.
+    fn merge(contexts: Vec<Self>) -> Self {
+        let mut merged: Vec<Self> = Vec::with_capacity(contexts.len());
+        for context in contexts.into_iter().flat_map(|context| match context {
+            Self::Branched(contexts) => contexts,
+            context => vec![context],
+        }) {
+            let existing = merged
+                .iter_mut()
+                .find(|existing| match (&**existing, &context) {
+                    (
+                        Self::AttributeValue {
+                            attribute, quote, ..
+                        },
+                        Self::AttributeValue {
+                            attribute: other_attribute,
+                            quote: other_quote,
+                            ..
+                        },
+                    ) => attribute == other_attribute && quote == other_quote,
+                    (existing, context) => existing == context,
+                });
+            match (existing, context) {
+                (
+                    Some(Self::AttributeValue { may_be_scheme, .. }),
+                    Self::AttributeValue {
+                        may_be_scheme: other_may_be_scheme,
+                        ..
+                    },
+                ) => *may_be_scheme = *may_be_scheme || other_may_be_scheme,
+                (Some(_existing), _context) => (),
+                (None, context) => merged.push(context),
+            }
+        }
+
+        if merged.len() == 1 {
+            merged.remove(0)
+        } else {
+            Self::Branched(merged)
+        }
+    }
+
+    /// Each context this could be.
+    fn contexts(&self) -> std::slice::Iter<'_, Self> {
+        match self {
+            Self::Branched(contexts) => contexts.iter(),
+            context => std::slice::from_ref(context).iter(),
+        }
+    }
+
+    /// Element of the start tag this is within, outside of any attribute value.
+    fn tag_element(&self) -> Option<&str> {
+        match self {
+            Self::TagName(element) | Self::Tag(element) => Some(element),
+            Self::AttributeName(attribute)
+            | Self::AfterAttributeName(attribute)
+            | Self::BeforeAttributeValue(attribute) => Some(&attribute.element),
+            Self::Branched(contexts) => {
+                let element = contexts.first()?.tag_element()?;
+                contexts
+                    .iter()
+                    .all(|context| context.tag_element() == Some(element))
+                    .then_some(element)
+            }
+            _ => None,
+        }
+    }
+
+    /// Where in the document the context is, like "within a tag".
+    pub(crate) fn location(&self) -> String {
+        match self {
+            Self::Text => "within text".to_string(),
+            Self::EscapableRawText(element) | Self::RawText(element) => {
+                format!("within a `<{element}>` element")
+            }
+            Self::Comment => "within a comment".to_string(),
+            Self::AttributeValue {
+                attribute,
+                quote: Some(_quote),
+                ..
+            } => format!("within the quoted `{}` attribute value", attribute.name),
+            Self::AttributeValue { quote: None, .. } | Self::BeforeAttributeValue(_) => {
+                "within an unquoted attribute value".to_string()
+            }
+            Self::Declaration => "within an end tag or markup declaration".to_string(),
+            Self::TagName(_)
+            | Self::Tag(_)
+            | Self::AttributeName(_)
+            | Self::AfterAttributeName(_) => "within a tag".to_string(),
+            Self::Branched(_) => {
+                "after branches that end in different places within a tag".to_string()
+            }
+        }
+    }
+
+    /// Lex text until the start of a tag or comment.
+    fn after_text<'a>(&mut self, text: &'a str) -> &'a str {
+        let Some(start) = text.find('<') else {
+            return "";
+        };
+        let rest = &text[start + 1..];
+        if let Some(rest) = rest.strip_prefix("!--") {
+            *self = Self::Comment;
+            rest
+        } else if rest.starts_with(['!', '?', '/']) {
+            *self = Self::Declaration;
+            rest
+        } else if rest.starts_with(|char: char| char.is_ascii_alphabetic()) {
+            *self = Self::TagName(String::new());
+            rest
+        } else {
+            rest
+        }
+    }
+
+    /// Lex a single character within a tag.
+    fn push_tag_char(&mut self, char: char) {
+        let is_whitespace = char.is_ascii_whitespace();
+        *self = match std::mem::take(self) {
+            Self::Declaration if char == '>' => Self::Text,
+            Self::TagName(element) | Self::Tag(element) if char == '>' => {
+                Self::after_start_tag(&element)
+            }
+            Self::TagName(element) if is_whitespace || char == '/' => Self::Tag(element),
+            Self::TagName(mut element) => {
+                element.push(char.to_ascii_lowercase());
+                Self::TagName(element)
+            }
+            Self::Tag(element) if is_whitespace || char == '/' => Self::Tag(element),
+            Self::Tag(element) => Self::AttributeName(Attribute {
+                element,
+                name: char.to_ascii_lowercase().to_string(),
+            }),
+            Self::AttributeName(attribute)
+            | Self::AfterAttributeName(attribute)
+            | Self::BeforeAttributeValue(attribute)
+                if char == '>' =>
+            {
+                Self::after_start_tag(&attribute.element)
+            }
+            Self::AttributeName(attribute) | Self::AfterAttributeName(attribute) if char == '=' => {
+                Self::BeforeAttributeValue(attribute)
+            }
+            Self::AttributeName(attribute) | Self::AfterAttributeName(attribute) if char == '/' => {
+                Self::Tag(attribute.element)
+            }
+            Self::AttributeName(attribute) if is_whitespace => Self::AfterAttributeName(attribute),
+            Self::AttributeName(mut attribute) => {
+                attribute.name.push(char.to_ascii_lowercase());
+                Self::AttributeName(attribute)
+            }
+            Self::AfterAttributeName(attribute) if is_whitespace => {
+                Self::AfterAttributeName(attribute)
+            }
+            Self::AfterAttributeName(attribute) => Self::AttributeName(Attribute {
+                element: attribute.element,
+                name: char.to_ascii_lowercase().to_string(),
+            }),
+            Self::BeforeAttributeValue(attribute) if is_whitespace => {
+                Self::BeforeAttributeValue(attribute)
+            }
+            Self::BeforeAttributeValue(attribute) => Self::AttributeValue {
+                attribute,
+                quote: matches!(char, '"' | '\'').then_some(char),
+                may_be_scheme: !matches!(char, ':' | '/' | '?' | '#'),
+            },
+            Self::AttributeValue {
+                attribute,
+                quote: Some(quote),
+                ..
+            } if char == quote => Self::Tag(attribute.element),
+            Self::AttributeValue {
+                attribute,
+                quote: None,
+                ..
+            } if char == '>' => Self::after_start_tag(&attribute.element),
+            Self::AttributeValue {
+                attribute,
+                quote: None,
+                ..
+            } if is_whitespace => Self::Tag(attribute.element),
+            Self::AttributeValue {
+                attribute,
+                quote,
+                may_be_scheme,
+            } => Self::AttributeValue {
+                attribute,
+                quote,
+                may_be_scheme: may_be_scheme && !matches!(char, ':' | '/' | '?' | '#'),
+            },
+            context => context,
+        };
+    }
+
+    /// Context for the contents of the element that was just opened.
+    fn after_start_tag(element: &str) -> Self {
+        match element {
+            "script" => Self::RawText("script"),
+            "style" => Self::RawText("style"),
+            "textarea" => Self::EscapableRawText("textarea"),
+            "title" => Self::EscapableRawText("title"),
+            _ => Self::Text,
+        }
+    }
+}
+
+/// Find the position after the `</` of the tag closing the element,
+/// like `</script>`.
+fn find_end_tag(text: &str, element: &str) -> Option<usize> {
+    let lowercase_text = text.to_ascii_lowercase();
+    let tag = format!("</{element}");
+    lowercase_text
+        .match_indices(&tag)
+        .find_map(|(start, _tag)| {
+            lowercase_text[start + tag.len()..]
+                .chars()
+                .next()
+                .is_none_or(|char| char == '>' || char == '/' || char.is_ascii_whitespace())
+                .then_some(start + 2)
+        })
+}
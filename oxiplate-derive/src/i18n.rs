@@ -0,0 +1,423 @@
+//! Message catalogs for translating templates at compile time.
+//!
+//! Catalogs are gettext `.po` files in the `locales` directory
+//! within the template directory, named after their locale (e.g., `locales/fr.po`).
+//! Only `msgid`/`msgstr` pairs are supported;
+//! contexts and plural forms are not.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::PathBuf;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{TokenStreamExt, quote, quote_spanned};
+use syn::parse::{Parse, ParseStream};
+use syn::token::Comma;
+use syn::{Ident, LitStr};
+
+use crate::html_context::Branches;
+use crate::{BuiltTokens, State, parse_key_value, templates_dir};
+
+/// Locale specified on the struct with
+/// `#[oxiplate_locale(locale, default = "en")]`,
+/// along with the catalogs that were found for translating templates.
+#[derive(Clone)]
+pub(crate) struct Locale {
+    /// Field or variable containing the locale to render with.
+    variable: Ident,
+
+    /// Locale to use when the variable doesn't match any catalog.
+    default: String,
+
+    /// Catalogs by locale.
+    catalogs: BTreeMap<String, Catalog>,
+}
+
+/// Translations from a single `.po` file.
+#[derive(Clone)]
+struct Catalog {
+    path: PathBuf,
+
+    /// Translated messages by their message ID.
+    messages: HashMap<String, String>,
+}
+
+/// Piece of a message.
+enum Segment {
+    /// Text that is output as-is.
+    Text(String),
+
+    /// Value that is escaped and output, like `{name}`.
+    Placeholder(String),
+}
+
+impl Parse for Locale {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let variable: Ident = input.parse()?;
+        input.parse::<Comma>()?;
+        let default = parse_key_value(input, "default")?;
+        if !input.is_empty() {
+            input.parse::<Comma>()?;
+        }
+
+        let catalogs = read_catalogs(default.span())?;
+        if !catalogs.contains_key(&default.value()) {
+            return Err(syn::Error::new(
+                default.span(),
+                format!(
+                    "No catalog found for the default locale. Expected `locales/{}.po` in the \
+                     template directory.",
+                    default.value()
+                ),
+            ));
+        }
+
+        Ok(Self {
+            variable,
+            default: default.value(),
+            catalogs,
+        })
+    }
+}
+
+impl Locale {
+    /// Attribute to pass the locale along to included templates.
+    pub(crate) fn to_attribute(&self) -> TokenStream {
+        let variable = &self.variable;
+        let default = LitStr::new(&self.default, variable.span());
+
+        quote! { #[oxiplate_locale(#variable, default = #default)] }
+    }
+
+    /// Build a `match` on the locale
+    /// that outputs the translation of the message for each locale.
+    /// Placeholders are output with `placeholder()`,
+    /// which returns `None` for names that aren't available.
+    pub(crate) fn translate(
+        &self,
+        state: &mut State,
+        id: &str,
+        span: Span,
+        mut placeholder: impl FnMut(&mut State, &str) -> Option<BuiltTokens>,
+    ) -> BuiltTokens {
+        let missing: Vec<&str> = self
+            .catalogs
+            .iter()
+            .filter(|(_locale, catalog)| !catalog.messages.contains_key(id))
+            .map(|(locale, _catalog)| locale.as_str())
+            .collect();
+        if !missing.is_empty() {
+            let message = format!(
+                "Translation for `{id}` is missing from: {}",
+                missing.join(", ")
+            );
+            return (quote_spanned! {span=> compile_error!(#message); }, 0);
+        }
+
+        let mut arms = TokenStream::new();
+        let mut default_arm = TokenStream::new();
+        let mut estimated_length = 0;
+        let mut includes = TokenStream::new();
+        let mut branches = Branches::new(&state.html_context);
+        for (locale, catalog) in &self.catalogs {
+            let path = LitStr::new(&catalog.path.to_string_lossy(), span);
+            includes.append_all(quote_spanned! {span=> const _: &str = include_str!(#path); });
+
+            let segments = match parse_message(&catalog.messages[id]) {
+                Ok(segments) => segments,
+                Err(error) => {
+                    let message = format!("Invalid `{locale}` translation of `{id}`: {error}");
+                    return (quote_spanned! {span=> compile_error!(#message); }, 0);
+                }
+            };
+
+            // Each translation is lexed on its own
+            // so placeholders are escaped for where they are in it.
+            state.html_context = branches.start();
+            let mut tokens = TokenStream::new();
+            let mut locale_length = 0;
+            for segment in segments {
+                match segment {
+                    Segment::Text(text) => {
+                        if state.tracks_html_context() {
+                            state.html_context.push_str(&text);
+                        }
+                        locale_length += text.len();
+                        tokens.append_all(quote_spanned! {span=>
+                            oxiplate_formatter.write_str(#text)?;
+                        });
+                    }
+                    Segment::Placeholder(name) => {
+                        let Some((value, value_length)) = placeholder(state, &name) else {
+                            let message = format!(
+                                "Unknown placeholder `{{{name}}}` in the `{locale}` translation \
+                                 of `{id}`"
+                            );
+                            return (quote_spanned! {span=> compile_error!(#message); }, 0);
+                        };
+                        locale_length += value_length;
+                        tokens.append_all(quote! { #value; });
+                    }
+                }
+            }
+
+            if *locale == self.default {
+                estimated_length = locale_length;
+                default_arm = tokens;
+            } else {
+                arms.append_all(quote_spanned! {span=> #locale => { #tokens } });
+            }
+            branches.end(&state.html_context);
+        }
+
+        match branches.finish() {
+            Ok(context) => state.html_context = context,
+            Err((first, second)) if state.tracks_html_context() => {
+                let message = format!(
+                    "Translations of `{id}` end in different HTML contexts: one ends {first} and \
+                     another {second}. Keep tags, comments, and quoted attribute values balanced \
+                     within each translation so the writs after it can be escaped correctly."
+                );
+                return (quote_spanned! {span=> compile_error!(#message); }, 0);
+            }
+            Err(_mismatch) => (),
+        }
+
+        let variable = &self.variable;
+        let locale = if state.local_variables.contains(&variable.to_string()) {
+            quote! { #variable }
+        } else {
+            quote_spanned! {span=> self.#variable }
+        };
+
+        (
+            quote_spanned! {span=>
+                {
+                    #includes
+                    match ::core::convert::AsRef::<str>::as_ref(&#locale) {
+                        #arms
+                        _ => { #default_arm }
+                    }
+                }
+            },
+            estimated_length,
+        )
+    }
+}
+
+/// Error for translating messages in templates without a locale.
+pub(crate) fn missing_locale_error(span: Span) -> BuiltTokens {
+    (
+        quote_spanned! {span=>
+            compile_error!("Translating messages requires a locale to be specified with `#[oxiplate_locale(...)]`");
+        },
+        0,
+    )
+}
+
+/// Message ID for text that will be translated,
+/// with braces escaped so they aren't treated as placeholders.
+pub(crate) fn escape_message_text(text: &str) -> String {
+    text.replace('{', "{{").replace('}', "}}")
+}
+
+/// Split a message into text and placeholders like `{name}`.
+/// Literal braces are written as `{{` and `}}`.
+fn parse_message(message: &str) -> Result<Vec<Segment>, &'static str> {
+    let mut segments = vec![];
+    let mut text = String::new();
+    let mut chars = message.chars().peekable();
+    while let Some(char) = chars.next() {
+        match char {
+            '{' if chars.next_if_eq(&'{').is_some() => text.push('{'),
+            '}' if chars.next_if_eq(&'}').is_some() => text.push('}'),
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(char @ ('a'..='z' | 'A'..='Z' | '_')) => name.push(char),
+                        Some(char @ '0'..='9') if !name.is_empty() => name.push(char),
+                        Some(_) => return Err("Placeholder names must be valid identifiers"),
+                        None => return Err("Placeholder is never closed with `}`"),
+                    }
+                }
+                if name.is_empty() {
+                    return Err("Placeholder names must be valid identifiers");
+                }
+
+                if !text.is_empty() {
+                    segments.push(Segment::Text(std::mem::take(&mut text)));
+                }
+                segments.push(Segment::Placeholder(name));
+            }
+            '}' => return Err("Unmatched `}`; use `}}` for a literal brace"),
+            char => text.push(char),
+        }
+    }
+
+    if !text.is_empty() {
+        segments.push(Segment::Text(text));
+    }
+
+    Ok(segments)
+}
+
+/// Read all catalogs from the `locales` directory in the template directory.
+fn read_catalogs(span: Span) -> syn::Result<BTreeMap<String, Catalog>> {
+    let Ok(templates_dir) = templates_dir(span) else {
+        return Err(syn::Error::new(
+            span,
+            "Catalogs are read from the `locales` directory in the template directory, but the \
+             template directory could not be found",
+        ));
+    };
+    let locales_dir = templates_dir.join("locales");
+
+    let entries = fs::read_dir(&locales_dir).map_err(|error| {
+        syn::Error::new(
+            span,
+            format!(
+                "Failed to read catalogs from `{}`: {error}",
+                locales_dir.to_string_lossy()
+            ),
+        )
+    })?;
+
+    let mut catalogs = BTreeMap::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|error| syn::Error::new(span, format!("Failed to read catalog: {error}")))?
+            .path();
+        if path.extension().is_none_or(|extension| extension != "po") {
+            continue;
+        }
+        let Some(locale) = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+        else {
+            continue;
+        };
+
+        let code = fs::read_to_string(&path).map_err(|error| {
+            syn::Error::new(
+                span,
+                format!("Failed to read `{}`: {error}", path.to_string_lossy()),
+            )
+        })?;
+        let messages = parse_catalog(&code).map_err(|(line, message)| {
+            syn::Error::new(
+                span,
+                format!("{message} in `{}` on line {line}", path.to_string_lossy()),
+            )
+        })?;
+
+        catalogs.insert(locale, Catalog { path, messages });
+    }
+
+    Ok(catalogs)
+}
+
+/// Which part of a catalog entry strings are currently being added to.
+enum Field {
+    Id,
+    Translation,
+}
+
+/// Parse the `msgid`/`msgstr` pairs from a `.po` file.
+/// The header, untranslated messages, and fuzzy translations are skipped.
+/// Errors include the line number they occurred on.
+fn parse_catalog(code: &str) -> Result<HashMap<String, String>, (usize, &'static str)> {
+    let mut messages = HashMap::new();
+
+    let mut id = String::new();
+    let mut translation = String::new();
+    let mut is_fuzzy = false;
+    let mut field = None;
+
+    let mut finish_entry = |id: &mut String, translation: &mut String, is_fuzzy: &mut bool| {
+        if !id.is_empty() && !translation.is_empty() && !*is_fuzzy {
+            messages.insert(std::mem::take(id), std::mem::take(translation));
+        }
+        id.clear();
+        translation.clear();
+        *is_fuzzy = false;
+    };
+
+    for (index, line) in code.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim();
+
+        let string = if line.is_empty() {
+            finish_entry(&mut id, &mut translation, &mut is_fuzzy);
+            field = None;
+            continue;
+        } else if let Some(flags) = line.strip_prefix("#,") {
+            is_fuzzy |= flags.split(',').any(|flag| flag.trim() == "fuzzy");
+            continue;
+        } else if line.starts_with('#') {
+            continue;
+        } else if line.starts_with("msgctxt") {
+            return Err((line_number, "Message contexts are not supported"));
+        } else if line.starts_with("msgid_plural") || line.starts_with("msgstr[") {
+            return Err((line_number, "Plural forms are not supported"));
+        } else if let Some(string) = line.strip_prefix("msgid ") {
+            if field.is_some() {
+                finish_entry(&mut id, &mut translation, &mut is_fuzzy);
+            }
+            field = Some(Field::Id);
+            string
+        } else if let Some(string) = line.strip_prefix("msgstr ") {
+            field = Some(Field::Translation);
+            string
+        } else if line.starts_with('"') {
+            line
+        } else {
+            return Err((
+                line_number,
+                "Expected `msgid`, `msgstr`, a quoted string, or a comment",
+            ));
+        };
+
+        let string = parse_string(string).map_err(|message| (line_number, message))?;
+        match field {
+            Some(Field::Id) => id.push_str(&string),
+            Some(Field::Translation) => translation.push_str(&string),
+            None => return Err((line_number, "Expected `msgid` or `msgstr` before string")),
+        }
+    }
+
+    finish_entry(&mut id, &mut translation, &mut is_fuzzy);
+
+    Ok(messages)
+}
+
+/// Parse a quoted string from a `.po` file.
+fn parse_string(string: &str) -> Result<String, &'static str> {
+    let Some(string) = string
+        .trim()
+        .strip_prefix('"')
+        .and_then(|string| string.strip_suffix('"'))
+    else {
+        return Err("Expected a string wrapped in `\"`");
+    };
+
+    let mut value = String::with_capacity(string.len());
+    let mut chars = string.chars();
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => match chars.next() {
+                Some('"') => value.push('"'),
+                Some('\\') => value.push('\\'),
+                Some('n') => value.push('\n'),
+                Some('r') => value.push('\r'),
+                Some('t') => value.push('\t'),
+                _ => return Err(r#"Unknown escape; expected `\"`, `\\`, `\n`, `\r`, or `\t`"#),
+            },
+            '"' => return Err(r#"Quotes within strings must be escaped as `\"`"#),
+            char => value.push(char),
+        }
+    }
+
+    Ok(value)
+}
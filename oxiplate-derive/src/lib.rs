@@ -7,7 +7,10 @@
 #![doc = include_str!("../README.md")]
 
 mod config;
+mod html_context;
+mod i18n;
 mod parser;
+mod render;
 mod source;
 mod state;
 mod template;
@@ -21,20 +24,25 @@ use std::path::{Path, PathBuf};
 
 use proc_macro::TokenStream;
 use proc_macro2::Span;
-use quote::{quote, quote_spanned};
+use quote::{ToTokens, TokenStreamExt, quote, quote_spanned};
+use syn::ext::IdentExt;
 use syn::parse::Parse;
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::token::Colon;
+use syn::token::{Colon, Comma, Eq};
 use syn::{
-    Attribute, Data, DeriveInput, Expr, ExprLit, Ident, Lit, LitStr, MetaList, MetaNameValue,
+    Attribute, Data, DataEnum, DeriveInput, Expr, ExprLit, Fields, Ident, Lit, LitBool, LitStr,
+    MetaList, MetaNameValue,
 };
 
-use crate::config::OptimizedRenderer;
+use crate::config::{Config, OptimizedRenderer, SyntaxOverrides};
+use crate::html_context::HtmlContext;
+use crate::i18n::Locale;
 pub(crate) use crate::source::Source;
 use crate::source::SourceOwned;
 pub(crate) use crate::state::State;
-use crate::state::{LocalVariables, build_config};
-use crate::template::{TokenSlice, parse, tokens_and_eof};
+use crate::state::{LocalVariables, MacroDefinition, build_config};
+use crate::template::{TokenSlice, parse, parse_macros, tokens_and_eof};
 
 type BuiltTokens = (proc_macro2::TokenStream, usize);
 
@@ -98,7 +106,15 @@ type BuiltTokens = (proc_macro2::TokenStream, usize);
 /// ```
 #[proc_macro_derive(
     Oxiplate,
-    attributes(oxiplate, oxiplate_inline, oxiplate_extends, oxiplate_include)
+    attributes(
+        oxiplate,
+        oxiplate_inline,
+        oxiplate_extends,
+        oxiplate_include,
+        oxiplate_syntax,
+        oxiplate_error,
+        oxiplate_locale
+    )
 )]
 pub fn oxiplate(input: TokenStream) -> TokenStream {
     #[cfg(feature = "_unreachable")]
@@ -115,60 +131,197 @@ pub fn oxiplate(input: TokenStream) -> TokenStream {
         }
     };
 
-    oxiplate_internal(input, &VecDeque::from([&HashMap::new()])).0
+    oxiplate_internal(
+        input,
+        &LocalVariables::new(),
+        &VecDeque::from([&HashMap::new()]),
+    )
+    .0
 }
 
-/// Internal derive function that allows for block token streams to be passed in.
+/// Builds a value that implements `Render` from an inline template,
+/// without needing a struct.
+///
+/// Values can be passed in as named arguments after the template.
+/// Any other variables used in the template are borrowed from the surrounding scope.
+///
+/// ```ignore
+/// let name = "world";
+/// let greeting = oxiplate::render!(html: "Hello {{ name }}! {{ count }}", count = 19);
+/// assert_eq!(greeting.render()?, "Hello world! 19");
+/// ```
+#[proc_macro]
+pub fn render(input: TokenStream) -> TokenStream {
+    render::render(input)
+}
+
+/// Internal derive function that allows for local variables
+/// and block token streams to be passed in.
 pub(crate) fn oxiplate_internal(
     input: TokenStream,
+    local_variables: &LocalVariables,
     blocks: &VecDeque<&HashMap<&str, (BuiltTokens, Option<BuiltTokens>)>>,
 ) -> (TokenStream, usize) {
     let input = match syn::parse(input) {
         Ok(input) => input,
         Err(err) => return (err.to_compile_error().into(), 0),
     };
-    parse_input(&input, blocks)
+    parse_input(&input, local_variables, blocks)
+}
+
+/// Internal function for `import` statements
+/// that builds the template at the provided path
+/// and returns the macros it defines,
+/// along with any errors encountered while building it.
+pub(crate) fn import_macros(
+    path: &LitStr,
+    state: &State,
+) -> (HashMap<String, MacroDefinition>, proc_macro2::TokenStream) {
+    let span = path.span();
+    let attr: Attribute = syn::parse_quote_spanned! {span=> #[oxiplate_include = #path] };
+
+    // Macros are built with the imported template's own escaper group and syntax,
+    // but access fields from the same struct as the importing template.
+    let blocks = VecDeque::new();
+    let mut import_state = State {
+        error_type: state.error_type.clone(),
+        locale: state.locale.clone(),
+        ..State::new(state.config.clone(), LocalVariables::new(), &blocks)
+    };
+
+    let parsed_tokens = parse_source_tokens(&attr, &TemplateType::Import, &mut import_state);
+    let errors =
+        match process_parsed_tokens(parsed_tokens, &mut import_state, &TemplateType::Import) {
+            Ok((errors, _estimated_length)) => errors,
+            Err(err) => err.to_compile_error(),
+        };
+
+    (import_state.macros, errors)
 }
 
 /// Parses the template information from the attributes
 /// and data information from the associated struct.
-/// Returns the token stream for the `::std::fmt::Display` implementation for the struct.
+/// Returns the token stream for the `::std::fmt::Display` implementation for the struct,
+/// along with render functions for any named templates.
 fn parse_input(
     input: &DeriveInput,
+    local_variables: &LocalVariables,
     blocks: &VecDeque<&HashMap<&str, (BuiltTokens, Option<BuiltTokens>)>>,
 ) -> (TokenStream, usize) {
+    let options = match parse_template_options(&input.attrs) {
+        Ok(options) => options,
+        Err(err) => return (err.to_compile_error().into(), 0),
+    };
+    let error_type = options.error_type.as_ref();
+
+    let (templates, template_type, optimized_renderer): ParsedTemplates =
+        match parse_template_and_data(input, &options, local_variables, blocks) {
+            Ok(data) => data,
+            Err((err, template_type, optimized_renderer)) => (
+                vec![BuiltTemplate {
+                    name: None,
+                    template: err.to_compile_error(),
+                    estimated_length: 0,
+                    escaper_group: None,
+                }],
+                template_type.unwrap_or(TemplateType::Inline),
+                optimized_renderer,
+            ),
+        };
+
+    // Internally, the template is used directly instead of via `Display`/`Render`.
+    if let TemplateType::Extends | TemplateType::Include = template_type {
+        let mut tokens = proc_macro2::TokenStream::new();
+        let mut estimated_length = 0;
+        for BuiltTemplate {
+            template,
+            estimated_length: template_length,
+            ..
+        } in templates
+        {
+            tokens.append_all(template);
+            estimated_length += template_length;
+        }
+        return (tokens.into(), estimated_length);
+    }
+
+    let mut expanded = proc_macro2::TokenStream::new();
+    let mut estimated_length = 0;
+    for template in &templates {
+        if template.name.is_some() {
+            expanded.append_all(build_named_render_fns(input, template, error_type));
+        } else {
+            expanded.append_all(build_impls(
+                input,
+                template,
+                &optimized_renderer,
+                error_type,
+            ));
+            estimated_length = template.estimated_length;
+        }
+    }
+
+    (TokenStream::from(expanded), estimated_length)
+}
+
+/// Builds the `Display` (and `Render`, when available) implementations
+/// for the unnamed template.
+fn build_impls(
+    input: &DeriveInput,
+    BuiltTemplate {
+        template,
+        estimated_length,
+        #[cfg_attr(not(feature = "_oxiplate"), allow(unused_variables))]
+        escaper_group,
+        ..
+    }: &BuiltTemplate,
+    optimized_renderer: &OptimizedRenderer,
+    #[cfg_attr(not(feature = "_oxiplate"), allow(unused_variables))] error_type: Option<
+        &proc_macro2::TokenStream,
+    >,
+) -> proc_macro2::TokenStream {
     let DeriveInput {
         ident, generics, ..
     } = &input;
 
-    let (template, estimated_length, template_type, optimized_renderer): (
-        proc_macro2::TokenStream,
-        usize,
-        TemplateType,
-        OptimizedRenderer,
-    ) = match parse_template_and_data(input, blocks) {
-        Ok(data) => data,
-        Err((err, template_type, optimized_renderer)) => (
-            err.to_compile_error(),
-            0,
-            template_type.unwrap_or(TemplateType::Inline),
-            optimized_renderer,
-        ),
-    };
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
 
-    // Internally, the template is used directly instead of via `Display`/`Render`.
-    if let TemplateType::Extends | TemplateType::Include = template_type {
-        return (template.into(), estimated_length);
+    // Templates with their own error type can only report it through `TryRender`,
+    // so `Display` is built on top of it instead.
+    #[cfg(feature = "_oxiplate")]
+    if let Some(error_type) = error_type {
+        return quote! {
+            impl #impl_generics ::core::fmt::Display for #ident #type_generics #where_clause {
+                fn fmt(&self, oxiplate_formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::oxiplate::TryRender::render_into(self, oxiplate_formatter)
+                        .map_err(|_| ::core::fmt::Error)
+                }
+            }
+            impl #impl_generics ::oxiplate::TryRender for #ident #type_generics #where_clause {
+                type Error = #error_type;
+
+                const ESTIMATED_LENGTH: usize = #estimated_length;
+
+                #[inline]
+                fn render_into<W: ::core::fmt::Write>(&self, oxiplate_formatter: &mut W) -> ::core::result::Result<(), Self::Error> {
+                    extern crate alloc;
+
+                    use ::core::fmt::Write as _;
+                    use ::oxiplate::{ToCowStr as _, UnescapedText as _};
+                    #template
+                    Ok(())
+                }
+            }
+        };
     }
 
-    let where_clause = &generics.where_clause;
-    let expanded = if *optimized_renderer {
+    if **optimized_renderer {
         #[cfg(not(feature = "_oxiplate"))]
         quote! {
             compile_error!(
                 "`optimized_renderer` config option specified in `/oxiplate.toml` is only available when using `oxiplate`. It looks like `oxiplate-derive` is being used directly instead."
             );
-            impl #generics ::core::fmt::Display for #ident #generics #where_clause {
+            impl #impl_generics ::core::fmt::Display for #ident #type_generics #where_clause {
                 fn fmt(&self, oxiplate_formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                     let string = {
                         extern crate alloc;
@@ -184,14 +337,25 @@ fn parse_input(
             }
         }
 
+        #[cfg(feature = "_oxiplate")]
+        let escaped_render = escaper_group
+            .as_ref()
+            .and_then(|escaper_group| syn::parse_str::<syn::Path>(escaper_group).ok())
+            .map(|escaper_group| {
+                quote! {
+                    impl #impl_generics ::oxiplate::EscapedRender<#escaper_group> for #ident #type_generics #where_clause {}
+                }
+            });
+
         #[cfg(feature = "_oxiplate")]
         quote! {
-            impl #generics ::core::fmt::Display for #ident #generics #where_clause {
+            impl #impl_generics ::core::fmt::Display for #ident #type_generics #where_clause {
                 fn fmt(&self, oxiplate_formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                     ::oxiplate::Render::render_into(self, oxiplate_formatter)
                 }
             }
-            impl #generics ::oxiplate::Render for #ident #generics #where_clause {
+            #escaped_render
+            impl #impl_generics ::oxiplate::Render for #ident #type_generics #where_clause {
                 const ESTIMATED_LENGTH: usize = #estimated_length;
 
                 #[inline]
@@ -207,7 +371,7 @@ fn parse_input(
         }
     } else {
         quote! {
-            impl #generics ::core::fmt::Display for #ident #generics #where_clause {
+            impl #impl_generics ::core::fmt::Display for #ident #type_generics #where_clause {
                 fn fmt(&self, oxiplate_formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                     let string = {
                         extern crate alloc;
@@ -222,74 +386,400 @@ fn parse_input(
                 }
             }
         }
+    }
+}
+
+/// Builds the `render_NAME_into()` function for a named template,
+/// along with `render_NAME()` when using `oxiplate`.
+/// They write directly into the writer regardless of `optimized_renderer`,
+/// and the estimated length is only used by `render_NAME()`.
+fn build_named_render_fns(
+    input: &DeriveInput,
+    BuiltTemplate {
+        name,
+        template,
+        #[cfg_attr(not(feature = "_oxiplate"), allow(unused_variables))]
+        estimated_length,
+        ..
+    }: &BuiltTemplate,
+    error_type: Option<&proc_macro2::TokenStream>,
+) -> proc_macro2::TokenStream {
+    let DeriveInput {
+        vis,
+        ident,
+        generics,
+        ..
+    } = &input;
+
+    let Some(name) = name else {
+        unreachable!("Only named templates should have render functions built for them");
     };
+    let span = name.span();
+    let render_into_fn = Ident::new(&format!("render_{}_into", name.value()), span);
 
-    (TokenStream::from(expanded), estimated_length)
+    #[cfg(feature = "_oxiplate")]
+    let uses = quote! {
+        use ::oxiplate::{ToCowStr as _, UnescapedText as _};
+    };
+    #[cfg(not(feature = "_oxiplate"))]
+    let uses = quote! {};
+
+    let error_type = error_type.map_or_else(
+        || quote! { ::core::fmt::Error },
+        |error_type| quote! { #error_type },
+    );
+
+    #[cfg(feature = "_oxiplate")]
+    let render_fn = {
+        let render_fn = Ident::new(&format!("render_{}", name.value()), span);
+        quote_spanned! {span=>
+            /// Render the template into a string.
+            ///
+            /// # Errors
+            ///
+            /// If strings cannot be written to the formatter.
+            #[inline]
+            #vis fn #render_fn(&self) -> ::core::result::Result<::oxiplate::__private::String, #error_type> {
+                let mut string = ::oxiplate::__private::String::with_capacity(#estimated_length);
+                self.#render_into_fn(&mut string)?;
+                Ok(string)
+            }
+        }
+    };
+    #[cfg(not(feature = "_oxiplate"))]
+    let render_fn = quote! {};
+
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        #[allow(dead_code)]
+        impl #impl_generics #ident #type_generics #where_clause {
+            #render_fn
+
+            /// Render the template into a writer.
+            ///
+            /// # Errors
+            ///
+            /// If strings cannot be written to the formatter.
+            #[inline]
+            #vis fn #render_into_fn<W: ::core::fmt::Write>(&self, oxiplate_formatter: &mut W) -> ::core::result::Result<(), #error_type> {
+                extern crate alloc;
+
+                use ::core::fmt::Write as _;
+                #uses
+                #template
+                Ok(())
+            }
+        }
+    }
 }
 
-type ParsedTemplate = (
-    proc_macro2::TokenStream,
-    usize,
-    TemplateType,
-    OptimizedRenderer,
-);
+/// A template that has been parsed and built.
+struct BuiltTemplate {
+    /// Name of the template, if one was specified.
+    name: Option<LitStr>,
+
+    /// Tokens for the built template.
+    template: proc_macro2::TokenStream,
+
+    /// Estimated length of the rendered template.
+    estimated_length: usize,
+
+    /// Path to the escaper group the template's output is escaped for, if any.
+    #[cfg_attr(not(feature = "_oxiplate"), allow(dead_code))]
+    escaper_group: Option<String>,
+}
+
+type ParsedTemplates = (Vec<BuiltTemplate>, TemplateType, OptimizedRenderer);
 
 fn parse_template_and_data(
     input: &DeriveInput,
+    options: &TemplateOptions,
+    local_variables: &LocalVariables,
     blocks: &VecDeque<&HashMap<&str, (BuiltTokens, Option<BuiltTokens>)>>,
-) -> Result<ParsedTemplate, (syn::Error, Option<TemplateType>, OptimizedRenderer)> {
+) -> Result<ParsedTemplates, (syn::Error, Option<TemplateType>, OptimizedRenderer)> {
     // Build the shared config from the `oxiplate.toml` file.
-    let config =
-        build_config(input).map_err(|(err, optimized_renderer)| (err, None, optimized_renderer))?;
+    let config = build_config(input.span())
+        .map_err(|(err, optimized_renderer)| (err, None, optimized_renderer))?;
 
     let DeriveInput {
         attrs, ident, data, ..
     } = &input;
 
-    // Ensure the data is a struct
     match data {
-        Data::Struct(_struct_item) => (),
-        _ => {
+        Data::Struct(_struct_item) => parse_struct_templates(
+            attrs,
+            ident.span(),
+            &config,
+            options,
+            local_variables,
+            blocks,
+        ),
+        Data::Enum(enum_item) => {
+            parse_enum_templates(attrs, enum_item, &config, options, local_variables, blocks)
+        }
+        Data::Union(_union_item) => Err((
+            syn::Error::new(input.span(), "Expected a struct or enum"),
+            None,
+            config.optimized_renderer,
+        )),
+    }
+}
+
+/// Parses each of the templates specified for a struct.
+/// At most one template may be unnamed,
+/// and the names of the others must be unique.
+fn parse_struct_templates(
+    attrs: &[Attribute],
+    span: Span,
+    config: &Config,
+    options: &TemplateOptions,
+    local_variables: &LocalVariables,
+    blocks: &VecDeque<&HashMap<&str, (BuiltTokens, Option<BuiltTokens>)>>,
+) -> Result<ParsedTemplates, (syn::Error, Option<TemplateType>, OptimizedRenderer)> {
+    let optimized_renderer = config.optimized_renderer.clone();
+
+    let template_types = parse_template_types(attrs, span)
+        .map_err(|err: syn::Error| (err, None, optimized_renderer.clone()))?;
+    let syntax_overrides = parse_syntax_overrides(attrs)
+        .map_err(|err: syn::Error| (err, None, optimized_renderer.clone()))?;
+
+    let mut templates: Vec<BuiltTemplate> = Vec::with_capacity(template_types.len());
+    let mut template_type = TemplateType::Inline;
+    for (name, attr, current_template_type) in template_types {
+        let duplicate_error = match &name {
+            Some(name)
+                if templates.iter().any(|template| {
+                    template
+                        .name
+                        .as_ref()
+                        .is_some_and(|other| other.value() == name.value())
+                }) =>
+            {
+                Some((name.span(), "Template names must be unique"))
+            }
+            None if templates.iter().any(|template| template.name.is_none()) => Some((
+                attr.span(),
+                "Only one unnamed template may be specified. Name additional templates like: \
+                 #[oxiplate(name = \"text\", path = \"path/to/template.txt.oxip\")]",
+            )),
+            _ => None,
+        };
+        if let Some((span, message)) = duplicate_error {
             return Err((
-                syn::Error::new(input.span(), "Expected a struct"),
-                None,
-                config.optimized_renderer,
+                syn::Error::new(span, message),
+                Some(current_template_type),
+                optimized_renderer,
             ));
         }
+
+        let ((template, estimated_length), escaper_group) = parse_template(
+            attr,
+            &current_template_type,
+            config.clone(),
+            local_variables.clone(),
+            blocks,
+            syntax_overrides.clone(),
+            options,
+        )?;
+        templates.push(BuiltTemplate {
+            name,
+            template,
+            estimated_length,
+            escaper_group,
+        });
+        template_type = current_template_type;
     }
 
-    let (attr, template_type) = parse_template_type(attrs, ident.span())
-        .map_err(|err: syn::Error| (err, None, config.optimized_renderer.clone()))?;
+    Ok((templates, template_type, optimized_renderer))
+}
 
+/// Builds the pattern matching the variant in the enum's `match`,
+/// adding any named fields to the local variables.
+fn variant_pattern(
+    variant: &syn::Variant,
+    local_variables: &mut LocalVariables,
+) -> proc_macro2::TokenStream {
+    let variant_ident = &variant.ident;
+    match &variant.fields {
+        Fields::Named(fields) => {
+            let field_idents: Vec<&Ident> = fields
+                .named
+                .iter()
+                .filter_map(|field| field.ident.as_ref())
+                .collect();
+            local_variables.add(
+                field_idents
+                    .iter()
+                    .map(|field_ident| field_ident.unraw().to_string())
+                    .collect(),
+            );
+            quote! { Self::#variant_ident { #(#field_idents),* } }
+        }
+        Fields::Unnamed(_fields) => quote! { Self::#variant_ident(..) },
+        Fields::Unit => quote! { Self::#variant_ident },
+    }
+}
+
+/// Parses the template for each variant of an enum
+/// and builds a `match` that renders the template for the current variant.
+/// Named fields of each variant are available as local variables
+/// within that variant's template.
+fn parse_enum_templates(
+    attrs: &[Attribute],
+    enum_item: &DataEnum,
+    config: &Config,
+    options: &TemplateOptions,
+    local_variables: &LocalVariables,
+    blocks: &VecDeque<&HashMap<&str, (BuiltTokens, Option<BuiltTokens>)>>,
+) -> Result<ParsedTemplates, (syn::Error, Option<TemplateType>, OptimizedRenderer)> {
     let optimized_renderer = config.optimized_renderer.clone();
 
-    let mut state = State {
-        local_variables: LocalVariables::new(),
-        inferred_escaper_group: None,
-        default_escaper_group: None,
-        failed_to_set_default_escaper_group: false,
-        config,
-        blocks,
-        has_content: false,
-    };
+    // Templates on the enum itself would be ambiguous,
+    // so require them to be specified on each variant instead.
+    if let Some((_name, attr, _template_type)) = parse_template_types(attrs, Span::call_site())
+        .ok()
+        .and_then(|template_types| template_types.into_iter().next())
+    {
+        return Err((
+            syn::Error::new(
+                attr.span(),
+                "Templates for enums must be specified on each variant rather than on the enum",
+            ),
+            None,
+            optimized_renderer,
+        ));
+    }
 
-    let parsed_tokens = parse_source_tokens(attr, &template_type, &mut state);
-    let (template, estimated_length): BuiltTokens = process_parsed_tokens(
-        parsed_tokens,
-        &mut state,
-        #[cfg(any(feature = "_oxiplate", feature = "external-template-spans"))]
-        &template_type,
-    )
-    .map_err(|err: syn::Error| (err, Some(template_type.clone()), optimized_renderer.clone()))?;
+    // An empty enum can never be constructed, so there would be nothing to render.
+    if enum_item.variants.is_empty() {
+        return Err((
+            syn::Error::new(
+                enum_item.brace_token.span.join(),
+                "Expected at least one variant",
+            ),
+            None,
+            optimized_renderer,
+        ));
+    }
+
+    let syntax_overrides = parse_syntax_overrides(attrs)
+        .map_err(|err: syn::Error| (err, None, optimized_renderer.clone()))?;
+
+    let mut arms = proc_macro2::TokenStream::new();
+    let mut estimated_length = 0;
+    let mut escaper_groups = Vec::with_capacity(enum_item.variants.len());
+    for variant in &enum_item.variants {
+        let mut local_variables = local_variables.clone();
+        let variant_ident = &variant.ident;
+        let pattern = variant_pattern(variant, &mut local_variables);
+
+        let template_types = parse_template_types(&variant.attrs, variant_ident.span())
+            .map_err(|err: syn::Error| (err, None, optimized_renderer.clone()))?;
+        let mut template_types = template_types.into_iter();
+        let Some((name, attr, template_type)) = template_types.next() else {
+            unreachable!("At least one template type should be present if no error was returned");
+        };
+        if let Some((_name, extra_attr, extra_template_type)) = template_types.next() {
+            return Err((
+                syn::Error::new(
+                    extra_attr.span(),
+                    "Enum variants can only have one template",
+                ),
+                Some(extra_template_type),
+                optimized_renderer,
+            ));
+        }
+        if let Some(name) = name {
+            return Err((
+                syn::Error::new(
+                    name.span(),
+                    "Named templates are not supported on enum variants",
+                ),
+                Some(template_type),
+                optimized_renderer,
+            ));
+        }
+
+        let ((template, variant_estimated_length), escaper_group) = parse_template(
+            attr,
+            &template_type,
+            config.clone(),
+            local_variables,
+            blocks,
+            syntax_overrides.clone(),
+            options,
+        )?;
+
+        estimated_length = estimated_length.max(variant_estimated_length);
+        escaper_groups.push(escaper_group);
+        arms.append_all(quote! {
+            #[allow(unused_variables)]
+            #pattern => {
+                #template
+            }
+        });
+    }
+
+    // The output is only escaped for a group if every variant's template is.
+    let escaper_group = escaper_groups
+        .iter()
+        .all(|escaper_group| escaper_group == &escaper_groups[0])
+        .then(|| escaper_groups.swap_remove(0))
+        .flatten();
 
     Ok((
-        template,
-        estimated_length,
-        template_type,
+        vec![BuiltTemplate {
+            name: None,
+            template: quote! {
+                match self {
+                    #arms
+                }
+            },
+            estimated_length,
+            escaper_group,
+        }],
+        TemplateType::Inline,
         optimized_renderer,
     ))
 }
 
+/// Built template along with the path to the escaper group
+/// its output is escaped for, if any.
+type BuiltTemplateTokens = (BuiltTokens, Option<String>);
+
+/// Parses a single template from the provided attribute.
+fn parse_template(
+    attr: &Attribute,
+    template_type: &TemplateType,
+    config: Config,
+    local_variables: LocalVariables,
+    blocks: &VecDeque<&HashMap<&str, (BuiltTokens, Option<BuiltTokens>)>>,
+    syntax_overrides: SyntaxOverrides,
+    options: &TemplateOptions,
+) -> Result<BuiltTemplateTokens, (syn::Error, Option<TemplateType>, OptimizedRenderer)> {
+    let optimized_renderer = config.optimized_renderer.clone();
+
+    let mut state = State {
+        syntax_overrides,
+        error_type: options.error_type.clone(),
+        locale: options.locale.clone(),
+        ..State::new(config, local_variables, blocks)
+    };
+
+    let parsed_tokens = parse_source_tokens(attr, template_type, &mut state);
+    let template = process_parsed_tokens(parsed_tokens, &mut state, template_type)
+        .map_err(|err: syn::Error| (err, Some(template_type.clone()), optimized_renderer))?;
+
+    #[cfg(feature = "_oxiplate")]
+    let escaper_group = state
+        .output_escaper_group()
+        .map(|escaper_group| escaper_group.escaper.clone());
+    #[cfg(not(feature = "_oxiplate"))]
+    let escaper_group = None;
+
+    Ok((template, escaper_group))
+}
+
 type ParsedTokens = Result<
     (
         Span,
@@ -300,10 +790,9 @@ type ParsedTokens = Result<
     ParsedEscaperError,
 >;
 
-fn process_parsed_tokens<'a>(
+fn process_parsed_tokens(
     parsed_tokens: ParsedTokens,
-    state: &'a mut State<'a>,
-    #[cfg(any(feature = "_oxiplate", feature = "external-template-spans"))]
+    state: &mut State,
     template_type: &TemplateType,
 ) -> Result<BuiltTokens, syn::Error> {
     match parsed_tokens {
@@ -318,7 +807,10 @@ fn process_parsed_tokens<'a>(
             available_escaper_groups.sort_unstable();
             let available_escaper_groups = available_escaper_groups.join(", ");
             let template = match template_type {
-                TemplateType::Path | TemplateType::Extends | TemplateType::Include => {
+                TemplateType::Path
+                | TemplateType::Extends
+                | TemplateType::Include
+                | TemplateType::Import => {
                     internal_error!(
                         span.unwrap(),
                         "Unregistered file extension causing `EscaperNotFound` error",
@@ -355,15 +847,40 @@ fn process_parsed_tokens<'a>(
                 ));
             }
 
+            let syntax = state.syntax();
+            syntax
+                .validate()
+                .map_err(|message| syn::Error::new(span, message))?;
+
             // Build the source.
             let owned_source = SourceOwned::new(&code, span, origin);
             let source = Source::new(&owned_source);
-            let (tokens, eof) = tokens_and_eof(source);
+            let (tokens, eof) = tokens_and_eof(source, &syntax);
             let tokens = TokenSlice::new(&tokens, &eof);
 
             // Build the `::std::fmt::Display` implementation for the struct.
             // (This is where the template is actually parsed.)
-            Ok(parse(state, tokens))
+            // Imported templates are only parsed for the macros they define.
+            if let TemplateType::Import = template_type {
+                return Ok(parse_macros(state, tokens));
+            }
+            let (mut template, estimated_length) = parse(state, tokens);
+
+            // The including template continues escaping as if it's still in HTML text.
+            if matches!(template_type, TemplateType::Include)
+                && state.tracks_html_context()
+                && state.html_context != HtmlContext::Text
+            {
+                let message = format!(
+                    "Included template ends {}, but included templates need to end in HTML text \
+                     so the including template can continue to be escaped correctly. Keep tags, \
+                     comments, and quoted attribute values balanced within the included template.",
+                    state.html_context.location(),
+                );
+                template.append_all(quote_spanned! {span=> compile_error!(#message); });
+            }
+
+            Ok((template, estimated_length))
         }
     }
 }
@@ -374,13 +891,16 @@ enum TemplateType {
     Inline,
     Extends,
     Include,
+    Import,
 }
 
-/// Parse the attributes to figure out what type of template this struct references.
-fn parse_template_type(
-    attrs: &Vec<Attribute>,
-    span: Span,
-) -> Result<(&Attribute, TemplateType), syn::Error> {
+/// Template types along with their optional names and the attributes they were parsed from.
+type TemplateTypes<'a> = Vec<(Option<LitStr>, &'a Attribute, TemplateType)>;
+
+/// Parse the attributes to figure out what type of templates this struct references,
+/// along with the name of each template if one was provided.
+fn parse_template_types(attrs: &[Attribute], span: Span) -> Result<TemplateTypes<'_>, syn::Error> {
+    let mut template_types = Vec::new();
     for attr in attrs {
         let path = attr.path();
         let template_type = if path.is_ident("oxiplate_inline") {
@@ -395,15 +915,214 @@ fn parse_template_type(
             continue;
         };
 
-        return Ok((attr, template_type));
+        template_types.push((parse_template_name(attr)?, attr, template_type));
     }
 
-    Err(syn::Error::new(
-        span,
-        r#"Expected an attribute named `oxiplate_inline` or `oxiplate` to specify the template:
+    if template_types.is_empty() {
+        return Err(syn::Error::new(
+            span,
+            r#"Expected an attribute named `oxiplate_inline` or `oxiplate` to specify the template:
 External: #[oxiplate = "path/to/template/from/templates/directory.html.oxip"]
 Internal: #[oxiplate_inline(html: "{{ your_var }}")]"#,
-    ))
+        ));
+    }
+
+    Ok(template_types)
+}
+
+/// Parse the delimiters and whitespace handling specified on the struct, if any.
+/// Delimiters look like `#[oxiplate_syntax(writ = ["<<", ">>"])]`
+/// and whitespace handling looks like `#[oxiplate_syntax(trim_blocks = true)]`.
+fn parse_syntax_overrides(attrs: &[Attribute]) -> Result<SyntaxOverrides, syn::Error> {
+    let mut syntax_overrides = SyntaxOverrides::default();
+    for attr in attrs {
+        if !attr.path().is_ident("oxiplate_syntax") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            let kind = meta
+                .path
+                .get_ident()
+                .map(ToString::to_string)
+                .unwrap_or_default();
+
+            let value = meta.value()?;
+            if value.peek(LitBool) {
+                let value: LitBool = value.parse()?;
+                return syntax_overrides
+                    .set_whitespace(&kind, value.value)
+                    .map_err(|message| meta.error(message));
+            }
+
+            let content;
+            syn::bracketed!(content in value);
+            let pair = Punctuated::<LitStr, Comma>::parse_terminated(&content)?
+                .iter()
+                .map(LitStr::value)
+                .collect::<Vec<String>>();
+            let pair = pair.iter().map(String::as_str).collect::<Vec<&str>>();
+
+            syntax_overrides
+                .set(&kind, &pair)
+                .map_err(|message| meta.error(message))
+        })?;
+    }
+
+    Ok(syntax_overrides)
+}
+
+/// Options from attributes on the struct or enum
+/// that apply to each of its templates.
+struct TemplateOptions {
+    /// Error type from `#[oxiplate_error(...)]`.
+    error_type: Option<proc_macro2::TokenStream>,
+
+    /// Locale from `#[oxiplate_locale(...)]`.
+    locale: Option<Locale>,
+}
+
+/// Parse the options specified on the struct that apply to each of its templates.
+fn parse_template_options(attrs: &[Attribute]) -> Result<TemplateOptions, syn::Error> {
+    Ok(TemplateOptions {
+        error_type: parse_error_type(attrs)?,
+        locale: parse_locale(attrs)?,
+    })
+}
+
+/// Parse the locale specified on the struct, if any.
+/// Locales look like `#[oxiplate_locale(locale, default = "en")]`.
+fn parse_locale(attrs: &[Attribute]) -> Result<Option<Locale>, syn::Error> {
+    let mut locale = None;
+    for attr in attrs {
+        if !attr.path().is_ident("oxiplate_locale") {
+            continue;
+        }
+
+        if locale.is_some() {
+            return Err(syn::Error::new(
+                attr.span(),
+                "Only one locale may be specified",
+            ));
+        }
+
+        locale = Some(attr.parse_args::<Locale>()?);
+    }
+
+    Ok(locale)
+}
+
+/// Parse the error type specified on the struct, if any.
+/// Error types look like `#[oxiplate_error(YourError)]`.
+fn parse_error_type(attrs: &[Attribute]) -> Result<Option<proc_macro2::TokenStream>, syn::Error> {
+    let mut error_type = None;
+    for attr in attrs {
+        if !attr.path().is_ident("oxiplate_error") {
+            continue;
+        }
+
+        if cfg!(not(feature = "_oxiplate")) {
+            return Err(syn::Error::new(
+                attr.span(),
+                "`oxiplate_error` requires the `TryRender` trait from `oxiplate`. It looks like \
+                 `oxiplate-derive` is being used directly instead.",
+            ));
+        }
+
+        if error_type.is_some() {
+            return Err(syn::Error::new(
+                attr.span(),
+                "Only one error type may be specified",
+            ));
+        }
+
+        error_type = Some(attr.parse_args::<syn::Type>()?.to_token_stream());
+    }
+
+    Ok(error_type)
+}
+
+/// Parse the name of the template from the attribute, if present.
+/// Named templates look like
+/// `#[oxiplate(name = "html", path = "path/to/template.html.oxip")]`
+/// or `#[oxiplate_inline(name = "text", "{{ your_var }}")]`.
+fn parse_template_name(attr: &Attribute) -> Result<Option<LitStr>, syn::Error> {
+    let syn::Meta::List(MetaList { tokens, .. }) = &attr.meta else {
+        return Ok(None);
+    };
+
+    let NamedInline { name, .. } = syn::parse2(tokens.clone())?;
+    let name = if name.is_some() && attr.path().is_ident("oxiplate") {
+        Some(syn::parse2::<NamedPath>(tokens.clone())?.name)
+    } else {
+        name
+    };
+
+    if let Some(name) = &name {
+        if syn::parse_str::<Ident>(&name.value()).is_err() {
+            return Err(syn::Error::new(
+                name.span(),
+                "Template names must be valid identifiers",
+            ));
+        }
+    }
+
+    Ok(name)
+}
+
+/// A named external template: `name = "html", path = "path/to/template.html.oxip"`.
+struct NamedPath {
+    name: LitStr,
+    path: LitStr,
+}
+
+impl Parse for NamedPath {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let name = parse_key_value(input, "name")?;
+        input.parse::<Comma>()?;
+        let path = parse_key_value(input, "path")?;
+        if !input.is_empty() {
+            input.parse::<Comma>()?;
+        }
+
+        Ok(NamedPath { name, path })
+    }
+}
+
+/// An inline template that may be prefixed with a name: `name = "text", "{{ your_var }}"`.
+struct NamedInline {
+    name: Option<LitStr>,
+    template: proc_macro2::TokenStream,
+}
+
+impl Parse for NamedInline {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let name = if input.peek(Ident) && input.peek2(Eq) {
+            let name = parse_key_value(input, "name")?;
+            input.parse::<Comma>()?;
+            Some(name)
+        } else {
+            None
+        };
+
+        Ok(NamedInline {
+            name,
+            template: input.parse()?,
+        })
+    }
+}
+
+/// Parse a `key = "value"` pair with the expected key.
+fn parse_key_value(input: syn::parse::ParseStream, expected_key: &str) -> syn::Result<LitStr> {
+    let key: Ident = input.parse()?;
+    if key != expected_key {
+        return Err(syn::Error::new(
+            key.span(),
+            format!("Expected `{expected_key}`"),
+        ));
+    }
+    input.parse::<Eq>()?;
+    input.parse()
 }
 
 fn parse_code_literal(
@@ -425,6 +1144,9 @@ Internal: #[oxiplate_inline(html: "{{ your_var }}")]"#
             TemplateType::Include => {
                 r#"Must provide a path to a template that exists. E.g., `{% include "path/to/template.html.oxip" %}`"#
             }
+            TemplateType::Import => {
+                r#"Must provide a path to a template that exists. E.g., `{% import "path/to/template.html.oxip" as macros %}`"#
+            }
         };
 
         // Expand macros
@@ -451,9 +1173,10 @@ fn parse_source_tokens(
 ) -> ParsedTokens {
     match template_type {
         TemplateType::Inline => parse_source_tokens_for_inline(attr, state),
-        TemplateType::Path | TemplateType::Extends | TemplateType::Include => {
-            parse_source_tokens_for_path(attr, state)
-        }
+        TemplateType::Path
+        | TemplateType::Extends
+        | TemplateType::Include
+        | TemplateType::Import => parse_source_tokens_for_path(attr, state),
     }
 }
 
@@ -532,53 +1255,11 @@ Internal: #[oxiplate_inline(html: "{{ your_var }}")]"#);
             path: _,
             delimiter: _,
             tokens,
-        }) => match syn::parse2::<Template>(tokens.clone()) {
-            #[cfg(not(feature = "_oxiplate"))]
-            Ok(Template::WithEscaper(template)) => {
-                let span = template.escaper.span();
-                Err(ParsedEscaperError::ParseError(quote_spanned! {span=>
-                    compile_error!("Escaping requires the `oxiplate` library, but you appear to be using \
-                 `oxiplate-derive` directly. Replacing `oxiplate-derive` with `oxiplate` in the \
-                 dependencies should fix this issue, although you may need to turn off some \
-                 default features if you want it to work the same way.");
-                }))
-            }
-            #[cfg(feature = "_oxiplate")]
-            Ok(Template::WithEscaper(TemplateWithEscaper {
-                escaper,
-                colon: _,
-                template,
-            })) => {
-                let span = template.span();
-
-                let escaper_name = escaper.to_string();
-                if !state.config.escaper_groups.contains_key(&escaper_name) {
-                    return Err(ParsedEscaperError::EscaperNotFound((
-                        escaper_name,
-                        escaper.span(),
-                    )));
-                }
-
-                Ok((
-                    span,
-                    quote::quote_spanned!(span=> #template),
-                    None,
-                    Some(escaper_name),
-                ))
-            }
-            Ok(Template::WithoutEscaper(TemplateWithoutEscaper { template })) => {
-                let span = template.span();
-                Ok((span, quote::quote_spanned!(span=> #template), None, None))
-            }
-            Err(error) => {
-                let span = error.span();
-                let compile_error = error.to_compile_error();
-                Err(ParsedEscaperError::ParseError(quote_spanned! {span=>
-                    compile_error!("Failed to parse inline template. Should look something like:\n#[oxiplate_inline(html: \"{{ your_var }}\")]");
-                    #compile_error
-                }))
-            }
-        },
+        }) => parse_source_tokens_for_template(
+            syn::parse2::<NamedInline>(tokens.clone())
+                .and_then(|named_inline| syn::parse2::<Template>(named_inline.template)),
+            state,
+        ),
         syn::Meta::NameValue(meta) => {
             let span = meta.span();
             Err(ParsedEscaperError::ParseError(quote_spanned! {span=>
@@ -588,6 +1269,61 @@ Internal: #[oxiplate_inline(html: "{{ your_var }}")]"#);
     }
 }
 
+/// Parse the escaper information and template source from an inline template.
+#[cfg_attr(not(feature = "_oxiplate"), allow(clippy::unnecessary_wraps))]
+fn parse_source_tokens_for_template(
+    template: syn::Result<Template>,
+    #[cfg_attr(not(feature = "_oxiplate"), allow(unused_variables))] state: &mut State,
+) -> ParsedTokens {
+    match template {
+        #[cfg(not(feature = "_oxiplate"))]
+        Ok(Template::WithEscaper(template)) => {
+            let span = template.escaper.span();
+            Err(ParsedEscaperError::ParseError(quote_spanned! {span=>
+                compile_error!("Escaping requires the `oxiplate` library, but you appear to be using \
+             `oxiplate-derive` directly. Replacing `oxiplate-derive` with `oxiplate` in the \
+             dependencies should fix this issue, although you may need to turn off some \
+             default features if you want it to work the same way.");
+            }))
+        }
+        #[cfg(feature = "_oxiplate")]
+        Ok(Template::WithEscaper(TemplateWithEscaper {
+            escaper,
+            colon: _,
+            template,
+        })) => {
+            let span = template.span();
+
+            let escaper_name = escaper.to_string();
+            if !state.config.escaper_groups.contains_key(&escaper_name) {
+                return Err(ParsedEscaperError::EscaperNotFound((
+                    escaper_name,
+                    escaper.span(),
+                )));
+            }
+
+            Ok((
+                span,
+                quote::quote_spanned!(span=> #template),
+                None,
+                Some(escaper_name),
+            ))
+        }
+        Ok(Template::WithoutEscaper(TemplateWithoutEscaper { template })) => {
+            let span = template.span();
+            Ok((span, quote::quote_spanned!(span=> #template), None, None))
+        }
+        Err(error) => {
+            let span = error.span();
+            let compile_error = error.to_compile_error();
+            Err(ParsedEscaperError::ParseError(quote_spanned! {span=>
+                compile_error!("Failed to parse inline template. Should look something like:\n#[oxiplate_inline(html: \"{{ your_var }}\")]");
+                #compile_error
+            }))
+        }
+    }
+}
+
 /// Build the absolute template directory
 /// from the package's directory and provided relative template directory.
 fn templates_dir(span: Span) -> Result<PathBuf, ParsedEscaperError> {
@@ -719,19 +1455,29 @@ fn parse_source_tokens_for_path(
     attr: &Attribute,
     #[cfg_attr(not(feature = "_oxiplate"), allow(unused_variables))] state: &mut State,
 ) -> ParsedTokens {
-    let syn::Meta::NameValue(MetaNameValue {
-        path: _,
-        eq_token: _,
-        value: Expr::Lit(ExprLit {
-            attrs: _,
-            lit: Lit::Str(path),
-        }),
-    }) = &attr.meta
-    else {
+    let incorrect_syntax = || {
         let span = attr.span();
-        return Err(ParsedEscaperError::ParseError(quote_spanned! {span=>
+        ParsedEscaperError::ParseError(quote_spanned! {span=>
             compile_error!("Incorrect syntax for external template. Should look something like:\n#[oxiplate = \"/path/to/template/from/templates/directory.txt.oxip\"]");
-        }));
+        })
+    };
+    let named_path: NamedPath;
+    let path = match &attr.meta {
+        syn::Meta::NameValue(MetaNameValue {
+            path: _,
+            eq_token: _,
+            value:
+                Expr::Lit(ExprLit {
+                    attrs: _,
+                    lit: Lit::Str(path),
+                }),
+        }) => path,
+        syn::Meta::List(MetaList { tokens, .. }) => {
+            named_path =
+                syn::parse2::<NamedPath>(tokens.clone()).map_err(|_err| incorrect_syntax())?;
+            &named_path.path
+        }
+        _ => return Err(incorrect_syntax()),
     };
 
     let full_path = template_path(path, attr.span())?;
@@ -0,0 +1,124 @@
+#[cfg(feature = "_oxiplate")]
+use std::collections::{HashMap, VecDeque};
+
+use proc_macro::TokenStream;
+#[cfg(feature = "_oxiplate")]
+use quote::quote;
+#[cfg(feature = "_oxiplate")]
+use syn::Expr;
+#[cfg(feature = "_oxiplate")]
+use syn::parse::{Parse, ParseStream};
+#[cfg(feature = "_oxiplate")]
+use syn::spanned::Spanned;
+#[cfg(feature = "_oxiplate")]
+use syn::token::{Comma, Eq};
+
+#[cfg(feature = "_oxiplate")]
+use crate::state::{LocalVariables, build_config};
+#[cfg(feature = "_oxiplate")]
+use crate::{
+    Ident, State, Template, TemplateType, parse_source_tokens_for_template, process_parsed_tokens,
+};
+
+/// Input for the `render!()` macro:
+/// an inline template followed by optional named arguments.
+#[cfg(feature = "_oxiplate")]
+struct RenderInput {
+    template: Template,
+    arguments: Vec<(Ident, Expr)>,
+}
+
+#[cfg(feature = "_oxiplate")]
+impl Parse for RenderInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let template = input.parse()?;
+
+        let mut arguments = Vec::new();
+        while !input.is_empty() {
+            input.parse::<Comma>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let name: Ident = input.parse()?;
+            input.parse::<Eq>()?;
+            let value: Expr = input.parse()?;
+            arguments.push((name, value));
+        }
+
+        Ok(Self {
+            template,
+            arguments,
+        })
+    }
+}
+
+/// Build a value that implements `Render` from an inline template.
+/// Variables that are not passed in as arguments
+/// are borrowed from the surrounding scope.
+#[cfg_attr(not(feature = "_oxiplate"), allow(clippy::needless_pass_by_value))]
+pub(crate) fn render(input: TokenStream) -> TokenStream {
+    let span = proc_macro2::Span::call_site();
+
+    #[cfg(not(feature = "_oxiplate"))]
+    {
+        let _ = input;
+        quote::quote_spanned! {span=>
+            compile_error!("`render!()` requires the `oxiplate` library, but you appear to be using \
+                            `oxiplate-derive` directly.")
+        }
+        .into()
+    }
+
+    #[cfg(feature = "_oxiplate")]
+    {
+        let RenderInput {
+            template,
+            arguments,
+        } = match syn::parse(input) {
+            Ok(input) => input,
+            Err(err) => return err.to_compile_error().into(),
+        };
+
+        let config = match build_config(span) {
+            Ok(config) => config,
+            Err((err, _optimized_renderer)) => return err.to_compile_error().into(),
+        };
+
+        let no_blocks = HashMap::new();
+        let blocks = VecDeque::from([&no_blocks]);
+        let mut state = State::new(config, LocalVariables::all_local(), &blocks);
+
+        let parsed_tokens = parse_source_tokens_for_template(Ok(template), &mut state);
+        let (template, estimated_length) =
+            match process_parsed_tokens(parsed_tokens, &mut state, &TemplateType::Inline) {
+                Ok(built_tokens) => built_tokens,
+                Err(err) => return err.to_compile_error().into(),
+            };
+
+        let (names, values): (Vec<Ident>, Vec<Expr>) = arguments.into_iter().unzip();
+        let values = values.iter().map(|value| {
+            let span = value.span();
+            quote::quote_spanned! {span=> #value }
+        });
+
+        quote! {
+            ::oxiplate::RenderFn::<_, _, #estimated_length>::new(
+                (#(#values,)*),
+                |oxiplate_formatter: &mut dyn ::core::fmt::Write, oxiplate_arguments| -> ::core::fmt::Result {
+                    extern crate alloc;
+
+                    use ::core::fmt::Write as _;
+                    use ::oxiplate::{ToCowStr as _, UnescapedText as _};
+
+                    #[allow(unused_variables)]
+                    let (#(#names,)*) = oxiplate_arguments;
+
+                    #template
+                    Ok(())
+                },
+            )
+        }
+        .into()
+    }
+}
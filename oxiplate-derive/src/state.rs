@@ -2,13 +2,18 @@ use std::collections::{HashMap, HashSet, VecDeque};
 #[cfg(not(feature = "config"))]
 use std::fs;
 
-use syn::DeriveInput;
-use syn::spanned::Spanned;
+use proc_macro2::{Span, TokenStream};
 
 use crate::BuiltTokens;
 #[cfg(not(feature = "config"))]
 use crate::config::config_path;
-use crate::config::{Config, EscaperGroup, OptimizedRenderer, read_config};
+use crate::config::{
+    Config, EscaperGroup, OptimizedRenderer, Syntax, SyntaxOverrides, read_config,
+};
+#[cfg(feature = "_oxiplate")]
+use crate::html_context::HTML_ESCAPER;
+use crate::html_context::HtmlContext;
+use crate::i18n::Locale;
 
 #[cfg(all(feature = "built-in-escapers", not(feature = "_oxiplate")))]
 compile_error!(
@@ -18,12 +23,12 @@ compile_error!(
 
 /// Build a `Config` from Oxiplate's defaults
 /// and the user-defined `/oxiplate.toml`.
-pub(crate) fn build_config(input: &DeriveInput) -> Result<Config, (syn::Error, OptimizedRenderer)> {
+pub(crate) fn build_config(span: Span) -> Result<Config, (syn::Error, OptimizedRenderer)> {
     #[cfg(not(feature = "config"))]
     if fs::exists(config_path()).unwrap_or(false) {
         return Err((
             syn::Error::new(
-                input.span(),
+                span,
                 r#"`/oxiplate.toml` exists, but the "config" feature is turned off. Either delete/rename `/oxiplate.toml`, or turn the "config" feature on."#,
             ),
             OptimizedRenderer::unoptimized(),
@@ -46,7 +51,7 @@ pub(crate) fn build_config(input: &DeriveInput) -> Result<Config, (syn::Error, O
         {
             return Err((
                 syn::Error::new(
-                    input.span(),
+                    span,
                     format!(
                         "The `fallback_escaper_group` that was provided \
                          (`{fallback_escaper_group}`) does not match any of the `escaper_groups` \
@@ -59,6 +64,21 @@ pub(crate) fn build_config(input: &DeriveInput) -> Result<Config, (syn::Error, O
         }
     }
 
+    for escaper_group in config.escaper_group_syntax.keys() {
+        if !config.escaper_groups.contains_key(escaper_group) {
+            return Err((
+                syn::Error::new(
+                    span,
+                    format!(
+                        "Syntax was provided for escaper group `{escaper_group}`, but it does not \
+                         match any of the `escaper_groups` specified in `/oxiplate.toml`."
+                    ),
+                ),
+                config.optimized_renderer,
+            ));
+        }
+    }
+
     Ok(config)
 }
 
@@ -70,7 +90,7 @@ fn read_config_and_add_built_in_escapers() -> Result<Config, syn::Error> {
 
     // Add built-in escapers to the user-defined escapers
     let built_in_escapers = [
-        ("html", "::oxiplate::escapers::html::HtmlEscaper"),
+        ("html", crate::html_context::HTML_ESCAPER),
         ("md", "::oxiplate::escapers::markdown::MarkdownEscaper"),
         ("json", "::oxiplate::escapers::json::JsonEscaper"),
     ];
@@ -91,7 +111,16 @@ fn read_config_and_add_built_in_escapers() -> Result<Config, syn::Error> {
 }
 
 /// Local variables available for usage within templates.
+#[derive(Clone)]
 pub(crate) struct LocalVariables {
+    /// Whether all variables should be treated as local
+    /// because there is no `self` to access fields from.
+    all_local: bool,
+
+    /// Whether fields from `self` should be unavailable
+    /// because the template was included with `only`.
+    isolated: bool,
+
     /// Currently active variables.
     active: HashSet<String>,
 
@@ -103,15 +132,44 @@ impl LocalVariables {
     /// Create a new instance of local variables.
     pub fn new() -> Self {
         Self {
+            all_local: false,
+            isolated: false,
             active: HashSet::new(),
             stack: vec![vec![]],
         }
     }
 
+    /// Create a new instance where every variable is local,
+    /// for templates that are not attached to a struct.
+    #[cfg(feature = "_oxiplate")]
+    pub fn all_local() -> Self {
+        Self {
+            all_local: true,
+            ..Self::new()
+        }
+    }
+
+    /// Create a new instance with only the provided variables,
+    /// for templates that should not have access to fields from `self`.
+    pub fn isolated(vars: HashSet<String>) -> Self {
+        let mut local_variables = Self {
+            isolated: true,
+            ..Self::new()
+        };
+        local_variables.add(vars);
+        local_variables
+    }
+
+    /// Whether fields from `self` are unavailable.
+    #[must_use]
+    pub fn is_isolated(&self) -> bool {
+        self.isolated
+    }
+
     /// Whether the provided variable name exists as a local variable.
     #[must_use]
     pub fn contains(&self, var: &str) -> bool {
-        self.active.contains(var)
+        self.all_local || self.active.contains(var)
     }
 
     /// Add one or more variables to the current stack.
@@ -149,7 +207,17 @@ impl LocalVariables {
     }
 }
 
+/// A `macro` statement from a template that has been built
+/// and can be expanded wherever it's called.
+#[derive(Clone)]
+pub(crate) struct MacroDefinition {
+    /// Parameter names and their types, if specified.
+    pub(crate) parameters: Vec<(TokenStream, Option<TokenStream>)>,
+    pub(crate) template: BuiltTokens,
+}
+
 /// Macro state containing the configuration and any local variables.
+#[derive(Clone)]
 pub(crate) struct State<'a> {
     /// Storage for local variable names when building tokens.
     pub(crate) local_variables: LocalVariables,
@@ -167,4 +235,129 @@ pub(crate) struct State<'a> {
     pub(crate) failed_to_set_default_escaper_group: bool,
     pub(crate) blocks: &'a VecDeque<&'a HashMap<&'a str, (BuiltTokens, Option<BuiltTokens>)>>,
     pub(crate) has_content: bool,
+
+    /// Macros defined so far in the template, by name.
+    pub(crate) macros: HashMap<String, MacroDefinition>,
+
+    /// Delimiters specified on the struct
+    /// that override the configured ones.
+    pub(crate) syntax_overrides: SyntaxOverrides,
+
+    /// Error type specified on the struct with `#[oxiplate_error(...)]`.
+    /// When set, `?` converts errors into it rather than `fmt::Error`.
+    pub(crate) error_type: Option<TokenStream>,
+
+    /// Locale specified on the struct with `#[oxiplate_locale(...)]`
+    /// for translating messages.
+    pub(crate) locale: Option<Locale>,
+
+    /// Raw text element like `<pre>` that static text is currently within
+    /// when minifying HTML.
+    pub(crate) html_raw_text_element: Option<&'static str>,
+
+    /// Where in the HTML document the next writ will be output,
+    /// for choosing the escaper of writs that don't specify one.
+    pub(crate) html_context: HtmlContext,
+
+    /// Static text output since the last line break in static text,
+    /// which indented writs and includes line up with.
+    pub(crate) static_line: String,
+}
+
+impl<'a> State<'a> {
+    /// Create the state for building a template
+    /// that hasn't set an escaper group or defined any macros yet.
+    pub(crate) fn new(
+        config: Config,
+        local_variables: LocalVariables,
+        blocks: &'a VecDeque<&'a HashMap<&'a str, (BuiltTokens, Option<BuiltTokens>)>>,
+    ) -> Self {
+        Self {
+            local_variables,
+            config,
+            inferred_escaper_group: None,
+            default_escaper_group: None,
+            failed_to_set_default_escaper_group: false,
+            blocks,
+            has_content: false,
+            macros: HashMap::new(),
+            syntax_overrides: SyntaxOverrides::default(),
+            error_type: None,
+            locale: None,
+            html_raw_text_element: None,
+            html_context: HtmlContext::default(),
+            static_line: String::new(),
+        }
+    }
+
+    /// Delimiters to use when finding tags in the template.
+    /// The struct's delimiters take priority over the inferred escaper group's,
+    /// which take priority over the ones configured for all templates.
+    pub(crate) fn syntax(&self) -> Syntax {
+        let group_syntax = self
+            .inferred_escaper_group
+            .as_ref()
+            .and_then(|(name, _group)| self.config.escaper_group_syntax.get(name));
+        let syntax = if let Some(group_syntax) = group_syntax {
+            self.config.syntax.with_overrides(group_syntax)
+        } else {
+            self.config.syntax.clone()
+        };
+
+        syntax.with_overrides(&self.syntax_overrides)
+    }
+
+    /// Whether static text should be minified,
+    /// because `minify_html` is turned on
+    /// and the template's output is escaped for HTML by default.
+    pub(crate) fn minifies_html(&self) -> bool {
+        if !self.config.minify_html || self.failed_to_set_default_escaper_group {
+            return false;
+        }
+
+        let name = self
+            .default_escaper_group
+            .as_ref()
+            .or(self.inferred_escaper_group.as_ref())
+            .map(|(name, _group)| name)
+            .or(self.config.fallback_escaper_group.as_ref());
+
+        name.is_some_and(|name| name == "html")
+    }
+
+    /// Whether the HTML context of writs should be tracked
+    /// to choose escapers for them,
+    /// because the template's output is escaped for HTML by default.
+    #[cfg_attr(not(feature = "_oxiplate"), allow(clippy::unused_self))]
+    pub(crate) fn tracks_html_context(&self) -> bool {
+        #[cfg(feature = "_oxiplate")]
+        return self
+            .output_escaper_group()
+            .is_some_and(|group| group.escaper == HTML_ESCAPER);
+
+        #[cfg(not(feature = "_oxiplate"))]
+        false
+    }
+
+    /// Escaper group the template's default escaper belongs to,
+    /// if the template's output is escaped by default.
+    #[cfg(feature = "_oxiplate")]
+    pub(crate) fn output_escaper_group(&self) -> Option<&EscaperGroup> {
+        if self.failed_to_set_default_escaper_group {
+            return None;
+        }
+
+        if let Some((_name, group)) = &self.default_escaper_group {
+            return Some(group);
+        }
+
+        if let Some((_name, group)) = &self.inferred_escaper_group {
+            return Some(group);
+        }
+
+        self.config
+            .fallback_escaper_group
+            .as_ref()
+            .and_then(|name| self.config.escaper_groups.get(name))
+    }
 }
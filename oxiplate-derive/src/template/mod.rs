@@ -1,7 +1,7 @@
 mod parser;
 mod tokenizer;
 
-pub(crate) use self::parser::parse;
+pub(crate) use self::parser::{parse, parse_macros};
 #[cfg(test)]
 pub use self::tokenizer::TokenKind;
-pub use self::tokenizer::{TokenSlice, tokens_and_eof};
+pub use self::tokenizer::{TagKind, TokenSlice, WhitespacePreference, tokens_and_eof};
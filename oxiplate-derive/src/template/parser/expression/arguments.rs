@@ -17,7 +17,7 @@ pub(crate) struct ArgumentsGroup<'a> {
 }
 
 impl<'a> ArgumentsGroup<'a> {
-    pub fn to_tokens(&self, state: &State) -> TokenStream {
+    pub fn to_tokens(&self, state: &mut State) -> TokenStream {
         let mut tokens = TokenStream::new();
 
         if let Some((first_argument, remaining_arguments)) = &self.arguments {
@@ -37,6 +37,21 @@ impl<'a> ArgumentsGroup<'a> {
         group.to_token_stream()
     }
 
+    /// Get each of the arguments in order.
+    pub fn expressions(&self) -> Vec<&ExpressionAccess<'a>> {
+        let Some((first_argument, remaining_arguments)) = &self.arguments else {
+            return vec![];
+        };
+
+        let mut expressions = vec![first_argument.as_ref()];
+        expressions.extend(
+            remaining_arguments
+                .iter()
+                .map(|(_comma, expression)| expression),
+        );
+        expressions
+    }
+
     /// Get the `Source` for the entire arguments group.
     pub fn source(&self) -> &Source<'a> {
         &self.source
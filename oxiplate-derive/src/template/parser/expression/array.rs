@@ -0,0 +1,87 @@
+use proc_macro2::TokenStream;
+use quote::{TokenStreamExt, quote_spanned};
+
+use super::{Expression, ExpressionAccess, Res, expression};
+use crate::parser::{Parser as _, cut, many0, opt, take};
+use crate::template::tokenizer::{Token, TokenKind, TokenSlice};
+use crate::{BuiltTokens, Source, State};
+
+/// An array of comma-separated expressions.
+/// E.g., `[a, b, c]` or `[]`.
+#[derive(Debug)]
+pub struct Array<'a> {
+    /// Each item along with the comma before it, if any.
+    items: Vec<(Option<Source<'a>>, ExpressionAccess<'a>)>,
+    source: Source<'a>,
+}
+
+impl<'a> Array<'a> {
+    /// Parse an array with an optional trailing comma.
+    pub fn parse(tokens: TokenSlice<'a>) -> Res<'a, Expression<'a>> {
+        let (tokens, (open, items, close)) = (
+            take(TokenKind::OpenBracket),
+            opt((
+                expression(true, true),
+                many0((
+                    take(TokenKind::Comma),
+                    cut("Expected an expression after `,`", expression(true, true)),
+                )),
+                opt(take(TokenKind::Comma)),
+            )),
+            cut(
+                "Expected `]` after array items",
+                take(TokenKind::CloseBracket),
+            ),
+        )
+            .parse(tokens)?;
+
+        let mut source = open.source().clone();
+        let mut all_items = vec![];
+        if let Some((first_item, remaining_items, trailing_comma)) = items {
+            source = source.merge(&first_item.source(), "Item expected after `[`");
+            all_items.push((None, first_item));
+
+            for (comma, item) in remaining_items {
+                source = source
+                    .merge(comma.source(), "`,` expected after item")
+                    .merge(&item.source(), "Item expected after `,`");
+                all_items.push((Some(comma.source().clone()), item));
+            }
+
+            source =
+                source.merge_some(trailing_comma.map(Token::source), "`,` expected after item");
+        }
+        let source = source.merge(close.source(), "`]` expected after items");
+
+        Ok((
+            tokens,
+            Expression::Array(Self {
+                items: all_items,
+                source,
+            }),
+        ))
+    }
+
+    /// Source for the entire array.
+    pub fn source(&self) -> &Source<'a> {
+        &self.source
+    }
+
+    /// Build token stream for the array.
+    pub fn to_tokens(&self, state: &mut State) -> BuiltTokens {
+        let mut items = TokenStream::new();
+        for (comma, item) in &self.items {
+            if let Some(comma) = comma {
+                let span = comma.span_token();
+                items.append_all(quote_spanned! {span=> , });
+            }
+            items.append_all(item.to_tokens(state).0);
+        }
+
+        let span = self.source.span_token();
+
+        // Arrays aren't output directly,
+        // so they don't contribute to the estimated length.
+        (quote_spanned! {span=> [ #items ] }, 0)
+    }
+}
@@ -0,0 +1,191 @@
+use quote::{quote, quote_spanned};
+
+use super::{Expression, ExpressionAccess, Res, expression};
+use crate::parser::{Parser as _, alt, cut, many0, opt, take};
+use crate::template::parser::statement::helpers::pattern::Pattern;
+use crate::template::parser::statement::helpers::ty::Type;
+use crate::template::tokenizer::{TokenKind, TokenSlice};
+use crate::{BuiltTokens, Source, State};
+
+/// A closure that can be passed to methods and filters.
+/// E.g., `|item| item.is_visible` or `|| 19`.
+#[derive(Debug)]
+pub struct Closure<'a> {
+    /// The opening `|`, or `||` if there are no parameters.
+    open: Source<'a>,
+    parameters: Vec<Parameter<'a>>,
+    body: Box<ExpressionAccess<'a>>,
+    source: Source<'a>,
+}
+
+/// Parameter of a closure with an optional type.
+/// E.g., `item` or `(index, item): (usize, &Item)`.
+#[derive(Debug)]
+struct Parameter<'a> {
+    pattern: Pattern<'a>,
+    ty: Option<Type<'a>>,
+    source: Source<'a>,
+}
+
+impl<'a> Parameter<'a> {
+    fn parse(tokens: TokenSlice<'a>) -> Res<'a, Self> {
+        let (tokens, (pattern, ty)) = (
+            Pattern::parse,
+            opt((
+                take(TokenKind::Colon),
+                cut("Expected a type after `:`", Type::parse),
+            )),
+        )
+            .parse(tokens)?;
+
+        let (ty, source) = if let Some((colon, ty)) = ty {
+            let source = pattern
+                .source()
+                .clone()
+                .merge(colon.source(), "`:` expected after parameter pattern")
+                .merge(ty.source(), "Type expected after `:`");
+            (Some(ty), source)
+        } else {
+            (None, pattern.source().clone())
+        };
+
+        Ok((
+            tokens,
+            Self {
+                pattern,
+                ty,
+                source,
+            },
+        ))
+    }
+}
+
+impl<'a> Closure<'a> {
+    /// Parse a closure with or without parameters.
+    pub fn parse(tokens: TokenSlice<'a>) -> Res<'a, Expression<'a>> {
+        let (tokens, closure) =
+            alt((Self::parse_without_parameters, Self::parse_with_parameters)).parse(tokens)?;
+
+        Ok((tokens, Expression::Closure(closure)))
+    }
+
+    /// Parse a closure without parameters like `|| 19`.
+    fn parse_without_parameters(tokens: TokenSlice<'a>) -> Res<'a, Self> {
+        let (tokens, (open, body)) = (
+            take(TokenKind::Or),
+            cut("Expected an expression after `||`", expression(true, true)),
+        )
+            .parse(tokens)?;
+
+        let source = open
+            .source()
+            .clone()
+            .merge(&body.source(), "Expression expected after `||`");
+
+        Ok((
+            tokens,
+            Self {
+                open: open.source().clone(),
+                parameters: vec![],
+                body: Box::new(body),
+                source,
+            },
+        ))
+    }
+
+    /// Parse a closure with parameters like `|a, b| a + b`.
+    fn parse_with_parameters(tokens: TokenSlice<'a>) -> Res<'a, Self> {
+        let (tokens, (open, parameters, close, body)) = (
+            take(TokenKind::VerticalBar),
+            opt((
+                Parameter::parse,
+                many0((
+                    take(TokenKind::Comma),
+                    cut("Expected a parameter after `,`", Parameter::parse),
+                )),
+                opt(take(TokenKind::Comma)),
+            )),
+            cut(
+                "Expected `|` after closure parameters",
+                take(TokenKind::VerticalBar),
+            ),
+            cut(
+                "Expected an expression after closure parameters",
+                expression(true, true),
+            ),
+        )
+            .parse(tokens)?;
+
+        let mut source = open.source().clone();
+        let mut all_parameters = vec![];
+        if let Some((first_parameter, remaining_parameters, trailing_comma)) = parameters {
+            source = source.merge(&first_parameter.source, "Parameter expected after `|`");
+            all_parameters.push(first_parameter);
+
+            for (comma, parameter) in remaining_parameters {
+                source = source
+                    .merge(comma.source(), "`,` expected after parameter")
+                    .merge(&parameter.source, "Parameter expected after `,`");
+                all_parameters.push(parameter);
+            }
+
+            if let Some(trailing_comma) = trailing_comma {
+                source = source.merge(trailing_comma.source(), "`,` expected after parameter");
+            }
+        }
+        let source = source
+            .merge(close.source(), "`|` expected after parameters")
+            .merge(&body.source(), "Expression expected after `|`");
+
+        Ok((
+            tokens,
+            Self {
+                open: open.source().clone(),
+                parameters: all_parameters,
+                body: Box::new(body),
+                source,
+            },
+        ))
+    }
+
+    /// Source for the entire closure.
+    pub fn source(&self) -> &Source<'a> {
+        &self.source
+    }
+
+    /// Build token stream for the closure.
+    /// The parameters are only local variables within the body,
+    /// so they're added in a local variable scope that ends with the closure.
+    pub fn to_tokens(&self, state: &mut State) -> BuiltTokens {
+        state.local_variables.push_stack();
+
+        let mut parameters = vec![];
+        for Parameter { pattern, ty, .. } in &self.parameters {
+            state.local_variables.add(
+                pattern
+                    .get_variables()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
+            );
+
+            let pattern = pattern.to_tokens(state);
+            if let Some(ty) = ty {
+                let ty = ty.to_tokens();
+                parameters.push(quote! { #pattern: #ty });
+            } else {
+                parameters.push(pattern);
+            }
+        }
+
+        let (body, _body_length) = self.body.to_tokens(state);
+
+        state.local_variables.pop_stack();
+
+        let span = self.open.span_token();
+
+        // Closures aren't output directly,
+        // so they don't contribute to the estimated length.
+        (quote_spanned! {span=> | #(#parameters),* | #body }, 0)
+    }
+}
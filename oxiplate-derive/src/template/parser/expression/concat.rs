@@ -13,7 +13,7 @@ pub(crate) struct Concat<'a> {
 }
 
 impl<'a> Concat<'a> {
-    pub(super) fn to_tokens(&self, state: &State) -> BuiltTokens {
+    pub(super) fn to_tokens(&self, state: &mut State) -> BuiltTokens {
         {
             let mut format_tokens = vec![];
             let mut argument_tokens = vec![];
@@ -22,8 +22,8 @@ impl<'a> Concat<'a> {
                 match expression {
                     ExpressionAccess {
                         expression: Expression::String(string),
-                        fields,
-                    } if fields.is_empty() => {
+                        postfixes,
+                    } if postfixes.is_empty() => {
                         estimated_length += string.as_str().len();
                         let string =
                             syn::LitStr::new(string.as_str(), string.source().span_token());
@@ -0,0 +1,168 @@
+use proc_macro2::TokenStream;
+use quote::{TokenStreamExt, quote_spanned};
+
+use super::{Expression, ExpressionAccess, KeywordParser, Res, expression};
+use crate::parser::{Parser as _, cut, opt, take};
+use crate::template::tokenizer::{TokenKind, TokenSlice};
+use crate::{BuiltTokens, Source, State};
+
+/// An inline conditional that evaluates to the value of the matching branch.
+/// E.g., `if is_current { "active" } else { "" }`.
+#[derive(Debug)]
+pub struct Conditional<'a> {
+    /// Each `if`/`else if` keyword along with its condition and value.
+    branches: Vec<(Source<'a>, ExpressionAccess<'a>, Branch<'a>)>,
+
+    /// The `else` keyword and the value used when no conditions match.
+    otherwise: (Source<'a>, Branch<'a>),
+
+    /// Source for the entire conditional, from `if` to the last `}`.
+    source: Source<'a>,
+}
+
+/// The value of a branch wrapped in braces.
+/// E.g., `{ "active" }`.
+#[derive(Debug)]
+struct Branch<'a> {
+    expression: Box<ExpressionAccess<'a>>,
+    source: Source<'a>,
+}
+
+impl<'a> Branch<'a> {
+    fn parse(tokens: TokenSlice<'a>) -> Res<'a, Self> {
+        let (tokens, (open, expression, close)) = (
+            take(TokenKind::OpenBrace),
+            cut("Expected an expression", expression(true, true)),
+            cut("Expected `}` after expression", take(TokenKind::CloseBrace)),
+        )
+            .parse(tokens)?;
+
+        let source = open
+            .source()
+            .clone()
+            .merge(&expression.source(), "Expression expected after `{`")
+            .merge(close.source(), "`}` expected after expression");
+
+        Ok((
+            tokens,
+            Self {
+                expression: Box::new(expression),
+                source,
+            },
+        ))
+    }
+
+    fn to_tokens(&self, state: &mut State) -> BuiltTokens {
+        let (expression, estimated_length) = self.expression.to_tokens(state);
+        let span = self.source.span_token();
+        (quote_spanned! {span=> { #expression } }, estimated_length)
+    }
+}
+
+impl<'a> Conditional<'a> {
+    /// Parse an inline conditional.
+    pub fn parse(tokens: TokenSlice<'a>) -> Res<'a, Expression<'a>> {
+        let (mut tokens, (keyword, condition, branch)) = (
+            KeywordParser::new("if"),
+            cut("Expected a condition after `if`", expression(true, true)),
+            cut("Expected `{` followed by a value", Branch::parse),
+        )
+            .parse(tokens)?;
+
+        let mut source = keyword
+            .source()
+            .clone()
+            .merge(&condition.source(), "Condition expected after `if`")
+            .merge(&branch.source, "Value expected after condition");
+        let mut branches = vec![(keyword.source().clone(), condition, branch)];
+
+        // Parse any `else if` branches until the final `else`
+        // since a value is required regardless of which conditions match.
+        let otherwise = loop {
+            let (remaining_tokens, (else_keyword, if_keyword)) = (
+                cut(
+                    "Expected `else` followed by a value; inline `if` expressions must always \
+                     produce a value",
+                    KeywordParser::new("else"),
+                ),
+                opt(KeywordParser::new("if")),
+            )
+                .parse(tokens)?;
+
+            source = source.merge(else_keyword.source(), "`else` expected after value");
+
+            let Some(if_keyword) = if_keyword else {
+                let (remaining_tokens, branch) =
+                    cut("Expected `{` followed by a value", Branch::parse)
+                        .parse(remaining_tokens)?;
+                tokens = remaining_tokens;
+
+                source = source.merge(&branch.source, "Value expected after `else`");
+
+                break (else_keyword.source().clone(), branch);
+            };
+
+            let (remaining_tokens, (condition, branch)) = (
+                cut("Expected a condition after `if`", expression(true, true)),
+                cut("Expected `{` followed by a value", Branch::parse),
+            )
+                .parse(remaining_tokens)?;
+            tokens = remaining_tokens;
+
+            source = source
+                .merge(if_keyword.source(), "`if` expected after `else`")
+                .merge(&condition.source(), "Condition expected after `if`")
+                .merge(&branch.source, "Value expected after condition");
+
+            let keyword = else_keyword
+                .source()
+                .clone()
+                .merge(if_keyword.source(), "`if` expected after `else`");
+            branches.push((keyword, condition, branch));
+        };
+
+        Ok((
+            tokens,
+            Expression::Conditional(Self {
+                branches,
+                otherwise,
+                source,
+            }),
+        ))
+    }
+
+    /// Source for the entire conditional.
+    pub fn source(&self) -> &Source<'a> {
+        &self.source
+    }
+
+    /// Build token stream for the conditional.
+    /// Rust checks that every branch evaluates to the same type
+    /// and points any mismatches at the offending branch.
+    pub fn to_tokens(&self, state: &mut State) -> BuiltTokens {
+        let mut tokens = TokenStream::new();
+        let mut estimated_length = usize::MAX;
+
+        for (index, (keyword, condition, branch)) in self.branches.iter().enumerate() {
+            let span = keyword.span_token();
+            let (condition, _condition_length) = condition.to_tokens(state);
+            let (branch, branch_length) = branch.to_tokens(state);
+            estimated_length = estimated_length.min(branch_length);
+
+            if index == 0 {
+                tokens.append_all(quote_spanned! {span=> if #condition #branch });
+            } else {
+                tokens.append_all(quote_spanned! {span=> else if #condition #branch });
+            }
+        }
+
+        let (keyword, branch) = &self.otherwise;
+        let span = keyword.span_token();
+        let (branch, branch_length) = branch.to_tokens(state);
+        estimated_length = estimated_length.min(branch_length);
+        tokens.append_all(quote_spanned! {span=> else #branch });
+
+        let span = self.source.span_token();
+        (quote_spanned! {span=> ( #tokens ) }, estimated_length)
+    }
+}
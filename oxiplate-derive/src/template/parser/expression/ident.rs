@@ -20,7 +20,7 @@ pub(crate) fn identifier(tokens: TokenSlice) -> Res<Expression> {
     Ok((tokens, Expression::Identifier(field)))
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct Identifier<'a> {
     source: &'a Source<'a>,
 }
@@ -86,7 +86,7 @@ pub(crate) enum IdentifierOrFunction<'a> {
     Function(Identifier<'a>, ArgumentsGroup<'a>),
 }
 impl<'a> IdentifierOrFunction<'a> {
-    pub fn to_tokens(&self, state: &State) -> TokenStream {
+    pub fn to_tokens(&self, state: &mut State) -> TokenStream {
         let mut tokens = TokenStream::new();
 
         match self {
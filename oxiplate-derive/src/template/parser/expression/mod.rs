@@ -3,28 +3,36 @@ use quote::{ToTokens, TokenStreamExt, quote, quote_spanned};
 use syn::token::Dot;
 
 mod arguments;
+mod array;
+mod closure;
 mod concat;
+mod conditional;
 mod group;
 mod ident;
 mod keyword;
 mod literal;
 mod operator;
 mod prefix_operator;
+mod r#struct;
 mod tuple;
 
-use self::arguments::arguments;
+pub(super) use self::arguments::{ArgumentsGroup, arguments};
+use self::array::Array;
+use self::closure::Closure;
 use self::concat::Concat;
+use self::conditional::Conditional;
 use self::ident::IdentifierOrFunction;
 pub(super) use self::ident::{Identifier, identifier};
 pub(super) use self::keyword::{Keyword, KeywordParser};
 pub(super) use self::literal::{Bool, Char, Float, Integer, Number, String};
+use self::r#struct::Struct;
 use super::Res;
-use super::expression::arguments::ArgumentsGroup;
 use super::expression::operator::{Operator, parse_operator};
 use super::expression::prefix_operator::{PrefixOperator, parse_prefixed_expression};
 use crate::parser::{Parser as _, alt, context, cut, fail, into, many0, many1, opt, take};
 use crate::template::parser::expression::group::Group;
 use crate::template::parser::expression::tuple::Tuple;
+use crate::template::parser::statement::helpers::ty::Type;
 use crate::template::tokenizer::{Token, TokenKind, TokenSlice};
 use crate::{BuiltTokens, Source, State};
 
@@ -34,7 +42,7 @@ pub(crate) struct Field<'a> {
     ident_or_fn: IdentifierOrFunction<'a>,
 }
 impl<'a> Field<'a> {
-    pub fn to_tokens(&self, state: &State) -> TokenStream {
+    pub fn to_tokens(&self, state: &mut State) -> TokenStream {
         let span = self.dot.span_token();
         let dot = syn::parse2::<Dot>(quote_spanned! {span=> . })
             .expect("Dot should be able to be parsed properly here");
@@ -52,6 +60,66 @@ impl<'a> Field<'a> {
     }
 }
 
+/// Something applied to the end of an expression.
+#[derive(Debug)]
+pub(crate) enum Postfix<'a> {
+    /// `.field` or `.method()`
+    Field(Field<'a>),
+
+    /// `?` that ends rendering with an error if the value is an `Err`.
+    /// The error is converted into the template's error type if it has one,
+    /// otherwise it's replaced with `fmt::Error`.
+    Try(Source<'a>),
+
+    /// `as Type`
+    Cast(Keyword<'a>, Type<'a>),
+}
+
+impl<'a> Postfix<'a> {
+    /// Wrap or append to the tokens of everything before the postfix.
+    fn apply(&self, mut tokens: TokenStream, state: &mut State) -> TokenStream {
+        match self {
+            Postfix::Field(field) => {
+                tokens.append_all(field.to_tokens(state));
+                tokens
+            }
+            Postfix::Try(question_mark) => {
+                let span = question_mark.span_token();
+                if state.error_type.is_some() {
+                    quote_spanned! {span=> #tokens? }
+                } else {
+                    quote_spanned! {span=>
+                        ::core::result::Result::map_err(#tokens, |_| ::core::fmt::Error)?
+                    }
+                }
+            }
+            Postfix::Cast(keyword, ty) => {
+                tokens.append_all(quote! { #keyword });
+                tokens.append_all(ty.to_tokens());
+                tokens
+            }
+        }
+    }
+
+    /// Get the `Source` for the postfix.
+    fn source(&self) -> Source<'a> {
+        match self {
+            Postfix::Field(field) => field.source(),
+            Postfix::Try(source) => source.clone(),
+            Postfix::Cast(keyword, ty) => keyword
+                .source()
+                .clone()
+                .merge(ty.source(), "Type should follow `as`"),
+        }
+    }
+}
+
+impl<'a> From<Field<'a>> for Postfix<'a> {
+    fn from(field: Field<'a>) -> Self {
+        Postfix::Field(field)
+    }
+}
+
 #[derive(Debug)]
 pub(crate) enum Expression<'a> {
     Identifier(IdentifierOrFunction<'a>),
@@ -62,7 +130,19 @@ pub(crate) enum Expression<'a> {
     Bool(Bool<'a>),
     Group(Group<'a>),
     Tuple(Tuple<'a>),
+
+    /// `[expr, expr]`
+    Array(Array<'a>),
+
+    /// `Path { field: expr }`, `Path(expr)`, or `Path::Variant`
+    Struct(Struct<'a>),
     Concat(Concat<'a>),
+
+    /// `if expr { expr } else { expr }`
+    Conditional(Conditional<'a>),
+
+    /// `|pattern| expr`
+    Closure(Closure<'a>),
     Calc {
         left: Box<ExpressionAccess<'a>>,
         operator: Operator<'a>,
@@ -106,31 +186,16 @@ pub(crate) enum Expression<'a> {
 }
 
 impl<'a> Expression<'a> {
-    pub(crate) fn to_tokens(&self, state: &State) -> BuiltTokens {
+    pub(crate) fn to_tokens(&self, state: &mut State) -> BuiltTokens {
         match self {
-            Expression::Identifier(identifier) => match &identifier {
-                IdentifierOrFunction::Identifier(identifier) => {
-                    let span = identifier.source().span_token();
-                    if state.local_variables.contains(identifier.as_str()) {
-                        (quote! { #identifier }, 1)
-                    } else {
-                        (quote_spanned! {span=> self.#identifier }, 1)
-                    }
-                }
-                IdentifierOrFunction::Function(identifier, arguments) => {
-                    let arguments = arguments.to_tokens(state);
-
-                    let span = identifier.source().span_token();
-                    if state.local_variables.contains(identifier.as_str()) {
-                        (quote! { #identifier #arguments }, 1)
-                    } else {
-                        (quote_spanned! {span=> (self.#identifier)#arguments }, 1)
-                    }
-                }
-            },
+            Expression::Identifier(identifier) => Self::identifier(state, identifier),
             Expression::Group(group) => group.to_tokens(state),
             Expression::Tuple(tuple) => tuple.to_tokens(state),
+            Expression::Array(array) => array.to_tokens(state),
+            Expression::Struct(r#struct) => r#struct.to_tokens(state),
             Expression::Concat(concat) => concat.to_tokens(state),
+            Expression::Conditional(conditional) => conditional.to_tokens(state),
+            Expression::Closure(closure) => closure.to_tokens(state),
             Expression::Calc {
                 left,
                 operator,
@@ -208,9 +273,51 @@ impl<'a> Expression<'a> {
         }
     }
 
+    /// Generate tokens for a variable or function,
+    /// accessing it from `self` unless it's a local variable.
+    fn identifier(state: &mut State, identifier: &IdentifierOrFunction) -> BuiltTokens {
+        match identifier {
+            IdentifierOrFunction::Identifier(identifier) => {
+                let span = identifier.source().span_token();
+                if state.local_variables.contains(identifier.as_str()) {
+                    (quote! { #identifier }, 1)
+                } else if state.local_variables.is_isolated() {
+                    (Self::isolated_error(identifier), 1)
+                } else {
+                    (quote_spanned! {span=> self.#identifier }, 1)
+                }
+            }
+            IdentifierOrFunction::Function(identifier, arguments) => {
+                let arguments = arguments.to_tokens(state);
+
+                let span = identifier.source().span_token();
+                if state.local_variables.contains(identifier.as_str()) {
+                    (quote! { #identifier #arguments }, 1)
+                } else if state.local_variables.is_isolated() {
+                    (Self::isolated_error(identifier), 1)
+                } else {
+                    (quote_spanned! {span=> (self.#identifier)#arguments }, 1)
+                }
+            }
+        }
+    }
+
+    /// Generate an error for a variable that isn't available
+    /// because the template was included with `only`.
+    fn isolated_error(identifier: &Identifier) -> TokenStream {
+        let span = identifier.source().span_token();
+        let message = format!(
+            "`{}` is not available because this template was included with `only`; pass it in \
+             with `with {{ {}: ... }}`",
+            identifier.as_str(),
+            identifier.as_str(),
+        );
+        quote_spanned! {span=> compile_error!(#message) }
+    }
+
     /// Generate tokens for a filter expression.
     fn filter(
-        state: &State,
+        state: &mut State,
         name: &Identifier,
         expression: &ExpressionAccess,
         vertical_bar: &Source,
@@ -218,7 +325,29 @@ impl<'a> Expression<'a> {
         arguments: Option<&ArgumentsGroup>,
         source: &Source,
     ) -> BuiltTokens {
-        let (expression, estimated_length) = expression.to_tokens(state);
+        let expression = expression.to_tokens(state);
+        Self::filter_tokens(
+            state,
+            name,
+            expression,
+            vertical_bar,
+            cow_prefix,
+            arguments,
+            source,
+        )
+    }
+
+    /// Generate tokens for a filter call
+    /// applied to the already built tokens of the value being filtered.
+    pub(crate) fn filter_tokens(
+        state: &mut State,
+        name: &Identifier,
+        (expression, estimated_length): BuiltTokens,
+        vertical_bar: &Source,
+        cow_prefix: Option<&Source>,
+        arguments: Option<&ArgumentsGroup>,
+        source: &Source,
+    ) -> BuiltTokens {
         let mut argument_tokens = expression;
 
         let arguments = if let Some(arguments) = arguments {
@@ -297,7 +426,11 @@ impl<'a> Expression<'a> {
             | Expression::Cow { source, .. } => source.clone(),
             Expression::Group(group) => group.source().clone(),
             Expression::Tuple(tuple) => tuple.source().clone(),
+            Expression::Array(array) => array.source().clone(),
+            Expression::Struct(r#struct) => r#struct.source().clone(),
             Expression::Concat(concat) => concat.source().clone(),
+            Expression::Conditional(conditional) => conditional.source().clone(),
+            Expression::Closure(closure) => closure.source().clone(),
             Expression::Prefixed(prefix_operator, expression) => prefix_operator
                 .source()
                 .clone()
@@ -311,41 +444,138 @@ impl<'a> Expression<'a> {
     }
 }
 
+/// Optional namespace, name, and arguments of a possible macro call.
+pub(crate) type MacroCall<'b, 'a> = (
+    Option<&'b Identifier<'a>>,
+    &'b Identifier<'a>,
+    &'b ArgumentsGroup<'a>,
+);
+
 #[derive(Debug)]
 pub(crate) struct ExpressionAccess<'a> {
     expression: Expression<'a>,
-    fields: Vec<Field<'a>>,
+    postfixes: Vec<Postfix<'a>>,
 }
 impl<'a> ExpressionAccess<'a> {
-    pub(crate) fn to_tokens(&self, state: &State) -> BuiltTokens {
-        let mut tokens = TokenStream::new();
-        let (expression, estimated_length) = self.expression.to_tokens(state);
-        tokens.append_all(expression);
-        for field in &self.fields {
-            tokens.append_all(field.to_tokens(state));
+    pub(crate) fn to_tokens(&self, state: &mut State) -> BuiltTokens {
+        let (mut tokens, estimated_length) = self.expression.to_tokens(state);
+        for postfix in &self.postfixes {
+            tokens = postfix.apply(tokens, state);
         }
         (tokens, estimated_length)
     }
 
+    /// Get the namespace, name, and arguments if this could be a macro call
+    /// like `foo(bar)` or `ui.foo(bar)` with nothing applied to the result.
+    pub(crate) fn as_macro_call(&self) -> Option<MacroCall<'_, 'a>> {
+        match (&self.expression, self.postfixes.as_slice()) {
+            (Expression::Identifier(IdentifierOrFunction::Function(name, arguments)), []) => {
+                Some((None, name, arguments))
+            }
+            (
+                Expression::Identifier(IdentifierOrFunction::Identifier(namespace)),
+                [
+                    Postfix::Field(Field {
+                        ident_or_fn: IdentifierOrFunction::Function(name, arguments),
+                        ..
+                    }),
+                ],
+            ) => Some((Some(namespace), name, arguments)),
+            _ => None,
+        }
+    }
+
+    /// Get the identifier if this is only a variable like `name`.
+    pub(crate) fn as_identifier(&self) -> Option<&Identifier<'a>> {
+        match (&self.expression, self.postfixes.as_slice()) {
+            (Expression::Identifier(IdentifierOrFunction::Identifier(identifier)), []) => {
+                Some(identifier)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether this is only a literal like `"text"`, `'c'`, `19`, `1.5`, or `true`.
+    pub(crate) fn is_literal(&self) -> bool {
+        matches!(
+            (&self.expression, self.postfixes.as_slice()),
+            (
+                Expression::Char(_)
+                    | Expression::String(_)
+                    | Expression::Integer(_)
+                    | Expression::Float(_)
+                    | Expression::Bool(_),
+                [],
+            )
+        )
+    }
+
+    /// Split `items | loop` or `items | loop_neighbors` with nothing applied to the result
+    /// into the value being filtered
+    /// and a function that applies the same filter to other tokens instead.
+    pub(crate) fn as_loop_filter(
+        &self,
+    ) -> Option<(
+        &ExpressionAccess<'a>,
+        impl Fn(&mut State, BuiltTokens) -> BuiltTokens + '_,
+    )> {
+        match (&self.expression, self.postfixes.as_slice()) {
+            (
+                Expression::Filter {
+                    name,
+                    expression,
+                    vertical_bar,
+                    cow_prefix: None,
+                    arguments,
+                    source,
+                },
+                [],
+            ) if matches!(name.as_str(), "loop" | "loop_neighbors") => Some((
+                expression.as_ref(),
+                move |state: &mut State, tokens: BuiltTokens| {
+                    Expression::filter_tokens(
+                        state,
+                        name,
+                        tokens,
+                        vertical_bar,
+                        None,
+                        arguments.as_ref(),
+                        source,
+                    )
+                },
+            )),
+            _ => None,
+        }
+    }
+
     /// Get the `Source` for expression accesses.
     pub(crate) fn source(&self) -> Source<'a> {
         let mut source: Source<'a> = self.expression.source();
-        for field in &self.fields {
+        for postfix in &self.postfixes {
             source = source.merge(
-                &field.source(),
-                "Field source should be immediately after the rest of the expression",
+                &postfix.source(),
+                "Postfix source should be immediately after the rest of the expression",
             );
         }
         source
     }
 }
 
+impl<'a> From<Identifier<'a>> for ExpressionAccess<'a> {
+    fn from(identifier: Identifier<'a>) -> Self {
+        Self {
+            expression: Expression::Identifier(IdentifierOrFunction::Identifier(identifier)),
+            postfixes: Vec::new(),
+        }
+    }
+}
+
 pub(super) fn expression<'a>(
     allow_generic_nesting: bool,
     allow_concat_nesting: bool,
 ) -> impl Fn(TokenSlice<'a>) -> Res<'a, ExpressionAccess<'a>> {
     move |tokens| {
-        let (tokens, (expression, fields)) = (
+        let (tokens, (expression, postfixes)) = (
             alt((
                 filters(allow_generic_nesting),
                 Concat::parser(allow_concat_nesting),
@@ -356,20 +586,56 @@ pub(super) fn expression<'a>(
                 into(String::parse),
                 into(Number::parse),
                 into(Bool::parse),
+                Conditional::parse,
+                Closure::parse,
+                Struct::parse,
                 identifier,
                 parse_prefixed_expression(allow_generic_nesting),
                 into(Group::parse),
                 Tuple::parse,
+                Array::parse,
                 full_range,
             )),
-            many0(field()),
+            many0(postfix),
         )
             .parse(tokens)?;
 
-        Ok((tokens, ExpressionAccess { expression, fields }))
+        Ok((
+            tokens,
+            ExpressionAccess {
+                expression,
+                postfixes,
+            },
+        ))
     }
 }
 
+type ParsedFilter<'a> = (
+    &'a Token<'a>,
+    Option<&'a Token<'a>>,
+    Identifier<'a>,
+    Option<ArgumentsGroup<'a>>,
+);
+
+fn postfix(tokens: TokenSlice) -> Res<Postfix> {
+    alt((
+        into(field()),
+        |tokens| {
+            let (tokens, question_mark) = take(TokenKind::Question).parse(tokens)?;
+            Ok((tokens, Postfix::Try(question_mark.source().clone())))
+        },
+        |tokens| {
+            let (tokens, (keyword, ty)) = (
+                KeywordParser::new("as"),
+                cut("Expected a type after `as`", Type::parse),
+            )
+                .parse(tokens)?;
+            Ok((tokens, Postfix::Cast(keyword, ty)))
+        },
+    ))
+    .parse(tokens)
+}
+
 fn field<'a>() -> impl Fn(TokenSlice<'a>) -> Res<'a, Field<'a>> + 'a {
     |tokens| {
         let (tokens, (dot, ident, arguments)) =
@@ -482,6 +748,28 @@ fn index<'a>(allow_generic_nesting: bool) -> impl Fn(TokenSlice<'a>) -> Res<'a,
     }
 }
 
+/// Parses a single filter (`| filter()`) after an expression.
+/// A `|` that isn't followed by a filter name is left for bitwise or (`flags | 0b100`).
+fn filter(tokens: TokenSlice) -> Res<ParsedFilter> {
+    let (tokens, (vertical_bar, cow_prefix)) = (
+        take(TokenKind::VerticalBar),
+        opt(take(TokenKind::GreaterThan)),
+    )
+        .parse(tokens)?;
+
+    let (tokens, (name, arguments)) = if cow_prefix.is_some() {
+        (
+            cut("Expected a filter name", Identifier::parse),
+            opt(arguments),
+        )
+            .parse(tokens)?
+    } else {
+        (Identifier::parse, opt(arguments)).parse(tokens)?
+    };
+
+    Ok((tokens, (vertical_bar, cow_prefix, name, arguments)))
+}
+
 /// Parses filters (`expr | filter()`).
 fn filters<'a>(allow_generic_nesting: bool) -> impl Fn(TokenSlice<'a>) -> Res<'a, Expression<'a>> {
     move |tokens| {
@@ -493,16 +781,8 @@ fn filters<'a>(allow_generic_nesting: bool) -> impl Fn(TokenSlice<'a>) -> Res<'a
             .parse(tokens);
         }
 
-        let (tokens, (expression, filters)) = (
-            expression(false, false),
-            many1((
-                take(TokenKind::VerticalBar),
-                opt(take(TokenKind::GreaterThan)),
-                cut("Expected a filter name", Identifier::parse),
-                opt(arguments),
-            )),
-        )
-            .parse(tokens)?;
+        let (tokens, (expression, filters)) =
+            (expression(false, false), many1(filter)).parse(tokens)?;
 
         let mut source = expression.source();
         let mut expression_access = expression;
@@ -531,7 +811,7 @@ fn filters<'a>(allow_generic_nesting: bool) -> impl Fn(TokenSlice<'a>) -> Res<'a
                     arguments,
                     source: source.clone(),
                 },
-                fields: Vec::new(),
+                postfixes: Vec::new(),
             }
         }
 
@@ -2,11 +2,48 @@ use proc_macro2::TokenStream;
 use quote::{ToTokens, TokenStreamExt, quote_spanned};
 
 use super::super::Res;
-use crate::parser::{Parser as _, alt, take};
+use crate::parser::{Parser as _, alt, context, fail, take};
 use crate::template::tokenizer::{TokenKind, TokenSlice};
 use crate::{Source, internal_error};
 
 pub(super) fn parse_operator(tokens: TokenSlice) -> Res<Operator> {
+    alt((parse_shift_operator, parse_single_token_operator)).parse(tokens)
+}
+
+/// Parses `<<` and `>>`.
+/// These are tokenized as two separate `<` or `>`
+/// so closing multiple generics like `Vec<Vec<u8>>` in types still works,
+/// so the second one must immediately follow the first.
+fn parse_shift_operator(tokens: TokenSlice) -> Res<Operator> {
+    let (remaining_tokens, (first, second)) = alt((
+        (take(TokenKind::LessThan), take(TokenKind::LessThan)),
+        (take(TokenKind::GreaterThan), take(TokenKind::GreaterThan)),
+    ))
+    .parse(tokens.clone())?;
+
+    if second.source().range_full().start != second.source().range_token().start {
+        return context("Expected shift operator without whitespace", fail()).parse(tokens);
+    }
+
+    let kind = if *first.kind() == TokenKind::LessThan {
+        OperatorKind::ShiftLeft
+    } else {
+        OperatorKind::ShiftRight
+    };
+
+    Ok((
+        remaining_tokens,
+        Operator {
+            source: first
+                .source()
+                .clone()
+                .merge(second.source(), "Second character of operator expected"),
+            kind,
+        },
+    ))
+}
+
+fn parse_single_token_operator(tokens: TokenSlice) -> Res<Operator> {
     let (tokens, token) = alt((
         take(TokenKind::Plus),
         take(TokenKind::Minus),
@@ -21,6 +58,9 @@ pub(super) fn parse_operator(tokens: TokenSlice) -> Res<Operator> {
         take(TokenKind::LessThan),
         take(TokenKind::Or),
         take(TokenKind::And),
+        take(TokenKind::Ampersand),
+        take(TokenKind::VerticalBar),
+        take(TokenKind::Caret),
         take(TokenKind::RangeInclusive),
         take(TokenKind::RangeExclusive),
         #[cfg(feature = "_unreachable")]
@@ -31,7 +71,7 @@ pub(super) fn parse_operator(tokens: TokenSlice) -> Res<Operator> {
     macro_rules! op {
         ($variant:ident, $operator:ident, $token:ident) => {
             Operator {
-                source: $token.source(),
+                source: $token.source().clone(),
                 kind: OperatorKind::$variant,
             }
         };
@@ -55,6 +95,10 @@ pub(super) fn parse_operator(tokens: TokenSlice) -> Res<Operator> {
         "||" => op!(Or, operator, token),
         "&&" => op!(And, operator, token),
 
+        "&" => op!(BitAnd, operator, token),
+        "|" => op!(BitOr, operator, token),
+        "^" => op!(BitXor, operator, token),
+
         "..=" => op!(RangeInclusive, operator, token),
         ".." => op!(RangeExclusive, operator, token),
 
@@ -68,7 +112,7 @@ pub(super) fn parse_operator(tokens: TokenSlice) -> Res<Operator> {
 
 #[derive(Debug)]
 pub(crate) struct Operator<'a> {
-    source: &'a Source<'a>,
+    source: Source<'a>,
     kind: OperatorKind,
 }
 
@@ -90,6 +134,13 @@ enum OperatorKind {
     Or,
     And,
 
+    BitAnd,
+    /// `|` when not followed by a filter name.
+    BitOr,
+    BitXor,
+    ShiftLeft,
+    ShiftRight,
+
     /// `start..=end` that matches all values where `start <= x <= end`.
     /// See: <https://doc.rust-lang.org/core/ops/struct.RangeInclusive.html>
     RangeInclusive,
@@ -116,6 +167,11 @@ impl<'a> Operator<'a> {
             | OperatorKind::LessThanOrEqual
             | OperatorKind::Or
             | OperatorKind::And
+            | OperatorKind::BitAnd
+            | OperatorKind::BitOr
+            | OperatorKind::BitXor
+            | OperatorKind::ShiftLeft
+            | OperatorKind::ShiftRight
             | OperatorKind::RangeInclusive => true,
 
             // `expr..` is valid as well as `expr..expr`.
@@ -124,8 +180,8 @@ impl<'a> Operator<'a> {
     }
 
     /// Get the `Source` for the operator and any leading whitespace.
-    pub fn source(&self) -> &'a Source<'a> {
-        self.source
+    pub fn source(&self) -> &Source<'a> {
+        &self.source
     }
 }
 
@@ -155,6 +211,12 @@ impl ToTokens for Operator<'_> {
             OperatorKind::Or => quote_op!(||),
             OperatorKind::And => quote_op!(&&),
 
+            OperatorKind::BitAnd => quote_op!(&),
+            OperatorKind::BitOr => quote_op!(|),
+            OperatorKind::BitXor => quote_op!(^),
+            OperatorKind::ShiftLeft => quote_op!(<<),
+            OperatorKind::ShiftRight => quote_op!(>>),
+
             OperatorKind::RangeInclusive => quote_op!(..=),
             OperatorKind::RangeExclusive => quote_op!(..),
         });
@@ -0,0 +1,251 @@
+use proc_macro2::TokenStream;
+use quote::{TokenStreamExt, quote, quote_spanned};
+
+use super::{ArgumentsGroup, Expression, ExpressionAccess, Identifier, Res, arguments, expression};
+use crate::parser::{Parser as _, alt, context, cut, fail, into, many0, opt, take};
+use crate::template::parser::expression::ident::IdentifierOrFunction;
+use crate::template::parser::statement::helpers::pattern::Path;
+use crate::template::tokenizer::{Token, TokenKind, TokenSlice};
+use crate::{BuiltTokens, Source, State};
+
+/// A struct, tuple struct, or unit variant built from a path.
+/// Paths are used as written like they are in `if let` patterns,
+/// so they must either contain `::` or start with a capital letter
+/// to be distinguished from variables and functions.
+/// E.g., `Link { href, text: "Home" }`, `Some(value)`, or `Kind::Active`.
+#[derive(Debug)]
+pub(crate) enum Struct<'a> {
+    Named(NamedStruct<'a>),
+    Tuple(TupleStruct<'a>),
+    Unit(Path<'a>),
+}
+
+impl<'a> Struct<'a> {
+    pub fn parse(tokens: TokenSlice<'a>) -> Res<'a, Expression<'a>> {
+        let (tokens, r#struct) = alt((
+            into(NamedStruct::parse),
+            into(TupleStruct::parse),
+            into(Path::parse_exclude_ident),
+        ))
+        .parse(tokens)?;
+
+        Ok((tokens, Expression::Struct(r#struct)))
+    }
+
+    pub fn source(&self) -> &Source<'a> {
+        match self {
+            Self::Named(named_struct) => &named_struct.source,
+            Self::Tuple(tuple_struct) => &tuple_struct.source,
+            Self::Unit(path) => path.source(),
+        }
+    }
+
+    pub fn to_tokens(&self, state: &mut State) -> BuiltTokens {
+        let tokens = match self {
+            Self::Named(named_struct) => named_struct.to_tokens(state),
+            Self::Tuple(tuple_struct) => tuple_struct.to_tokens(state),
+            Self::Unit(path) => path.to_tokens(),
+        };
+
+        // Structs aren't output directly,
+        // so they don't contribute to the estimated length.
+        (tokens, 0)
+    }
+}
+
+impl<'a> From<Path<'a>> for Struct<'a> {
+    fn from(value: Path<'a>) -> Self {
+        Self::Unit(value)
+    }
+}
+
+/// Parse a path that names a type or variant rather than a variable.
+fn type_path(tokens: TokenSlice) -> Res<Path> {
+    let (remaining_tokens, path) = Path::parse_include_ident.parse(tokens.clone())?;
+
+    if !path.is_type() {
+        return context("Expected a struct or variant name", fail()).parse(tokens);
+    }
+
+    Ok((remaining_tokens, path))
+}
+
+/// A struct with named fields.
+/// E.g., `Link { href: url, text }`.
+#[derive(Debug)]
+pub(crate) struct NamedStruct<'a> {
+    path: Path<'a>,
+    /// Each field along with the comma before it, if any.
+    fields: Vec<(Option<Source<'a>>, Field<'a>)>,
+    source: Source<'a>,
+}
+
+impl<'a> NamedStruct<'a> {
+    fn parse(tokens: TokenSlice<'a>) -> Res<'a, Self> {
+        // The closing brace isn't cut so inline `if` conditions
+        // like `kind == Kind::Link { "link" }` are still parsed as conditions.
+        let (tokens, (path, open, fields, close)) = (
+            type_path,
+            take(TokenKind::OpenBrace),
+            opt((
+                Field::parse,
+                many0((take(TokenKind::Comma), Field::parse)),
+                opt(take(TokenKind::Comma)),
+            )),
+            take(TokenKind::CloseBrace),
+        )
+            .parse(tokens)?;
+
+        let mut source = path
+            .source()
+            .clone()
+            .merge(open.source(), "`{` expected after path");
+        let mut all_fields = vec![];
+        if let Some((first_field, remaining_fields, trailing_comma)) = fields {
+            source = source.merge(&first_field.source, "Field expected after `{`");
+            all_fields.push((None, first_field));
+
+            for (comma, field) in remaining_fields {
+                source = source
+                    .merge(comma.source(), "`,` expected after field")
+                    .merge(&field.source, "Field expected after `,`");
+                all_fields.push((Some(comma.source().clone()), field));
+            }
+
+            source = source.merge_some(
+                trailing_comma.map(Token::source),
+                "`,` expected after field",
+            );
+        }
+        let source = source.merge(close.source(), "`}` expected after fields");
+
+        Ok((
+            tokens,
+            Self {
+                path,
+                fields: all_fields,
+                source,
+            },
+        ))
+    }
+
+    fn to_tokens(&self, state: &mut State) -> TokenStream {
+        let path = self.path.to_tokens();
+
+        let mut fields = TokenStream::new();
+        for (comma, field) in &self.fields {
+            if let Some(comma) = comma {
+                let span = comma.span_token();
+                fields.append_all(quote_spanned! {span=> , });
+            }
+            fields.append_all(field.to_tokens(state));
+        }
+
+        let span = self.source.span_token();
+        quote_spanned! {span=> #path { #fields } }
+    }
+}
+
+impl<'a> From<NamedStruct<'a>> for Struct<'a> {
+    fn from(value: NamedStruct<'a>) -> Self {
+        Self::Named(value)
+    }
+}
+
+/// A field of a named struct with an optional value.
+/// E.g., `text: "Home"` or `href`.
+#[derive(Debug)]
+struct Field<'a> {
+    name: Identifier<'a>,
+    value: Option<ExpressionAccess<'a>>,
+    source: Source<'a>,
+}
+
+impl<'a> Field<'a> {
+    fn parse(tokens: TokenSlice<'a>) -> Res<'a, Self> {
+        let (tokens, (name, value)) = (
+            Identifier::parse,
+            opt((
+                take(TokenKind::Colon),
+                cut("Expected an expression after `:`", expression(true, true)),
+            )),
+        )
+            .parse(tokens)?;
+
+        let (value, source) = if let Some((colon, value)) = value {
+            let source = name
+                .source()
+                .clone()
+                .merge(colon.source(), "`:` expected after field name")
+                .merge(&value.source(), "Expression expected after `:`");
+            (Some(value), source)
+        } else {
+            (None, name.source().clone())
+        };
+
+        Ok((
+            tokens,
+            Self {
+                name,
+                value,
+                source,
+            },
+        ))
+    }
+
+    /// Build the field with its value.
+    /// Shorthand fields are expanded so the value is looked up
+    /// the same way as any other variable.
+    fn to_tokens(&self, state: &mut State) -> TokenStream {
+        let name = &self.name;
+        let (value, _value_length) = match &self.value {
+            Some(value) => value.to_tokens(state),
+            None if state.local_variables.contains(name.as_str()) => return quote! { #name },
+            None => Expression::identifier(state, &IdentifierOrFunction::Identifier(name.clone())),
+        };
+
+        let span = self.source.span_token();
+        quote_spanned! {span=> #name: #value }
+    }
+}
+
+/// A tuple struct or tuple variant constructor.
+/// E.g., `Some(value)` or `Tab::Selected(index)`.
+#[derive(Debug)]
+pub(crate) struct TupleStruct<'a> {
+    path: Path<'a>,
+    arguments: ArgumentsGroup<'a>,
+    source: Source<'a>,
+}
+
+impl<'a> TupleStruct<'a> {
+    fn parse(tokens: TokenSlice<'a>) -> Res<'a, Self> {
+        let (tokens, (path, arguments)) = (type_path, arguments).parse(tokens)?;
+
+        let source = path
+            .source()
+            .clone()
+            .merge(arguments.source(), "Arguments expected after path");
+
+        Ok((
+            tokens,
+            Self {
+                path,
+                arguments,
+                source,
+            },
+        ))
+    }
+
+    fn to_tokens(&self, state: &mut State) -> TokenStream {
+        let path = self.path.to_tokens();
+        let arguments = self.arguments.to_tokens(state);
+        quote! { #path #arguments }
+    }
+}
+
+impl<'a> From<TupleStruct<'a>> for Struct<'a> {
+    fn from(value: TupleStruct<'a>) -> Self {
+        Self::Tuple(value)
+    }
+}
@@ -53,7 +53,7 @@ impl<'a> Tuple<'a> {
         &self.source
     }
 
-    pub fn to_tokens(&self, state: &State) -> BuiltTokens {
+    pub fn to_tokens(&self, state: &mut State) -> BuiltTokens {
         let mut items = vec![];
         let span = self.source.span_token();
         let mut expression_length = usize::MAX;
@@ -107,7 +107,7 @@ impl<'a> TupleItem<'a> {
         }
     }
 
-    pub fn to_tokens(&self, state: &State) -> BuiltTokens {
+    pub fn to_tokens(&self, state: &mut State) -> BuiltTokens {
         let (expression, expression_length) = self.expression.to_tokens(state);
         let comma = self.comma.clone().map_or_else(TokenStream::new, |comma| {
             let span = comma.span_token();
@@ -9,7 +9,7 @@ use super::{Statement, Static, Writ};
 use crate::parser::{Error, Parser as _, cut, opt, take};
 use crate::template::parser::Res;
 use crate::template::parser::statement::StatementKind;
-use crate::template::tokenizer::{TagKind, TokenKind, TokenSlice, WhitespacePreference};
+use crate::template::tokenizer::{TagKind, Token, TokenKind, TokenSlice, WhitespacePreference};
 use crate::{Source, State};
 
 pub(super) enum ItemToken {
@@ -109,7 +109,7 @@ impl<'a> Item<'a> {
                 ItemToken::Statement(quote! { #statement_tokens }, estimated_length)
             }
             Item::Static(text, _static_type) => {
-                let (text, estimated_length) = text.to_token();
+                let (text, estimated_length) = text.to_token(state);
                 state.has_content = true;
                 ItemToken::StaticText(text, estimated_length)
             }
@@ -117,7 +117,7 @@ impl<'a> Item<'a> {
                 if whitespace.0.is_empty() {
                     ItemToken::Comment
                 } else {
-                    let (text, estimated_length) = whitespace.to_token();
+                    let (text, estimated_length) = whitespace.to_token(state);
                     ItemToken::StaticText(text, estimated_length)
                 }
             }
@@ -206,6 +206,15 @@ pub(crate) fn tag_start(tokens: TokenSlice) -> Res<(Option<Item>, TagOpen, Sourc
             )
         }
         WhitespacePreference::Remove => (None, leading_whitespace),
+        WhitespacePreference::TrimLine => (
+            leading_whitespace.map(|whitespace| {
+                Static(
+                    without_indentation(whitespace.source()),
+                    whitespace.source().clone(),
+                )
+            }),
+            None,
+        ),
         WhitespacePreference::Indifferent => (
             leading_whitespace.map(|whitespace| {
                 Static(whitespace.source().as_str(), whitespace.source().clone())
@@ -222,6 +231,54 @@ pub(crate) fn tag_start(tokens: TokenSlice) -> Res<(Option<Item>, TagOpen, Sourc
     Ok((tokens, (whitespace.map(Item::Whitespace), open, source)))
 }
 
+/// Whitespace before a tag without the indentation on the tag's line,
+/// if the tag starts a line.
+fn without_indentation<'a>(whitespace: &Source<'a>) -> &'a str {
+    let text = whitespace.as_str();
+    if let Some(index) = text.rfind(['\n', '\r']) {
+        &text[..=index]
+    } else if whitespace.range_token().start == 0 {
+        ""
+    } else {
+        text
+    }
+}
+
+/// Text without the line break at the start of it, if any.
+fn without_line_break(text: &str) -> &str {
+    text.strip_prefix("\r\n")
+        .or_else(|| text.strip_prefix('\n'))
+        .unwrap_or(text)
+}
+
+/// Whitespace after a tag without the line break directly after it.
+/// The whitespace is consumed here,
+/// so the indentation before the next tag is removed here as well
+/// if the next tag also trims its line.
+fn parse_trimmed_line<'a>(
+    tokens: TokenSlice<'a>,
+    next_whitespace_preference: &WhitespacePreference,
+) -> Res<'a, Option<Item<'a>>> {
+    let (tokens, trailing_whitespace) = opt(take(TokenKind::StaticWhitespace)).parse(tokens)?;
+    let Some(trailing_whitespace) = trailing_whitespace else {
+        return Ok((tokens, None));
+    };
+
+    let text = if next_whitespace_preference == &WhitespacePreference::TrimLine {
+        without_indentation(trailing_whitespace.source())
+    } else {
+        trailing_whitespace.source().as_str()
+    };
+
+    Ok((
+        tokens,
+        Some(Item::Whitespace(Static(
+            without_line_break(text),
+            trailing_whitespace.source().clone(),
+        ))),
+    ))
+}
+
 pub(super) fn parse_trailing_whitespace<'a>(
     end_tag: &'a Source<'a>,
     whitespace_preference: &'a WhitespacePreference,
@@ -245,18 +302,20 @@ pub(super) fn parse_trailing_whitespace<'a>(
             }
         };
 
-        if let Some(next_token) = next_token {
-            let next_whitespace_preference = match next_token.kind() {
+        let next_whitespace_preference = match next_token.map(Token::kind) {
+            Some(
                 TokenKind::TagStart {
                     whitespace_preference,
                     ..
                 }
                 | TokenKind::WhitespaceAdjustmentTag {
                     whitespace_preference,
-                } => whitespace_preference,
-                _ => &WhitespacePreference::Indifferent,
-            };
+                },
+            ) => whitespace_preference,
+            _ => &WhitespacePreference::Indifferent,
+        };
 
+        if let Some(next_token) = next_token {
             match (whitespace_preference, next_whitespace_preference) {
                 (WhitespacePreference::Remove, WhitespacePreference::Replace)
                 | (WhitespacePreference::Replace, WhitespacePreference::Remove) => {
@@ -318,6 +377,9 @@ pub(super) fn parse_trailing_whitespace<'a>(
                     }),
                 ))
             }
+            WhitespacePreference::TrimLine => {
+                parse_trimmed_line(tokens, next_whitespace_preference)
+            }
             WhitespacePreference::Indifferent => Ok((tokens, None)),
         }
     }
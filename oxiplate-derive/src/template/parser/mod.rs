@@ -4,12 +4,13 @@ mod item;
 mod statement;
 mod r#static;
 mod template;
+mod translation;
 mod writ;
 
 use item::Item;
 use statement::Statement;
 use r#static::Static;
-pub(crate) use template::parse;
+pub(crate) use template::{parse, parse_macros};
 use writ::Writ;
 
 use crate::Source;
@@ -6,6 +6,7 @@ use syn::LitStr;
 
 use super::{Statement, StatementKind, StaticType};
 use crate::parser::{Parser as _, cut};
+use crate::state::LocalVariables;
 use crate::template::parser::expression::{KeywordParser, String};
 use crate::template::parser::template::Template;
 use crate::template::parser::{Item, Res};
@@ -83,9 +84,14 @@ impl<'a> Extends<'a> {
         let (template, _template_length) = &self.template.to_tokens(state);
         let mut tokens: TokenStream = quote! { #template };
 
+        let error_type = state
+            .error_type
+            .as_ref()
+            .map(|error_type| quote! { #[oxiplate_error(#error_type)] });
         let template_to_extend = quote_spanned! {span=>
             #[derive(#oxiplate)]
             #[oxiplate_extends = #path]
+            #error_type
             struct Template {}
         };
 
@@ -104,8 +110,11 @@ impl<'a> Extends<'a> {
         }
         block_stack.push_back(&blocks);
 
-        let (template, estimated_length) =
-            crate::oxiplate_internal(template_to_extend.into(), &block_stack);
+        let (template, estimated_length) = crate::oxiplate_internal(
+            template_to_extend.into(),
+            &LocalVariables::new(),
+            &block_stack,
+        );
         let template: TokenStream = template.into();
 
         tokens.append_all(quote! {
@@ -0,0 +1,233 @@
+use proc_macro2::TokenStream;
+use quote::{TokenStreamExt, quote_spanned};
+
+use super::super::expression::{ArgumentsGroup, Expression, Identifier, arguments};
+use super::super::writ::Writ;
+use super::super::{Item, Res};
+use super::{Statement, StatementKind};
+use crate::parser::{Parser as _, cut, many0, opt, take};
+use crate::template::parser::expression::KeywordParser;
+use crate::template::parser::template::Template;
+use crate::template::tokenizer::{TokenKind, TokenSlice};
+use crate::{BuiltTokens, Source, State, internal_error};
+
+/// `filter` statement for applying filters to the rendered contents.
+#[derive(Debug)]
+pub struct Filter<'a> {
+    /// The `filter` keyword.
+    keyword: Source<'a>,
+    filters: Vec<FilterCall<'a>>,
+    template: Template<'a>,
+    pub(super) is_ended: bool,
+}
+
+/// A single filter like `>trim` or `replace(" ", "-")`.
+#[derive(Debug)]
+struct FilterCall<'a> {
+    /// `|` separating this filter from the previous one.
+    vertical_bar: Option<Source<'a>>,
+    cow_prefix: Option<Source<'a>>,
+    name: Identifier<'a>,
+    arguments: Option<ArgumentsGroup<'a>>,
+    source: Source<'a>,
+}
+
+impl<'a> FilterCall<'a> {
+    fn parse(tokens: TokenSlice<'a>) -> Res<'a, Self> {
+        let (tokens, (cow_prefix, name, arguments)) = (
+            opt(take(TokenKind::GreaterThan)),
+            cut("Expected a filter name", Identifier::parse),
+            opt(arguments),
+        )
+            .parse(tokens)?;
+
+        let source = if let Some(cow_prefix) = &cow_prefix {
+            cow_prefix
+                .source()
+                .clone()
+                .merge(name.source(), "Filter name should follow cow prefix")
+        } else {
+            name.source().clone()
+        }
+        .merge_some(
+            arguments.as_ref().map(ArgumentsGroup::source),
+            "Arguments should follow filter name",
+        );
+
+        Ok((
+            tokens,
+            Self {
+                vertical_bar: None,
+                cow_prefix: cow_prefix.map(|token| token.source().clone()),
+                name,
+                arguments,
+                source,
+            },
+        ))
+    }
+}
+
+impl<'a> Filter<'a> {
+    pub(crate) fn add_item(&mut self, item: Item<'a>) {
+        if self.is_ended {
+            internal_error!(
+                item.source().span_token().unwrap(),
+                "Attempted to add item to ended `filter` statement",
+            );
+        }
+
+        match item {
+            Item::Statement(Statement {
+                kind: StatementKind::EndFilter,
+                ..
+            }) => {
+                self.is_ended = true;
+            }
+            Item::Statement(Statement { kind, source }) if !kind.expected_in_statements() => {
+                self.template.0.push(Item::CompileError {
+                    message: "Unexpected statement in `filter` statement; is an `endfilter` \
+                              statement missing?"
+                        .to_string(),
+                    error_source: source.clone(),
+                    consumed_source: source,
+                });
+            }
+            _ => {
+                self.template.0.push(item);
+            }
+        }
+    }
+
+    /// Render the contents into a string,
+    /// pass it through each filter,
+    /// and write the result out as-is
+    /// since the contents were already escaped while rendering.
+    /// Filters therefore see escaped entities and markup rather than the original values,
+    /// and their arguments must be literals
+    /// so nothing unescaped can be added to the result.
+    pub(crate) fn to_tokens<'b: 'a>(&'a self, state: &mut State<'b>) -> BuiltTokens {
+        let span = self.keyword.span_token();
+
+        #[cfg(not(feature = "_oxiplate"))]
+        if cfg!(not(feature = "_oxiplate")) {
+            return (
+                quote_spanned! {span=>
+                    compile_error!("Filter statements require the `oxiplate` library due to trait usage");
+                },
+                0,
+            );
+        }
+
+        let mut errors = TokenStream::new();
+        for argument in self
+            .filters
+            .iter()
+            .filter_map(|filter| filter.arguments.as_ref())
+            .flat_map(ArgumentsGroup::expressions)
+            .filter(|argument| !argument.is_literal())
+        {
+            let span = argument.source().span_token();
+            errors.append_all(quote_spanned! {span=>
+                compile_error!("Filter statement arguments must be literals because the filtered contents are output without escaping; apply the filter to a writ instead to pass other values");
+            });
+        }
+        if !errors.is_empty() {
+            return (errors, 0);
+        }
+
+        let (template, estimated_length) = self.template.to_tokens(state);
+
+        let mut value: BuiltTokens = (
+            quote_spanned! {span=>
+                ::oxiplate::CowStrWrapper::new(
+                    (&&::oxiplate::ToCowStrWrapper::new(&oxiplate_string.as_str())).to_cow_str()
+                )
+            },
+            estimated_length,
+        );
+        for filter in &self.filters {
+            value = Expression::filter_tokens(
+                state,
+                &filter.name,
+                value,
+                filter.vertical_bar.as_ref().unwrap_or(&self.keyword),
+                filter.cow_prefix.as_ref(),
+                filter.arguments.as_ref(),
+                &filter.source,
+            );
+        }
+        let (value, estimated_length) = Writ::escaper_raw(&value.0, value.1);
+
+        (
+            quote_spanned! {span=>
+                {
+                    let mut oxiplate_string = alloc::string::String::with_capacity(#estimated_length);
+                    {
+                        let oxiplate_formatter = &mut oxiplate_string;
+                        #template
+                    }
+                    #value;
+                }
+            },
+            estimated_length,
+        )
+    }
+}
+
+impl<'a> From<Filter<'a>> for StatementKind<'a> {
+    fn from(statement: Filter<'a>) -> Self {
+        StatementKind::Filter(statement)
+    }
+}
+
+pub(super) fn parse_filter(tokens: TokenSlice) -> Res<Statement> {
+    let (tokens, (keyword, first_filter, remaining_filters)) = (
+        KeywordParser::new("filter"),
+        cut("Expected a filter", FilterCall::parse),
+        many0((
+            take(TokenKind::VerticalBar),
+            cut("Expected a filter", FilterCall::parse),
+        )),
+    )
+        .parse(tokens)?;
+
+    let mut source = keyword
+        .source()
+        .clone()
+        .merge(&first_filter.source, "Filter expected after `filter`");
+
+    let mut filters = vec![first_filter];
+    for (vertical_bar, mut filter) in remaining_filters {
+        source = source
+            .merge(vertical_bar.source(), "`|` expected after filter")
+            .merge(&filter.source, "Filter expected after `|`");
+        filter.vertical_bar = Some(vertical_bar.source().clone());
+        filters.push(filter);
+    }
+
+    Ok((
+        tokens,
+        Statement {
+            kind: Filter {
+                keyword: keyword.source().clone(),
+                filters,
+                template: Template(vec![]),
+                is_ended: false,
+            }
+            .into(),
+            source,
+        },
+    ))
+}
+
+pub(super) fn parse_endfilter(tokens: TokenSlice) -> Res<Statement> {
+    let (tokens, output) = KeywordParser::new("endfilter").parse(tokens)?;
+
+    Ok((
+        tokens,
+        Statement {
+            kind: StatementKind::EndFilter,
+            source: output.source().clone(),
+        },
+    ))
+}
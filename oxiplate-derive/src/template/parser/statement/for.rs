@@ -5,22 +5,49 @@ use quote::{TokenStreamExt, quote, quote_spanned};
 
 use super::super::Item;
 use super::super::expression::{Keyword, expression};
-use super::{State, Statement, StatementKind};
-use crate::parser::{Parser as _, cut, into};
+use super::{State, Statement, StatementKind, finish_branches};
+use crate::html_context::Branches;
+use crate::parser::{Parser as _, cut, opt, take};
 use crate::template::parser::Res;
 use crate::template::parser::expression::{ExpressionAccess, KeywordParser};
 use crate::template::parser::statement::helpers::pattern::Pattern;
 use crate::template::parser::template::Template;
-use crate::template::tokenizer::TokenSlice;
+use crate::template::tokenizer::{TokenKind, TokenSlice};
 use crate::{BuiltTokens, Source, internal_error};
 
+/// Label for a loop like `'outer`
+/// that `break` and `continue` can target.
+#[derive(Debug)]
+pub(crate) struct Label<'a>(Source<'a>);
+
+impl<'a> Label<'a> {
+    fn parse(tokens: TokenSlice<'a>) -> Res<'a, Self> {
+        let (tokens, label) = take(TokenKind::Label).parse(tokens)?;
+
+        Ok((tokens, Self(label.source().clone())))
+    }
+
+    fn source(&self) -> &Source<'a> {
+        &self.0
+    }
+
+    fn to_tokens(&self) -> TokenStream {
+        let lifetime = syn::Lifetime::new(self.0.as_str(), self.0.span_token());
+
+        quote! { #lifetime }
+    }
+}
+
 #[derive(Debug)]
 pub struct For<'a> {
+    label: Option<Label<'a>>,
     #[allow(clippy::struct_field_names)]
     for_keyword: Keyword<'a>,
     pattern: Pattern<'a>,
     in_keyword: Keyword<'a>,
     expression: ExpressionAccess<'a>,
+    /// Condition from `if` that items must match to be looped over.
+    condition: Option<ExpressionAccess<'a>>,
     template: Template<'a>,
     otherwise: Option<Template<'a>>,
     pub(super) is_ended: bool,
@@ -87,21 +114,46 @@ impl<'a> For<'a> {
         self.pattern.get_variables()
     }
 
-    pub fn to_tokens<'b: 'a>(&self, state: &mut State<'b>) -> BuiltTokens {
+    pub fn to_tokens<'b: 'a>(&self, state: &mut State<'b>, source: &Source) -> BuiltTokens {
         let mut tokens = TokenStream::new();
         let mut estimated_length = 0;
 
+        // The loop may run any number of times,
+        // so its body needs to end in the same context it starts in.
+        let mut branches = Branches::new(&state.html_context);
+        branches.end_unchanged();
+
         let For {
+            label,
             for_keyword,
             pattern,
             in_keyword,
             expression,
+            condition,
             template,
             otherwise,
             is_ended: _,
         } = self;
 
-        let (expression, _expression_length) = expression.to_tokens(state);
+        // Items for `loop` filters need to be filtered before the loop information is calculated,
+        // but all other conditions are checked within the loop.
+        let (expression, body_condition) = match condition {
+            Some(condition)
+                if self.expression.as_loop_filter().is_some()
+                    && self.pattern.as_pair().is_some() =>
+            {
+                (self.filtered_expression(state, condition), None)
+            }
+            condition => (expression.to_tokens(state).0, condition.as_ref()),
+        };
+        let label = label.as_ref().map(|label| {
+            let label = label.to_tokens();
+            quote! { #label: }
+        });
+
+        // Variables may only be used in the condition.
+        let allow_unused = (condition.is_some() && body_condition.is_none())
+            .then(|| quote! { #[allow(unused_variables)] });
 
         state.local_variables.push_stack();
 
@@ -111,19 +163,32 @@ impl<'a> For<'a> {
                 .map(ToString::to_string)
                 .collect(),
         );
+        let skip = body_condition.map(|condition| {
+            let (condition, _condition_length) = condition.to_tokens(state);
+            quote! {
+                if !(#condition) {
+                    continue;
+                }
+            }
+        });
         let (template, template_length) = template.to_tokens(state);
+        branches.end(&state.html_context);
 
         // Loops will very likely run at least twice.
         estimated_length += template_length * 2;
 
         let pattern = pattern.to_tokens(state);
         if let Some(otherwise) = otherwise {
+            state.html_context = branches.start();
             let (otherwise, otherwise_length) = otherwise.to_tokens(state);
+            branches.end(&state.html_context);
             estimated_length = estimated_length.min(otherwise_length);
             tokens.append_all(quote! {
                 {
                     let mut loop_ran = false;
-                    #for_keyword #pattern #in_keyword #expression {
+                    #allow_unused
+                    #label #for_keyword #pattern #in_keyword #expression {
+                        #skip
                         loop_ran = true;
                         #template
                     }
@@ -133,14 +198,61 @@ impl<'a> For<'a> {
                 }
             });
         } else {
-            tokens
-                .append_all(quote! { #for_keyword #pattern #in_keyword #expression { #template } });
+            tokens.append_all(
+                quote! { #allow_unused #label #for_keyword #pattern #in_keyword #expression { #skip #template } },
+            );
         }
 
         state.local_variables.pop_stack();
+        tokens.append_all(finish_branches(state, branches, "for", source));
 
         (tokens, estimated_length)
     }
+
+    /// Build the iterable expression for `items | loop` with a `(loop, item)` pattern
+    /// so items are filtered before the `loop` filter is applied
+    /// and the loop information only counts matching items.
+    /// Items are matched through a reference
+    /// so the condition sees the same types as the loop body,
+    /// which requires bindings to be `Copy`, like references from `&items`.
+    fn filtered_expression<'b: 'a>(
+        &self,
+        state: &mut State<'b>,
+        condition: &ExpressionAccess,
+    ) -> TokenStream {
+        let Some(((iterable, filter), (_loop_pattern, item_pattern))) =
+            self.expression.as_loop_filter().zip(self.pattern.as_pair())
+        else {
+            return self.expression.to_tokens(state).0;
+        };
+
+        let (iterable, iterable_length) = iterable.to_tokens(state);
+
+        state.local_variables.push_stack();
+        state.local_variables.add(
+            item_pattern
+                .get_variables()
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        );
+        let (condition, _condition_length) = condition.to_tokens(state);
+        let item_pattern = item_pattern.to_tokens(state);
+        state.local_variables.pop_stack();
+
+        let filtered = quote! {
+            ::core::iter::Iterator::filter(
+                ::core::iter::IntoIterator::into_iter(#iterable),
+                |oxiplate_item| {
+                    #[allow(unused_variables)]
+                    let &#item_pattern = oxiplate_item;
+                    #condition
+                },
+            )
+        };
+
+        filter(state, (filtered, iterable_length)).0
+    }
 }
 
 impl<'a> From<For<'a>> for StatementKind<'a> {
@@ -152,31 +264,71 @@ impl<'a> From<For<'a>> for StatementKind<'a> {
 pub(super) fn parse_for(tokens: TokenSlice) -> Res<Statement> {
     let (tokens, for_keyword) = KeywordParser::new("for").parse(tokens)?;
 
-    let (tokens, (pattern, in_keyword, expression)) = (
+    let (tokens, label) = opt(Label::parse).parse(tokens)?;
+    let (tokens, label) = if let Some(label) = label {
+        let (tokens, colon) =
+            cut("Expected `:` after the label", take(TokenKind::Colon)).parse(tokens)?;
+        (tokens, Some((label, colon)))
+    } else {
+        (tokens, None)
+    };
+
+    let (tokens, (pattern, in_keyword, loop_expression, condition_keyword)) = (
         cut("Expected a pattern", Pattern::parse),
         cut("Expected 'in'", KeywordParser::new("in")),
         cut(
             "Expected an expression that is iterable",
             expression(true, true),
         ),
+        opt(KeywordParser::new("if")),
     )
         .parse(tokens)?;
 
-    let source = for_keyword
-        .source()
-        .clone()
+    let (tokens, condition) = if let Some(condition_keyword) = condition_keyword {
+        let (tokens, condition) =
+            cut("Expected a condition after `if`", expression(true, true)).parse(tokens)?;
+        (tokens, Some((condition_keyword, condition)))
+    } else {
+        (tokens, None)
+    };
+
+    let mut source = for_keyword.source().clone();
+    let label = if let Some((label, colon)) = label {
+        source = source
+            .merge(label.source(), "Label expected after `for`")
+            .merge(colon.source(), "`:` expected after label");
+        Some(label)
+    } else {
+        None
+    };
+
+    source = source
         .merge(pattern.source(), "Ident expected after whitespace")
         .merge(in_keyword.source(), "`in` expected after whitespace")
-        .merge(&expression.source(), "Expression expected after whitespace");
+        .merge(
+            &loop_expression.source(),
+            "Expression expected after whitespace",
+        );
+
+    let condition = if let Some((condition_keyword, condition)) = condition {
+        source = source
+            .merge(condition_keyword.source(), "`if` expected after expression")
+            .merge(&condition.source(), "Condition expected after `if`");
+        Some(condition)
+    } else {
+        None
+    };
 
     Ok((
         tokens,
         Statement {
             kind: For {
+                label,
                 for_keyword,
                 pattern,
                 in_keyword,
-                expression,
+                expression: loop_expression,
+                condition,
                 template: Template(vec![]),
                 otherwise: None,
                 is_ended: false,
@@ -199,29 +351,44 @@ pub(super) fn parse_endfor(tokens: TokenSlice) -> Res<Statement> {
     ))
 }
 
+/// `break` statement with an optional label like `'outer`.
 #[derive(Debug)]
-pub(crate) struct Break<'a>(Keyword<'a>);
+pub(crate) struct Break<'a> {
+    keyword: Keyword<'a>,
+    label: Option<Label<'a>>,
+    source: Source<'a>,
+}
 
 impl<'a> Break<'a> {
     pub fn parse(tokens: TokenSlice<'a>) -> Res<'a, Break<'a>> {
-        into(KeywordParser::new("break")).parse(tokens)
+        let (tokens, (keyword, label)) =
+            (KeywordParser::new("break"), opt(Label::parse)).parse(tokens)?;
+
+        let source = keyword.source().clone().merge_some(
+            label.as_ref().map(Label::source),
+            "Label expected after `break`",
+        );
+
+        Ok((
+            tokens,
+            Self {
+                keyword,
+                label,
+                source,
+            },
+        ))
     }
 
     pub fn source(&self) -> &Source<'a> {
-        self.0.source()
+        &self.source
     }
 
     pub fn to_tokens(&self) -> BuiltTokens {
-        let span = self.0.source().span_token();
-        let keyword = &self.0;
+        let span = self.keyword.source().span_token();
+        let keyword = &self.keyword;
+        let label = self.label.as_ref().map(Label::to_tokens);
 
-        (quote_spanned! {span=> #keyword; }, 0)
-    }
-}
-
-impl<'a> From<Keyword<'a>> for Break<'a> {
-    fn from(value: Keyword<'a>) -> Self {
-        Break(value)
+        (quote_spanned! {span=> #keyword #label; }, 0)
     }
 }
 
@@ -234,29 +401,44 @@ impl<'a> From<Break<'a>> for Statement<'a> {
     }
 }
 
+/// `continue` statement with an optional label like `'outer`.
 #[derive(Debug)]
-pub(crate) struct Continue<'a>(Keyword<'a>);
+pub(crate) struct Continue<'a> {
+    keyword: Keyword<'a>,
+    label: Option<Label<'a>>,
+    source: Source<'a>,
+}
 
 impl<'a> Continue<'a> {
     pub fn parse(tokens: TokenSlice<'a>) -> Res<'a, Continue<'a>> {
-        into(KeywordParser::new("continue")).parse(tokens)
+        let (tokens, (keyword, label)) =
+            (KeywordParser::new("continue"), opt(Label::parse)).parse(tokens)?;
+
+        let source = keyword.source().clone().merge_some(
+            label.as_ref().map(Label::source),
+            "Label expected after `continue`",
+        );
+
+        Ok((
+            tokens,
+            Self {
+                keyword,
+                label,
+                source,
+            },
+        ))
     }
 
     pub fn source(&self) -> &Source<'a> {
-        self.0.source()
+        &self.source
     }
 
     pub fn to_tokens(&self) -> BuiltTokens {
-        let span = self.0.source().span_token();
-        let keyword = &self.0;
-
-        (quote_spanned! {span=> #keyword; }, 0)
-    }
-}
+        let span = self.keyword.source().span_token();
+        let keyword = &self.keyword;
+        let label = self.label.as_ref().map(Label::to_tokens);
 
-impl<'a> From<Keyword<'a>> for Continue<'a> {
-    fn from(value: Keyword<'a>) -> Self {
-        Continue(value)
+        (quote_spanned! {span=> #keyword #label; }, 0)
     }
 }
 
@@ -50,6 +50,14 @@ impl<'a> Pattern<'a> {
         }
     }
 
+    /// Get both patterns if this is a tuple of exactly two patterns like `(a, b)`.
+    pub fn as_pair(&self) -> Option<(&Pattern<'a>, &Pattern<'a>)> {
+        match self {
+            Self::Tuple(tuple) => tuple.as_pair(),
+            _ => None,
+        }
+    }
+
     pub fn get_variables(&'a self) -> HashSet<&'a str> {
         match self {
             Self::Ident(identifier) => HashSet::from([identifier.as_str()]),
@@ -149,6 +157,21 @@ impl<'a> Path<'a> {
         &self.source
     }
 
+    /// Whether the path has any segments before the name like `Kind::Link`.
+    pub fn has_segments(&self) -> bool {
+        !self.segments.is_empty()
+    }
+
+    /// Whether the path names a type or variant rather than a variable,
+    /// either because it has segments or because the name is capitalized like `Link`.
+    pub fn is_type(&self) -> bool {
+        self.has_segments()
+            || self
+                .name
+                .as_str()
+                .starts_with(|character: char| character.is_ascii_uppercase())
+    }
+
     pub fn to_tokens(&self) -> TokenStream {
         let mut tokens = TokenStream::new();
 
@@ -78,6 +78,15 @@ impl<'a> Tuple<'a> {
         &self.source
     }
 
+    /// Get both patterns if this is a tuple of exactly two patterns like `(a, b)`.
+    pub fn as_pair(&self) -> Option<(&Pattern<'a>, &Pattern<'a>)> {
+        match (self.values.as_slice(), &self.last_value) {
+            ([(first, _comma)], Some(second)) => Some((first, second)),
+            ([(first, _first_comma), (second, _second_comma)], None) => Some((first, second)),
+            _ => None,
+        }
+    }
+
     pub fn get_variables(&'a self) -> HashSet<&'a str> {
         let mut vars = HashSet::new();
 
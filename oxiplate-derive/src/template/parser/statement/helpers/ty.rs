@@ -0,0 +1,195 @@
+use proc_macro2::TokenStream;
+use quote::{TokenStreamExt, quote_spanned};
+
+use super::pattern::Path;
+use crate::Source;
+use crate::parser::{Parser as _, alt, cut, many0, opt, take};
+use crate::template::parser::Res;
+use crate::template::tokenizer::{Token, TokenKind, TokenSlice};
+
+/// Type annotation like `&str`, `Option<usize>`, or `&[usize]`.
+#[derive(Debug)]
+pub(crate) struct Type<'a> {
+    /// Leading `&`s
+    references: Vec<Source<'a>>,
+    kind: TypeKind<'a>,
+    source: Source<'a>,
+}
+
+#[derive(Debug)]
+enum TypeKind<'a> {
+    /// `Option<usize>`
+    Path(Path<'a>, Option<Generics<'a>>),
+
+    /// `[usize]`, with the `Source` covering the brackets.
+    Slice(Box<Type<'a>>, Source<'a>),
+}
+
+/// Generic arguments for a type like `<usize>` or `<String, usize>`.
+#[derive(Debug)]
+struct Generics<'a> {
+    open: Source<'a>,
+    /// `Source` is the comma preceding the type.
+    types: Vec<(Option<Source<'a>>, Type<'a>)>,
+    close: Source<'a>,
+}
+
+impl<'a> Type<'a> {
+    pub fn parse(tokens: TokenSlice<'a>) -> Res<'a, Self> {
+        let (tokens, (references, (kind, kind_source))) = (
+            many0(take(TokenKind::Ampersand)),
+            alt((Self::parse_path, Self::parse_slice)),
+        )
+            .parse(tokens)?;
+
+        let mut source: Option<Source<'a>> = None;
+        let mut reference_sources = Vec::with_capacity(references.len());
+        for reference in references {
+            source = Some(
+                reference
+                    .source()
+                    .clone()
+                    .append_to_some(source, "Reference expected after previous reference"),
+            );
+            reference_sources.push(reference.source().clone());
+        }
+        let source = kind_source.append_to_some(source, "Type expected after references");
+
+        Ok((
+            tokens,
+            Self {
+                references: reference_sources,
+                kind,
+                source,
+            },
+        ))
+    }
+
+    fn parse_path(tokens: TokenSlice<'a>) -> Res<'a, (TypeKind<'a>, Source<'a>)> {
+        let (tokens, (path, generics)) = (
+            Path::parse_include_ident,
+            opt((
+                take(TokenKind::LessThan),
+                cut(
+                    "Expected comma-separated list of types followed by `>`",
+                    (
+                        Self::parse,
+                        many0((take(TokenKind::Comma), Self::parse)),
+                        opt(take(TokenKind::Comma)),
+                        take(TokenKind::GreaterThan),
+                    ),
+                ),
+            )),
+        )
+            .parse(tokens)?;
+
+        let mut source = path.source().clone();
+
+        let generics =
+            if let Some((open, (first_type, remaining_types, trailing_comma, close))) = generics {
+                source = source
+                    .merge(open.source(), "`<` expected after path")
+                    .merge(first_type.source(), "Type expected after `<`");
+
+                let mut types = vec![(None, first_type)];
+                for (comma, ty) in remaining_types {
+                    source = source
+                        .merge(comma.source(), "Comma expected after type")
+                        .merge(ty.source(), "Type expected after comma");
+                    types.push((Some(comma.source().clone()), ty));
+                }
+
+                source = source
+                    .merge_some(
+                        trailing_comma.map(Token::source),
+                        "Comma expected after type",
+                    )
+                    .merge(close.source(), "`>` expected after types");
+
+                Some(Generics {
+                    open: open.source().clone(),
+                    types,
+                    close: close.source().clone(),
+                })
+            } else {
+                None
+            };
+
+        Ok((tokens, (TypeKind::Path(path, generics), source)))
+    }
+
+    fn parse_slice(tokens: TokenSlice<'a>) -> Res<'a, (TypeKind<'a>, Source<'a>)> {
+        let (tokens, (open, (ty, close))) = (
+            take(TokenKind::OpenBracket),
+            cut(
+                "Expected a type followed by `]`",
+                (Self::parse, take(TokenKind::CloseBracket)),
+            ),
+        )
+            .parse(tokens)?;
+
+        let source = open
+            .source()
+            .clone()
+            .merge(ty.source(), "Type expected after `[`")
+            .merge(close.source(), "`]` expected after type");
+
+        Ok((
+            tokens,
+            (TypeKind::Slice(Box::new(ty), source.clone()), source),
+        ))
+    }
+
+    pub fn source(&self) -> &Source<'a> {
+        &self.source
+    }
+
+    pub fn to_tokens(&self) -> TokenStream {
+        let mut tokens = TokenStream::new();
+
+        for reference in &self.references {
+            let span = reference.span_token();
+            tokens.append_all(quote_spanned! {span=> & });
+        }
+
+        match &self.kind {
+            TypeKind::Path(path, generics) => {
+                tokens.append_all(path.to_tokens());
+                if let Some(generics) = generics {
+                    tokens.append_all(generics.to_tokens());
+                }
+            }
+            TypeKind::Slice(ty, source) => {
+                let mut group =
+                    proc_macro2::Group::new(proc_macro2::Delimiter::Bracket, ty.to_tokens());
+                group.set_span(source.span_token());
+                tokens.append(group);
+            }
+        }
+
+        tokens
+    }
+}
+
+impl Generics<'_> {
+    fn to_tokens(&self) -> TokenStream {
+        let Self { open, types, close } = self;
+        let mut tokens = TokenStream::new();
+
+        let open_span = open.span_token();
+        tokens.append_all(quote_spanned! {open_span=> < });
+
+        for (comma, ty) in types {
+            if let Some(comma) = comma {
+                let comma_span = comma.span_token();
+                tokens.append_all(quote_spanned! {comma_span=> , });
+            }
+            tokens.append_all(ty.to_tokens());
+        }
+
+        let close_span = close.span_token();
+        tokens.append_all(quote_spanned! {close_span=> > });
+
+        tokens
+    }
+}
@@ -3,7 +3,8 @@ use quote::{TokenStreamExt, quote};
 
 use super::super::expression::expression;
 use super::super::{Item, Res};
-use super::{Statement, StatementKind};
+use super::{Statement, StatementKind, finish_branches};
+use crate::html_context::Branches;
 use crate::parser::{Parser as _, cut, opt, take};
 use crate::template::parser::expression::{ExpressionAccess, KeywordParser};
 use crate::template::parser::statement::helpers::pattern::Pattern;
@@ -95,13 +96,15 @@ impl<'a> If<'a> {
         }
     }
 
-    pub(crate) fn to_tokens<'b: 'a>(&self, state: &mut State<'b>) -> BuiltTokens {
+    pub(crate) fn to_tokens<'b: 'a>(&self, state: &mut State<'b>, source: &Source) -> BuiltTokens {
         let mut tokens = TokenStream::new();
         let mut estimated_length = usize::MAX;
+        let mut branches = Branches::new(&state.html_context);
 
         let mut is_elseif = false;
         for (expression, template) in &self.ifs {
             state.local_variables.push_stack();
+            state.html_context = branches.start();
 
             match expression {
                 IfType::If(expression) => {
@@ -139,18 +142,24 @@ impl<'a> If<'a> {
                 }
             }
 
+            branches.end(&state.html_context);
             state.local_variables.pop_stack();
             is_elseif = true;
         }
         if let Some(template) = &self.otherwise {
             state.local_variables.push_stack();
+            state.html_context = branches.start();
 
             let (template, template_length) = template.to_tokens(state);
             estimated_length = estimated_length.min(template_length);
             tokens.append_all(quote! { else { #template } });
 
+            branches.end(&state.html_context);
             state.local_variables.pop_stack();
+        } else {
+            branches.end_unchanged();
         }
+        tokens.append_all(finish_branches(state, branches, "if", source));
 
         (tokens, estimated_length)
     }
@@ -0,0 +1,208 @@
+use proc_macro2::TokenStream;
+use quote::{TokenStreamExt, quote_spanned};
+use syn::LitStr;
+
+use super::{Statement, StatementKind};
+use crate::parser::{Parser as _, cut, many0, opt, take};
+use crate::template::parser::Res;
+use crate::template::parser::expression::{Identifier, KeywordParser, String};
+use crate::template::tokenizer::{Token, TokenKind, TokenSlice};
+use crate::{BuiltTokens, Source, State, import_macros};
+
+/// `import` or `from` statement for using macros defined in another template.
+/// E.g., `{% import "macros.html.oxip" as ui %}`
+/// or `{% from "forms.html.oxip" import input, select as dropdown %}`.
+#[derive(Debug)]
+pub struct Import<'a> {
+    path: String<'a>,
+    kind: ImportKind<'a>,
+}
+
+#[derive(Debug)]
+enum ImportKind<'a> {
+    /// All macros from the template, available as `namespace.name()`.
+    Namespace(Identifier<'a>),
+
+    /// Specific macros from the template, available by name.
+    Names(Vec<ImportedName<'a>>),
+}
+
+/// Macro imported by name like `input` or `select as dropdown`.
+#[derive(Debug)]
+struct ImportedName<'a> {
+    name: Identifier<'a>,
+    alias: Option<Identifier<'a>>,
+    source: Source<'a>,
+}
+
+impl<'a> ImportedName<'a> {
+    fn parse(tokens: TokenSlice<'a>) -> Res<'a, Self> {
+        let (tokens, (name, alias)) = (
+            Identifier::parse,
+            opt((
+                KeywordParser::new("as"),
+                cut(
+                    "Expected a name for the macro after `as`",
+                    Identifier::parse,
+                ),
+            )),
+        )
+            .parse(tokens)?;
+
+        let (alias, source) = if let Some((as_keyword, alias)) = alias {
+            let source = name
+                .source()
+                .clone()
+                .merge(as_keyword.source(), "`as` expected after macro name")
+                .merge(alias.source(), "Alias expected after `as`");
+            (Some(alias), source)
+        } else {
+            (None, name.source().clone())
+        };
+
+        Ok((
+            tokens,
+            Self {
+                name,
+                alias,
+                source,
+            },
+        ))
+    }
+}
+
+impl<'a> From<Import<'a>> for StatementKind<'a> {
+    fn from(statement: Import<'a>) -> Self {
+        StatementKind::Import(statement)
+    }
+}
+
+impl Import<'_> {
+    /// Build the imported template and add its macros to the state.
+    /// Nothing is output unless there are errors in the imported template
+    /// or a macro being imported by name doesn't exist.
+    pub fn to_tokens(&self, state: &mut State) -> BuiltTokens {
+        let path = LitStr::new(self.path.as_str(), self.path.source().span_token());
+        let (macros, mut tokens): (_, TokenStream) = import_macros(&path, state);
+
+        match &self.kind {
+            ImportKind::Namespace(namespace) => {
+                for (name, definition) in macros {
+                    state
+                        .macros
+                        .insert(format!("{}.{name}", namespace.as_str()), definition);
+                }
+            }
+            ImportKind::Names(names) => {
+                for ImportedName { name, alias, .. } in names {
+                    if let Some(definition) = macros.get(name.as_str()) {
+                        state.macros.insert(
+                            alias.as_ref().unwrap_or(name).as_str().to_string(),
+                            definition.clone(),
+                        );
+                    } else {
+                        let span = name.source().span_token();
+                        let message = format!(
+                            "Macro `{}` is not defined in `{}`",
+                            name.as_str(),
+                            self.path.as_str(),
+                        );
+                        tokens.append_all(quote_spanned! {span=> compile_error!(#message); });
+                    }
+                }
+            }
+        }
+
+        (tokens, 0)
+    }
+}
+
+pub(super) fn parse_import(tokens: TokenSlice) -> Res<Statement> {
+    let (tokens, (import_keyword, path, as_keyword, namespace)) = (
+        KeywordParser::new("import"),
+        cut("Expected path to the template to import", String::parse),
+        cut(
+            "Expected `as` followed by a namespace for the imported macros",
+            KeywordParser::new("as"),
+        ),
+        cut(
+            "Expected a namespace for the imported macros",
+            Identifier::parse,
+        ),
+    )
+        .parse(tokens)?;
+
+    let source = import_keyword
+        .source()
+        .clone()
+        .merge(path.source(), "Path expected after `import`")
+        .merge(as_keyword.source(), "`as` expected after path")
+        .merge(namespace.source(), "Namespace expected after `as`");
+
+    Ok((
+        tokens,
+        Statement {
+            kind: Import {
+                path,
+                kind: ImportKind::Namespace(namespace),
+            }
+            .into(),
+            source,
+        },
+    ))
+}
+
+pub(super) fn parse_from(tokens: TokenSlice) -> Res<Statement> {
+    let (
+        tokens,
+        (from_keyword, path, import_keyword, (first_name, remaining_names, trailing_comma)),
+    ) = (
+        KeywordParser::new("from"),
+        cut(
+            "Expected path to the template to import from",
+            String::parse,
+        ),
+        cut("Expected `import` after path", KeywordParser::new("import")),
+        cut(
+            "Expected comma-separated list of macros to import",
+            (
+                ImportedName::parse,
+                many0((take(TokenKind::Comma), ImportedName::parse)),
+                opt(take(TokenKind::Comma)),
+            ),
+        ),
+    )
+        .parse(tokens)?;
+
+    let mut source = from_keyword
+        .source()
+        .clone()
+        .merge(path.source(), "Path expected after `from`")
+        .merge(import_keyword.source(), "`import` expected after path")
+        .merge(&first_name.source, "Macro name expected after `import`");
+
+    let mut names = vec![first_name];
+    for (comma, name) in remaining_names {
+        source = source
+            .merge(comma.source(), "Comma expected after macro name")
+            .merge(&name.source, "Macro name expected after comma");
+        names.push(name);
+    }
+
+    source = source.merge_some(
+        trailing_comma.map(Token::source),
+        "Comma expected after macro name",
+    );
+
+    Ok((
+        tokens,
+        Statement {
+            kind: Import {
+                path,
+                kind: ImportKind::Names(names),
+            }
+            .into(),
+            source,
+        },
+    ))
+}
@@ -1,19 +1,74 @@
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use proc_macro2::TokenStream;
 use quote::{TokenStreamExt, quote, quote_spanned};
 use syn::LitStr;
 
+use super::super::writ::Writ;
 use super::{Statement, StatementKind};
-use crate::parser::{Parser as _, cut};
+use crate::html_context::HtmlContext;
+use crate::i18n::Locale;
+use crate::parser::{Parser as _, cut, many0, opt, take};
+use crate::state::LocalVariables;
 use crate::template::parser::Res;
-use crate::template::parser::expression::{KeywordParser, String};
-use crate::template::tokenizer::TokenSlice;
-use crate::{BuiltTokens, oxiplate_internal};
+use crate::template::parser::expression::{
+    ExpressionAccess, Identifier, Keyword, KeywordParser, String, expression,
+};
+use crate::template::tokenizer::{Token, TokenKind, TokenSlice};
+use crate::{BuiltTokens, Source, State, oxiplate_internal};
 
 #[derive(Debug)]
 pub struct Include<'a> {
     path: String<'a>,
+
+    /// Variables passed in with `with { name: value }`.
+    context: Vec<ContextVariable<'a>>,
+
+    /// `only` keyword that hides fields from the including template.
+    only: Option<Keyword<'a>>,
+
+    /// `indent` keyword that indents each line after the first
+    /// to line up with the start of the tag.
+    indent: Option<Keyword<'a>>,
+}
+
+/// Variable passed to an included template
+/// like `name: value` or the shorthand `name`.
+#[derive(Debug)]
+struct ContextVariable<'a> {
+    name: Identifier<'a>,
+    value: ExpressionAccess<'a>,
+    source: Source<'a>,
+}
+
+impl<'a> ContextVariable<'a> {
+    fn parse(tokens: TokenSlice<'a>) -> Res<'a, Self> {
+        let (tokens, (name, colon)) =
+            (Identifier::parse, opt(take(TokenKind::Colon))).parse(tokens)?;
+
+        let (tokens, (value, source)) = if let Some(colon) = colon {
+            let (tokens, value) =
+                cut("Expected an expression after `:`", expression(true, true)).parse(tokens)?;
+            let source = name
+                .source()
+                .clone()
+                .merge(colon.source(), "`:` expected after variable name")
+                .merge(&value.source(), "Expression expected after `:`");
+            (tokens, (value, source))
+        } else {
+            // Shorthand for `name: name`.
+            (tokens, (name.clone().into(), name.source().clone()))
+        };
+
+        Ok((
+            tokens,
+            Self {
+                name,
+                value,
+                source,
+            },
+        ))
+    }
 }
 
 impl<'a> From<Include<'a>> for StatementKind<'a> {
@@ -23,16 +78,44 @@ impl<'a> From<Include<'a>> for StatementKind<'a> {
 }
 
 impl Include<'_> {
-    pub fn to_tokens(&self) -> BuiltTokens {
+    /// Build the included template,
+    /// indenting its output to line up with the start of the tag (`tag_source`) if requested.
+    pub fn to_tokens(&self, state: &mut State, tag_source: &Source) -> BuiltTokens {
         let mut tokens = TokenStream::new();
 
         let span = self.path.source().span_token();
 
+        // Included templates are built as if they're in HTML text.
+        if state.tracks_html_context() && state.html_context != HtmlContext::Text {
+            let message = format!(
+                "Template is included {}, but included templates are built for HTML text, so \
+                 values within them wouldn't be escaped correctly. Include the template within \
+                 text instead.",
+                state.html_context.location(),
+            );
+            return (quote_spanned! {span=> compile_error!(#message); }, 0);
+        }
+
         #[cfg(feature = "_oxiplate")]
         let oxiplate = quote_spanned! {span=> ::oxiplate::Oxiplate };
         #[cfg(not(feature = "_oxiplate"))]
         let oxiplate = quote_spanned! {span=> ::oxiplate_derive::Oxiplate };
 
+        // Variables passed in with `with` are local variables in the included template.
+        // With `only`, they're the only variables available.
+        let names: HashSet<std::string::String> = self
+            .context
+            .iter()
+            .map(|variable| variable.name.as_str().to_string())
+            .collect();
+        let local_variables = if self.only.is_some() {
+            LocalVariables::isolated(names)
+        } else {
+            let mut local_variables = LocalVariables::new();
+            local_variables.add(names);
+            local_variables
+        };
+
         // Generate tokens for the included template.
         // They'll be injected into the main template later.
         //
@@ -45,37 +128,134 @@ impl Include<'_> {
         // will point the user to the correct place in the code
         // to fix things.
         let include_path = LitStr::new(self.path.as_str(), self.path.source().span_token());
+        let error_type = state
+            .error_type
+            .as_ref()
+            .map(|error_type| quote! { #[oxiplate_error(#error_type)] });
+        let locale = state.locale.as_ref().map(Locale::to_attribute);
         let template = quote_spanned! {span=>
             #[derive(#oxiplate)]
             #[oxiplate_include = #include_path]
+            #error_type
+            #locale
             struct IncludingTemplate;
         };
-        let (template, estimated_length) =
-            oxiplate_internal(template.into(), &VecDeque::from([&HashMap::new()]));
+        let (template, estimated_length) = oxiplate_internal(
+            template.into(),
+            &local_variables,
+            &VecDeque::from([&HashMap::new()]),
+        );
         let template: proc_macro2::TokenStream = template.into();
 
-        tokens.append_all(quote! { #template });
+        if self.context.is_empty() {
+            tokens.append_all(quote! { #template });
+        } else {
+            // Values are evaluated in the including template
+            // before being passed to the included template.
+            let names = self.context.iter().map(|variable| &variable.name);
+            let values = self
+                .context
+                .iter()
+                .map(|variable| variable.value.to_tokens(state).0);
+            tokens.append_all(quote! {{
+                let (#(#names,)*) = (#(#values,)*);
+                #template
+            }});
+        }
 
-        (tokens, estimated_length)
+        if self.indent.is_some() {
+            Writ::indented(state, tag_source, (tokens, estimated_length))
+        } else {
+            (tokens, estimated_length)
+        }
     }
 }
 
 pub(super) fn parse_include(tokens: TokenSlice) -> Res<Statement> {
-    let (tokens, (include_keyword, path)) = (
+    let (tokens, (include_keyword, path, with_keyword)) = (
         KeywordParser::new("include"),
         cut("Expected path to the template to include", String::parse),
+        opt(KeywordParser::new("with")),
     )
         .parse(tokens)?;
 
-    let source = include_keyword
+    let (tokens, context) = if with_keyword.is_some() {
+        let (tokens, context) = cut(
+            "Expected comma-separated list of variables wrapped in `{}`",
+            (
+                take(TokenKind::OpenBrace),
+                opt((
+                    ContextVariable::parse,
+                    many0((take(TokenKind::Comma), ContextVariable::parse)),
+                    opt(take(TokenKind::Comma)),
+                )),
+                take(TokenKind::CloseBrace),
+            ),
+        )
+        .parse(tokens)?;
+        (tokens, Some(context))
+    } else {
+        (tokens, None)
+    };
+
+    let (tokens, (only, indent)) = (
+        opt(KeywordParser::new("only")),
+        opt(KeywordParser::new("indent")),
+    )
+        .parse(tokens)?;
+
+    let mut source = include_keyword
         .source()
         .clone()
         .merge(path.source(), "Path expected after `include`");
 
+    let mut variables = vec![];
+    if let (Some(with_keyword), Some((open_brace, context, close_brace))) = (with_keyword, context)
+    {
+        source = source
+            .merge(with_keyword.source(), "`with` expected after path")
+            .merge(open_brace.source(), "`{` expected after `with`");
+
+        if let Some((first_variable, remaining_variables, trailing_comma)) = context {
+            source = source.merge(&first_variable.source, "Variable expected after `{`");
+            variables.push(first_variable);
+
+            for (comma, variable) in remaining_variables {
+                source = source
+                    .merge(comma.source(), "Comma expected after variable")
+                    .merge(&variable.source, "Variable expected after comma");
+                variables.push(variable);
+            }
+
+            source = source.merge_some(
+                trailing_comma.map(Token::source),
+                "Comma expected after variable",
+            );
+        }
+
+        source = source.merge(close_brace.source(), "`}` expected after variables");
+    }
+
+    source = source
+        .merge_some(
+            only.as_ref().map(Keyword::source),
+            "`only` expected after path or variables",
+        )
+        .merge_some(
+            indent.as_ref().map(Keyword::source),
+            "`indent` expected after path, variables, or `only`",
+        );
+
     Ok((
         tokens,
         Statement {
-            kind: Include { path }.into(),
+            kind: Include {
+                path,
+                context: variables,
+                only,
+                indent,
+            }
+            .into(),
             source,
         },
     ))
@@ -1,15 +1,18 @@
 use std::collections::HashSet;
 
-use quote::quote_spanned;
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
 
+use super::super::Item;
 use super::super::expression::{Keyword, expression};
 use super::{State, Statement, StatementKind};
-use crate::parser::{Parser as _, cut, take};
+use crate::parser::{Parser as _, cut, opt, take};
 use crate::template::parser::Res;
 use crate::template::parser::expression::{ExpressionAccess, KeywordParser};
 use crate::template::parser::statement::helpers::pattern::Pattern;
+use crate::template::parser::template::Template;
 use crate::template::tokenizer::{TokenKind, TokenSlice};
-use crate::{BuiltTokens, Source};
+use crate::{BuiltTokens, Source, internal_error};
 
 /// `let` statement for saving values to variables.
 #[derive(Debug)]
@@ -20,31 +23,73 @@ pub(crate) struct Let<'a> {
     /// Pattern for destructuring
     pattern: Pattern<'a>,
 
-    /// `=` operator
-    operator: Source<'a>,
-
     /// Value to save to the variable
-    expr: ExpressionAccess<'a>,
+    value: LetValue<'a>,
 
     /// Source for the entire statement
     source: Source<'a>,
 }
 
+/// Value of a `let` statement.
+#[derive(Debug)]
+enum LetValue<'a> {
+    /// `= expression`
+    Expression {
+        /// `=` operator
+        operator: Source<'a>,
+
+        /// Value to save to the variable
+        expr: Box<ExpressionAccess<'a>>,
+    },
+
+    /// Contents up until `endlet`,
+    /// rendered into a string.
+    Template {
+        template: Template<'a>,
+        is_ended: bool,
+    },
+}
+
 impl<'a> Let<'a> {
     /// Attempt to parse a `let` statement from the current input.
     pub fn parse(tokens: TokenSlice<'a>) -> Res<'a, Self> {
-        let (tokens, (keyword, pattern, operator, expr)) = (
+        let (tokens, (keyword, pattern)) = (
             KeywordParser::new("let"),
             cut("Expected a pattern", Pattern::parse),
-            cut("Expected `=`", take(TokenKind::Equal)),
-            cut("Expected an expression", expression(true, true)),
         )
             .parse(tokens)?;
 
+        // Only a plain variable name can capture rendered contents.
+        let (tokens, operator) = if let Pattern::Ident(_) = pattern {
+            opt(take(TokenKind::Equal)).parse(tokens)?
+        } else {
+            let (tokens, operator) = cut("Expected `=`", take(TokenKind::Equal)).parse(tokens)?;
+            (tokens, Some(operator))
+        };
+
         let source = keyword
             .source()
             .clone()
-            .merge(pattern.source(), "Variable name expected after `let`")
+            .merge(pattern.source(), "Variable name expected after `let`");
+
+        let Some(operator) = operator else {
+            return Ok((
+                tokens,
+                Self {
+                    keyword,
+                    pattern,
+                    value: LetValue::Template {
+                        template: Template(vec![]),
+                        is_ended: false,
+                    },
+                    source,
+                },
+            ));
+        };
+
+        let (tokens, expr) = cut("Expected an expression", expression(true, true)).parse(tokens)?;
+
+        let source = source
             .merge(operator.source(), "`=` expected after variable name")
             .merge(&expr.source(), "Expression expected after expression");
 
@@ -53,8 +98,10 @@ impl<'a> Let<'a> {
             Self {
                 keyword,
                 pattern,
-                operator: operator.source().clone(),
-                expr,
+                value: LetValue::Expression {
+                    operator: operator.source().clone(),
+                    expr: Box::new(expr),
+                },
                 source,
             },
         ))
@@ -70,19 +117,107 @@ impl<'a> Let<'a> {
         self.pattern.get_variables()
     }
 
+    /// Whether the statement has been closed.
+    /// Only the block form needs to be closed with `endlet`.
+    pub fn is_ended(&self) -> bool {
+        match &self.value {
+            LetValue::Expression { .. } => true,
+            LetValue::Template { is_ended, .. } => *is_ended,
+        }
+    }
+
+    pub(crate) fn add_item(&mut self, item: Item<'a>) {
+        let LetValue::Template { template, is_ended } = &mut self.value else {
+            internal_error!(
+                item.source().span_token().unwrap(),
+                "Attempted to add item to `let` statement without contents",
+            );
+        };
+
+        if *is_ended {
+            internal_error!(
+                item.source().span_token().unwrap(),
+                "Attempted to add item to ended `let` statement",
+            );
+        }
+
+        match item {
+            Item::Statement(Statement {
+                kind: StatementKind::EndLet,
+                ..
+            }) => {
+                *is_ended = true;
+            }
+            Item::Statement(Statement { kind, source }) if !kind.expected_in_statements() => {
+                template.0.push(Item::CompileError {
+                    message: "Unexpected statement in `let` statement; is an `endlet` statement \
+                              missing?"
+                        .to_string(),
+                    error_source: source.clone(),
+                    consumed_source: source,
+                });
+            }
+            _ => {
+                template.0.push(item);
+            }
+        }
+    }
+
     /// Build token stream for the statement.
-    pub fn to_tokens(&self, state: &State) -> BuiltTokens {
+    pub fn to_tokens<'b: 'a>(&'a self, state: &mut State<'b>) -> BuiltTokens {
         let span = self.source.span_token();
         let keyword = &self.keyword;
         let pattern = self.pattern.to_tokens(state);
-        let operator_span = self.operator.span_token();
-        let operator = quote_spanned! {operator_span=> = };
-        let (expr, _estimated_length) = self.expr.to_tokens(state);
 
-        (
-            quote_spanned! {span=> #keyword #pattern #operator #expr; },
-            0,
-        )
+        match &self.value {
+            LetValue::Expression { operator, expr } => {
+                let operator_span = operator.span_token();
+                let operator = quote_spanned! {operator_span=> = };
+                let (expr, _estimated_length) = expr.to_tokens(state);
+
+                (
+                    quote_spanned! {span=> #keyword #pattern #operator #expr; },
+                    0,
+                )
+            }
+            LetValue::Template { template, .. } => {
+                let (template, estimated_length) = template.to_tokens(state);
+                let value = Self::escaped_string(state, quote! { oxiplate_string });
+
+                (
+                    quote_spanned! {span=>
+                        #keyword #pattern = {
+                            let mut oxiplate_string = alloc::string::String::with_capacity(#estimated_length);
+                            {
+                                let oxiplate_formatter = &mut oxiplate_string;
+                                #template
+                            }
+                            #value
+                        };
+                    },
+                    0,
+                )
+            }
+        }
+    }
+
+    /// Mark the rendered contents as already escaped
+    /// for the template's current escaper group, if it has one.
+    #[cfg(feature = "_oxiplate")]
+    fn escaped_string(state: &State, string: TokenStream) -> TokenStream {
+        let Some(group) = state
+            .output_escaper_group()
+            .and_then(|group| syn::parse_str::<syn::Path>(&group.escaper).ok())
+        else {
+            return string;
+        };
+
+        quote! { ::oxiplate::EscapedString::<#group>::new(#string) }
+    }
+
+    #[cfg(not(feature = "_oxiplate"))]
+    fn escaped_string(_state: &State, string: TokenStream) -> TokenStream {
+        string
     }
 }
 
@@ -94,3 +229,15 @@ impl<'a> From<Let<'a>> for Statement<'a> {
         }
     }
 }
+
+pub(super) fn parse_endlet(tokens: TokenSlice) -> Res<Statement> {
+    let (tokens, output) = KeywordParser::new("endlet").parse(tokens)?;
+
+    Ok((
+        tokens,
+        Statement {
+            kind: StatementKind::EndLet,
+            source: output.source().clone(),
+        },
+    ))
+}
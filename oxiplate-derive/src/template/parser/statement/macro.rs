@@ -0,0 +1,381 @@
+use proc_macro2::TokenStream;
+use quote::{TokenStreamExt, quote, quote_spanned};
+
+use super::super::expression::{ArgumentsGroup, Identifier, arguments};
+use super::super::{Item, Res};
+use super::helpers::ty::Type;
+use super::{Statement, StatementKind};
+use crate::html_context::HtmlContext;
+use crate::parser::{Parser as _, cut, many0, opt, take};
+use crate::state::MacroDefinition;
+use crate::template::parser::expression::KeywordParser;
+use crate::template::parser::template::Template;
+use crate::template::tokenizer::{Token, TokenKind, TokenSlice};
+use crate::{BuiltTokens, Source, State, internal_error};
+
+/// `macro` statement for defining a reusable piece of a template.
+#[derive(Debug)]
+pub struct Macro<'a> {
+    name: Identifier<'a>,
+    parameters: Vec<Parameter<'a>>,
+    template: Template<'a>,
+    pub(super) is_ended: bool,
+}
+
+/// Parameter of a macro with an optional type.
+#[derive(Debug)]
+struct Parameter<'a> {
+    name: Identifier<'a>,
+    ty: Option<Type<'a>>,
+    source: Source<'a>,
+}
+
+impl<'a> Parameter<'a> {
+    fn parse(tokens: TokenSlice<'a>) -> Res<'a, Self> {
+        let (tokens, (name, ty)) = (
+            Identifier::parse,
+            opt((
+                take(TokenKind::Colon),
+                cut("Expected a type after `:`", Type::parse),
+            )),
+        )
+            .parse(tokens)?;
+
+        let (ty, source) = if let Some((colon, ty)) = ty {
+            let source = name
+                .source()
+                .clone()
+                .merge(colon.source(), "`:` expected after parameter name")
+                .merge(ty.source(), "Type expected after `:`");
+            (Some(ty), source)
+        } else {
+            (None, name.source().clone())
+        };
+
+        Ok((tokens, Self { name, ty, source }))
+    }
+
+    fn source(&self) -> &Source<'a> {
+        &self.source
+    }
+}
+
+impl<'a> Macro<'a> {
+    pub(crate) fn add_item(&mut self, item: Item<'a>) {
+        if self.is_ended {
+            internal_error!(
+                item.source().span_token().unwrap(),
+                "Attempted to add item to ended `macro` statement",
+            );
+        }
+
+        match item {
+            Item::Statement(Statement {
+                kind: StatementKind::EndMacro,
+                ..
+            }) => {
+                self.is_ended = true;
+            }
+            Item::Statement(Statement { kind, source }) if !kind.expected_in_statements() => {
+                self.template.0.push(Item::CompileError {
+                    message: "Unexpected statement in `macro` statement; is an `endmacro` \
+                              statement missing?"
+                        .to_string(),
+                    error_source: source.clone(),
+                    consumed_source: source,
+                });
+            }
+            _ => {
+                self.template.0.push(item);
+            }
+        }
+    }
+
+    /// Build the macro's template and save it to the state
+    /// so it can be expanded wherever it's called.
+    /// Nothing is output where the macro is defined
+    /// unless there are errors within the macro.
+    pub(crate) fn to_tokens<'b: 'a>(&self, state: &mut State<'b>) -> BuiltTokens {
+        // Errors like the macro never being closed
+        // need to be output even if the macro is never called.
+        let mut errors = TokenStream::new();
+        for item in &self.template.0 {
+            if let Item::CompileError {
+                message,
+                error_source,
+                ..
+            } = item
+            {
+                let span = error_source.span_token();
+                errors.append_all(quote_spanned! {span=> compile_error!(#message); });
+            }
+        }
+
+        let template = if errors.is_empty() {
+            state.local_variables.push_stack();
+            state.local_variables.add(
+                self.parameters
+                    .iter()
+                    .map(|parameter| parameter.name.as_str().to_string())
+                    .collect(),
+            );
+
+            // Macros can only be called in HTML text,
+            // so they're built as if they're in text
+            // and need to end there too.
+            let context = std::mem::take(&mut state.html_context);
+            let template = self.template.to_tokens(state);
+            let end_context = std::mem::replace(&mut state.html_context, context);
+            if state.tracks_html_context() && end_context != HtmlContext::Text {
+                let span = self.name.source().span_token();
+                let message = format!(
+                    "Macro `{}` ends {}, but macros need to end in HTML text so the template \
+                     calling them can continue to be escaped correctly. Keep tags, comments, and \
+                     quoted attribute values balanced within the macro.",
+                    self.name.as_str(),
+                    end_context.location(),
+                );
+                errors.append_all(quote_spanned! {span=> compile_error!(#message); });
+            }
+
+            state.local_variables.pop_stack();
+            template
+        } else {
+            (TokenStream::new(), 0)
+        };
+
+        let parameters = self
+            .parameters
+            .iter()
+            .map(|Parameter { name, ty, .. }| (quote! { #name }, ty.as_ref().map(Type::to_tokens)))
+            .collect();
+
+        state.macros.insert(
+            self.name.as_str().to_string(),
+            MacroDefinition {
+                parameters,
+                template,
+            },
+        );
+
+        (errors, 0)
+    }
+}
+
+impl<'a> From<Macro<'a>> for StatementKind<'a> {
+    fn from(statement: Macro<'a>) -> Self {
+        StatementKind::Macro(statement)
+    }
+}
+
+/// Name a macro is stored under in the state,
+/// including the namespace it was imported into, if any.
+/// E.g., `link` or `ui.link`.
+pub(crate) fn full_name(namespace: Option<&Identifier>, name: &Identifier) -> std::string::String {
+    if let Some(namespace) = namespace {
+        format!("{}.{}", namespace.as_str(), name.as_str())
+    } else {
+        name.as_str().to_string()
+    }
+}
+
+/// Expand the macro with the provided name
+/// if one has been defined or imported.
+pub(crate) fn expand(
+    state: &mut State,
+    namespace: Option<&Identifier>,
+    name: &Identifier,
+    arguments: &ArgumentsGroup,
+) -> Option<BuiltTokens> {
+    let span = name.source().span_token();
+    let name = full_name(namespace, name);
+    if !state.macros.contains_key(&name) {
+        return None;
+    }
+
+    if state.tracks_html_context() && state.html_context != HtmlContext::Text {
+        let message = format!(
+            "Macro `{name}` is called {}, but macros are built for HTML text, so values within \
+             them wouldn't be escaped correctly. Call the macro within text instead.",
+            state.html_context.location(),
+        );
+        return Some((quote_spanned! {span=> compile_error!(#message); }, 0));
+    }
+
+    let values: Vec<TokenStream> = arguments
+        .expressions()
+        .into_iter()
+        .map(|value| value.to_tokens(state).0)
+        .collect();
+    let definition = &state.macros[&name];
+    if values.len() != definition.parameters.len() {
+        let span = arguments.source().span_token();
+        let message = format!(
+            "Macro `{name}` expects {} argument(s), but {} were provided",
+            definition.parameters.len(),
+            values.len(),
+        );
+        return Some((quote_spanned! {span=> compile_error!(#message); }, 0));
+    }
+
+    let (template, estimated_length) = &definition.template;
+    if values.is_empty() {
+        return Some((quote! {{ #template }}, *estimated_length));
+    }
+
+    let names = definition.parameters.iter().map(|(name, _ty)| name);
+    let types = definition
+        .parameters
+        .iter()
+        .map(|(_name, ty)| ty.clone().unwrap_or_else(|| quote! { _ }));
+
+    Some((
+        quote! {{
+            let (#(#names,)*): (#(#types,)*) = (#(#values,)*);
+            #template
+        }},
+        *estimated_length,
+    ))
+}
+
+/// `call` statement for expanding a macro.
+#[derive(Debug)]
+pub struct Call<'a> {
+    /// Namespace the macro was imported into, if any.
+    namespace: Option<Identifier<'a>>,
+    name: Identifier<'a>,
+    arguments: ArgumentsGroup<'a>,
+    /// Source for the namespace (if any) and name.
+    name_source: Source<'a>,
+}
+
+impl<'a> Call<'a> {
+    pub fn parse(tokens: TokenSlice<'a>) -> Res<'a, Statement<'a>> {
+        let (tokens, (keyword, first_name, namespaced_name, arguments)) = (
+            KeywordParser::new("call"),
+            cut("Expected the name of a macro", Identifier::parse),
+            opt((
+                take(TokenKind::Period),
+                cut("Expected the name of a macro after `.`", Identifier::parse),
+            )),
+            cut("Expected arguments for the macro", arguments),
+        )
+            .parse(tokens)?;
+
+        let mut name_source = first_name.source().clone();
+        let (namespace, name) = if let Some((period, name)) = namespaced_name {
+            name_source = name_source
+                .merge(period.source(), "`.` expected after namespace")
+                .merge(name.source(), "Macro name expected after `.`");
+            (Some(first_name), name)
+        } else {
+            (None, first_name)
+        };
+
+        let source = keyword
+            .source()
+            .clone()
+            .merge(&name_source, "Macro name expected after `call`")
+            .merge(arguments.source(), "Arguments expected after macro name");
+
+        Ok((
+            tokens,
+            Statement {
+                kind: StatementKind::Call(Self {
+                    namespace,
+                    name,
+                    arguments,
+                    name_source,
+                }),
+                source,
+            },
+        ))
+    }
+
+    pub fn to_tokens(&self, state: &mut State) -> BuiltTokens {
+        expand(state, self.namespace.as_ref(), &self.name, &self.arguments).unwrap_or_else(|| {
+            let span = self.name_source.span_token();
+            let message = format!(
+                "Macro `{}` is not defined",
+                full_name(self.namespace.as_ref(), &self.name)
+            );
+            (quote_spanned! {span=> compile_error!(#message); }, 0)
+        })
+    }
+}
+
+pub(super) fn parse_macro(tokens: TokenSlice) -> Res<Statement> {
+    let (tokens, (keyword, name, (open_paren, parameters, close_paren))) = (
+        KeywordParser::new("macro"),
+        cut("Expected the name of the macro", Identifier::parse),
+        cut(
+            "Expected comma-separated list of parameters wrapped in `()`",
+            (
+                take(TokenKind::OpenParenthese),
+                opt((
+                    Parameter::parse,
+                    many0((take(TokenKind::Comma), Parameter::parse)),
+                    opt(take(TokenKind::Comma)),
+                )),
+                take(TokenKind::CloseParenthese),
+            ),
+        ),
+    )
+        .parse(tokens)?;
+
+    let mut source = keyword
+        .source()
+        .clone()
+        .merge(name.source(), "Macro name expected after `macro`")
+        .merge(open_paren.source(), "`(` expected after macro name");
+
+    let parameters =
+        if let Some((first_parameter, remaining_parameters, trailing_comma)) = parameters {
+            source = source.merge(first_parameter.source(), "Parameter expected after `(`");
+
+            let mut parameters = vec![first_parameter];
+            for (comma, parameter) in remaining_parameters {
+                source = source
+                    .merge(comma.source(), "Comma expected after parameter")
+                    .merge(parameter.source(), "Parameter expected after comma");
+                parameters.push(parameter);
+            }
+
+            source = source.merge_some(
+                trailing_comma.map(Token::source),
+                "Comma expected after parameter",
+            );
+
+            parameters
+        } else {
+            vec![]
+        };
+
+    source = source.merge(close_paren.source(), "`)` expected after parameters");
+
+    Ok((
+        tokens,
+        Statement {
+            kind: Macro {
+                name,
+                parameters,
+                template: Template(vec![]),
+                is_ended: false,
+            }
+            .into(),
+            source,
+        },
+    ))
+}
+
+pub(super) fn parse_endmacro(tokens: TokenSlice) -> Res<Statement> {
+    let (tokens, output) = KeywordParser::new("endmacro").parse(tokens)?;
+
+    Ok((
+        tokens,
+        Statement {
+            kind: StatementKind::EndMacro,
+            source: output.source().clone(),
+        },
+    ))
+}
@@ -4,7 +4,8 @@ use proc_macro2::TokenStream;
 use quote::{TokenStreamExt, quote, quote_spanned};
 
 use super::super::Item;
-use super::{Statement, StatementKind};
+use super::{Statement, StatementKind, finish_branches};
+use crate::html_context::Branches;
 use crate::parser::{Parser as _, cut, many0, opt, take};
 use crate::template::parser::Res;
 use crate::template::parser::expression::{ExpressionAccess, KeywordParser, expression};
@@ -76,13 +77,16 @@ impl<'a> Match<'a> {
         }
     }
 
-    pub(crate) fn to_tokens(&self, state: &mut State) -> BuiltTokens {
+    pub(crate) fn to_tokens(&self, state: &mut State, source: &Source) -> BuiltTokens {
         let mut tokens = TokenStream::new();
         let mut estimated_length = usize::MAX;
+        let mut branches = Branches::new(&state.html_context);
 
         let mut cases = TokenStream::new();
         for case in &self.cases {
+            state.html_context = branches.start();
             let (case, case_length) = case.to_tokens(state);
+            branches.end(&state.html_context);
             estimated_length = estimated_length.min(case_length);
             cases.append_all(case);
         }
@@ -91,6 +95,7 @@ impl<'a> Match<'a> {
         let (errors, _errors_length) = self.errors.to_tokens(state);
 
         tokens.append_all(quote! { #errors match #expression { #cases } });
+        tokens.append_all(finish_branches(state, branches, "match", source));
 
         (tokens, estimated_length)
     }
@@ -275,7 +280,7 @@ impl<'a> Guard<'a> {
         &self.source
     }
 
-    pub fn to_tokens(&self, state: &State) -> TokenStream {
+    pub fn to_tokens(&self, state: &mut State) -> TokenStream {
         let if_span = self.if_tag.span_token();
         let (expression, _estimated_length) = self.expression.to_tokens(state);
 
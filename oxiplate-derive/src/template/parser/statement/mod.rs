@@ -1,18 +1,25 @@
 mod block;
 mod escaper;
 mod extends;
+mod filter;
 mod r#for;
-mod helpers;
+pub(super) mod helpers;
 mod r#if;
+mod import;
 mod include;
 mod r#let;
+mod r#macro;
 mod r#match;
+mod raw;
+mod trans;
 
 use quote::quote_spanned;
 
 pub(crate) use self::escaper::DefaultEscaper;
+pub(super) use self::r#macro::expand as expand_macro;
 use super::r#static::StaticType;
 use super::{Item, Res};
+use crate::html_context::Branches;
 use crate::parser::{Parser as _, alt, cut, into};
 use crate::template::parser::Error;
 use crate::template::parser::item::tag_end;
@@ -37,6 +44,7 @@ pub(crate) enum StatementKind<'a> {
     EndBlock,
 
     Include(include::Include<'a>),
+    Import(import::Import<'a>),
 
     If(r#if::If<'a>),
     ElseIf(r#if::ElseIf<'a>),
@@ -53,6 +61,20 @@ pub(crate) enum StatementKind<'a> {
     EndMatch,
 
     Let(Let<'a>),
+    EndLet,
+
+    Macro(r#macro::Macro<'a>),
+    EndMacro,
+    Call(r#macro::Call<'a>),
+
+    Filter(filter::Filter<'a>),
+    EndFilter,
+
+    Raw(raw::Raw<'a>),
+    EndRaw,
+
+    Trans(trans::Trans<'a>),
+    EndTrans,
 }
 
 impl StatementKind<'_> {
@@ -71,16 +93,27 @@ impl StatementKind<'_> {
             | Self::EndIf
             | Self::EndFor
             | Self::Case(_)
-            | Self::EndMatch => false,
+            | Self::EndMatch
+            | Self::EndLet
+            | Self::EndMacro
+            | Self::EndFilter
+            | Self::EndRaw
+            | Self::EndTrans => false,
 
             Self::Block(_)
             | Self::Include(_)
+            | Self::Import(_)
             | Self::If(_)
             | Self::For(_)
             | Self::Continue(_)
             | Self::Break(_)
             | Self::Match(_)
-            | Self::Let(_) => true,
+            | Self::Let(_)
+            | Self::Macro(_)
+            | Self::Call(_)
+            | Self::Filter(_)
+            | Self::Raw(_)
+            | Self::Trans(_) => true,
         }
     }
 }
@@ -105,8 +138,14 @@ impl<'a> Statement<'a> {
             If(statement) => statement.is_ended,
             For(statement) => statement.is_ended,
             Match(statement) => statement.is_ended(),
-            DefaultEscaper(_) | Parent | EndBlock | Include(_) | ElseIf(_) | Else | EndIf
-            | Continue(_) | Break(_) | EndFor | Case(_) | EndMatch | Let(_) => true,
+            Let(statement) => statement.is_ended(),
+            Macro(statement) => statement.is_ended,
+            Filter(statement) => statement.is_ended,
+            Raw(statement) => statement.is_ended,
+            Trans(statement) => statement.is_ended,
+            DefaultEscaper(_) | Parent | EndBlock | Include(_) | Import(_) | ElseIf(_) | Else
+            | EndIf | Continue(_) | Break(_) | EndFor | Case(_) | EndMatch | EndLet | EndMacro
+            | Call(_) | EndFilter | EndRaw | EndTrans => true,
         }
     }
 
@@ -125,8 +164,14 @@ impl<'a> Statement<'a> {
             If(statement) => statement.add_item(item),
             For(statement) => statement.add_item(item),
             Match(statement) => statement.add_item(item),
-            DefaultEscaper(_) | Parent | EndBlock | Include(_) | ElseIf(_) | Else | EndIf
-            | Continue(_) | Break(_) | EndFor | Case(_) | EndMatch | Let(_) => {
+            Let(statement) => statement.add_item(item),
+            Macro(statement) => statement.add_item(item),
+            Filter(statement) => statement.add_item(item),
+            Raw(statement) => statement.add_item(item),
+            Trans(statement) => statement.add_item(item),
+            DefaultEscaper(_) | Parent | EndBlock | Include(_) | Import(_) | ElseIf(_) | Else
+            | EndIf | Continue(_) | Break(_) | EndFor | Case(_) | EndMatch | EndLet | EndMacro
+            | Call(_) | EndFilter | EndRaw | EndTrans => {
                 unreachable!("add_item() should not be called for this kind of statement")
             }
         }
@@ -166,19 +211,30 @@ impl<'a> Statement<'a> {
             StatementKind::Block(block) => Ok(block.to_tokens(state)),
             StatementKind::Parent => unexpected!("parent"),
             StatementKind::EndBlock => unexpected!("endblock"),
-            StatementKind::Include(statement) => Ok(statement.to_tokens()),
-            StatementKind::If(statement) => Ok(statement.to_tokens(state)),
+            StatementKind::Include(statement) => Ok(statement.to_tokens(state, &self.source)),
+            StatementKind::Import(statement) => Ok(statement.to_tokens(state)),
+            StatementKind::If(statement) => Ok(statement.to_tokens(state, &self.source)),
             StatementKind::ElseIf(_) => unexpected!("elseif"),
             StatementKind::Else => unexpected!("else"),
             StatementKind::EndIf => unexpected!("endif"),
-            StatementKind::For(statement) => Ok(statement.to_tokens(state)),
+            StatementKind::For(statement) => Ok(statement.to_tokens(state, &self.source)),
             StatementKind::Continue(statement) => Ok(statement.to_tokens()),
             StatementKind::Break(statement) => Ok(statement.to_tokens()),
             StatementKind::EndFor => unexpected!("endfor"),
-            StatementKind::Match(statement) => Ok(statement.to_tokens(state)),
+            StatementKind::Match(statement) => Ok(statement.to_tokens(state, &self.source)),
             StatementKind::Case(_) => unexpected!("case"),
             StatementKind::EndMatch => unexpected!("endmatch"),
             StatementKind::Let(statement) => Ok(statement.to_tokens(state)),
+            StatementKind::EndLet => unexpected!("endlet"),
+            StatementKind::Macro(statement) => Ok(statement.to_tokens(state)),
+            StatementKind::EndMacro => unexpected!("endmacro"),
+            StatementKind::Call(statement) => Ok(statement.to_tokens(state)),
+            StatementKind::Filter(statement) => Ok(statement.to_tokens(state)),
+            StatementKind::EndFilter => unexpected!("endfilter"),
+            StatementKind::Raw(statement) => Ok(statement.to_tokens(state)),
+            StatementKind::EndRaw => unexpected!("endraw"),
+            StatementKind::Trans(statement) => Ok(statement.to_tokens(state)),
+            StatementKind::EndTrans => unexpected!("endtrans"),
         };
 
         state.local_variables.pop_stack();
@@ -187,6 +243,35 @@ impl<'a> Statement<'a> {
     }
 }
 
+/// Continue from the HTML context the branches of a statement end in,
+/// or build an error if they end in different contexts.
+fn finish_branches(
+    state: &mut State,
+    branches: Branches,
+    statement: &str,
+    source: &Source,
+) -> proc_macro2::TokenStream {
+    match branches.finish() {
+        Ok(context) => {
+            state.html_context = context;
+            proc_macro2::TokenStream::new()
+        }
+        Err((first, second)) if state.tracks_html_context() => {
+            let message = format!(
+                "Branches of this `{statement}` statement end in different HTML contexts: one \
+                 ends {first} and another {second}. Keep tags, comments, and quoted attribute \
+                 values balanced within each branch so the writs after it can be escaped \
+                 correctly."
+            );
+            let span = source.span_token();
+            quote_spanned! {span=> compile_error!(#message); }
+        }
+        // The context is only lexed for minifying,
+        // which doesn't need to know it exactly.
+        Err(_mismatch) => proc_macro2::TokenStream::new(),
+    }
+}
+
 impl<'a> From<Statement<'a>> for Item<'a> {
     fn from(statement: Statement<'a>) -> Self {
         Item::Statement(statement)
@@ -202,7 +287,8 @@ pub(super) fn statement<'a>(
         let (tokens, mut statement): (TokenSlice<'a>, Statement<'a>) = cut(
             "Expected one of: default_escaper_group, replace_escaper_group, extends, block, \
              endblock, include, if, elseif, else, endif, for, continue, break, endfor, match, \
-             case, endmatch, let",
+             case, endmatch, let, endlet, macro, endmacro, call, import, from, filter, endfilter, \
+             raw, endraw, trans, endtrans",
             alt((
                 escaper::parse_default_escaper_group,
                 extends::parse_extends,
@@ -221,7 +307,21 @@ pub(super) fn statement<'a>(
                 r#match::Match::parse,
                 r#match::Case::parse,
                 r#match::Match::parse_end,
-                into(Let::parse),
+                alt((
+                    into(Let::parse),
+                    r#let::parse_endlet,
+                    r#macro::parse_macro,
+                    r#macro::parse_endmacro,
+                    r#macro::Call::parse,
+                    import::parse_import,
+                    import::parse_from,
+                    filter::parse_filter,
+                    filter::parse_endfilter,
+                    raw::parse_raw,
+                    raw::parse_endraw,
+                    trans::parse_trans,
+                    trans::parse_endtrans,
+                )),
             )),
         )
         .parse(tokens)?;
@@ -321,11 +421,17 @@ fn eof<'a>(
         StatementKind::If(_) => context_message!("if"),
         StatementKind::For(_) => context_message!("for"),
         StatementKind::Match(_) => context_message!("match"),
+        StatementKind::Let(_) => context_message!("let"),
+        StatementKind::Macro(_) => context_message!("macro"),
+        StatementKind::Filter(_) => context_message!("filter"),
+        StatementKind::Raw(_) => context_message!("raw"),
+        StatementKind::Trans(_) => context_message!("trans"),
         StatementKind::DefaultEscaper(_)
         | StatementKind::Extends(_)
         | StatementKind::Parent
         | StatementKind::EndBlock
         | StatementKind::Include(_)
+        | StatementKind::Import(_)
         | StatementKind::ElseIf(_)
         | StatementKind::Else
         | StatementKind::EndIf
@@ -334,7 +440,12 @@ fn eof<'a>(
         | StatementKind::EndFor
         | StatementKind::Case(_)
         | StatementKind::EndMatch
-        | StatementKind::Let(_) => {
+        | StatementKind::EndLet
+        | StatementKind::EndMacro
+        | StatementKind::Call(_)
+        | StatementKind::EndFilter
+        | StatementKind::EndRaw
+        | StatementKind::EndTrans => {
             unreachable!("These blocks should never fail to be closed because of EOF")
         }
     };
@@ -0,0 +1,76 @@
+use super::super::{Item, Res};
+use super::{Statement, StatementKind};
+use crate::parser::Parser as _;
+use crate::template::parser::expression::KeywordParser;
+use crate::template::parser::template::Template;
+use crate::template::tokenizer::TokenSlice;
+use crate::{BuiltTokens, State, internal_error};
+
+/// `raw` statement for outputting its contents without parsing any tags.
+/// The tokenizer passes everything up until `endraw` through as static text.
+#[derive(Debug)]
+pub struct Raw<'a> {
+    template: Template<'a>,
+    pub(super) is_ended: bool,
+}
+
+impl<'a> Raw<'a> {
+    pub(crate) fn add_item(&mut self, item: Item<'a>) {
+        if self.is_ended {
+            internal_error!(
+                item.source().span_token().unwrap(),
+                "Attempted to add item to ended `raw` statement",
+            );
+        }
+
+        match item {
+            Item::Statement(Statement {
+                kind: StatementKind::EndRaw,
+                ..
+            }) => {
+                self.is_ended = true;
+            }
+            _ => {
+                self.template.0.push(item);
+            }
+        }
+    }
+
+    pub(crate) fn to_tokens<'b: 'a>(&'a self, state: &mut State<'b>) -> BuiltTokens {
+        self.template.to_tokens(state)
+    }
+}
+
+impl<'a> From<Raw<'a>> for StatementKind<'a> {
+    fn from(statement: Raw<'a>) -> Self {
+        StatementKind::Raw(statement)
+    }
+}
+
+pub(super) fn parse_raw(tokens: TokenSlice) -> Res<Statement> {
+    let (tokens, output) = KeywordParser::new("raw").parse(tokens)?;
+
+    Ok((
+        tokens,
+        Statement {
+            kind: Raw {
+                template: Template(vec![]),
+                is_ended: false,
+            }
+            .into(),
+            source: output.source().clone(),
+        },
+    ))
+}
+
+pub(super) fn parse_endraw(tokens: TokenSlice) -> Res<Statement> {
+    let (tokens, output) = KeywordParser::new("endraw").parse(tokens)?;
+
+    Ok((
+        tokens,
+        Statement {
+            kind: StatementKind::EndRaw,
+            source: output.source().clone(),
+        },
+    ))
+}
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+
+use proc_macro2::TokenStream;
+use quote::{TokenStreamExt, quote_spanned};
+
+use super::super::writ::Writ;
+use super::super::{Item, Res};
+use super::{Statement, StatementKind};
+use crate::i18n::{escape_message_text, missing_locale_error};
+use crate::parser::Parser as _;
+use crate::template::parser::expression::KeywordParser;
+use crate::template::parser::template::Template;
+use crate::template::tokenizer::TokenSlice;
+use crate::{BuiltTokens, Source, State, internal_error};
+
+/// `trans` statement for translating its contents.
+/// The contents are used as the message ID,
+/// with variables like `{{ name }}` becoming placeholders like `{name}`.
+#[derive(Debug)]
+pub struct Trans<'a> {
+    /// The `trans` keyword.
+    keyword: Source<'a>,
+    template: Template<'a>,
+    pub(super) is_ended: bool,
+}
+
+impl<'a> Trans<'a> {
+    pub(crate) fn add_item(&mut self, item: Item<'a>) {
+        if self.is_ended {
+            internal_error!(
+                item.source().span_token().unwrap(),
+                "Attempted to add item to ended `trans` statement",
+            );
+        }
+
+        match item {
+            Item::Statement(Statement {
+                kind: StatementKind::EndTrans,
+                ..
+            }) => {
+                self.is_ended = true;
+            }
+            Item::Statement(Statement { kind, source }) => {
+                let message = if kind.expected_in_statements() {
+                    "Only text and variables are allowed in `trans` statements"
+                } else {
+                    "Unexpected statement in `trans` statement; is an `endtrans` statement missing?"
+                };
+                self.template.0.push(Item::CompileError {
+                    message: message.to_string(),
+                    error_source: source.clone(),
+                    consumed_source: source,
+                });
+            }
+            Item::Writ(writ) if writ.as_variable().is_none() => {
+                self.template.0.push(Item::CompileError {
+                    message: "Only variables like `{{ name }}` can be output in `trans` \
+                              statements; use `let` to assign other values to a variable first"
+                        .to_string(),
+                    error_source: writ.source().clone(),
+                    consumed_source: writ.source().clone(),
+                });
+            }
+            _ => {
+                self.template.0.push(item);
+            }
+        }
+    }
+
+    /// Build the message ID from the contents
+    /// and output the translation for the current locale.
+    pub(crate) fn to_tokens(&self, state: &mut State) -> BuiltTokens {
+        let span = self.keyword.span_token();
+
+        let mut errors = TokenStream::new();
+        let mut id = String::new();
+        let mut writs: HashMap<&str, &Writ> = HashMap::new();
+        for item in &self.template.0 {
+            match item {
+                Item::Static(text, _) | Item::Whitespace(text) => {
+                    id.push_str(&escape_message_text(text.0));
+                }
+                Item::Writ(writ) => {
+                    let Some(variable) = writ.as_variable() else {
+                        unreachable!("Only writs with variables should be added to `trans`");
+                    };
+                    id.push('{');
+                    id.push_str(variable.as_str());
+                    id.push('}');
+                    writs.entry(variable.as_str()).or_insert(writ);
+                }
+                Item::CompileError {
+                    message,
+                    error_source,
+                    ..
+                } => {
+                    let span = error_source.span_token();
+                    errors.append_all(quote_spanned! {span=> compile_error!(#message); });
+                }
+                Item::Comment(_) | Item::Statement(_) => (),
+            }
+        }
+
+        if !errors.is_empty() {
+            return (errors, 0);
+        }
+
+        let Some(locale) = state.locale.clone() else {
+            return missing_locale_error(span);
+        };
+
+        // Writs are built for each translation
+        // so they're escaped for where the translation puts them.
+        locale.translate(state, &id, span, |state, name| {
+            writs.get(name).map(|writ| writ.to_token(state))
+        })
+    }
+}
+
+impl<'a> From<Trans<'a>> for StatementKind<'a> {
+    fn from(statement: Trans<'a>) -> Self {
+        StatementKind::Trans(statement)
+    }
+}
+
+pub(super) fn parse_trans(tokens: TokenSlice) -> Res<Statement> {
+    let (tokens, keyword) = KeywordParser::new("trans").parse(tokens)?;
+
+    Ok((
+        tokens,
+        Statement {
+            kind: Trans {
+                keyword: keyword.source().clone(),
+                template: Template(vec![]),
+                is_ended: false,
+            }
+            .into(),
+            source: keyword.source().clone(),
+        },
+    ))
+}
+
+pub(super) fn parse_endtrans(tokens: TokenSlice) -> Res<Statement> {
+    let (tokens, output) = KeywordParser::new("endtrans").parse(tokens)?;
+
+    Ok((
+        tokens,
+        Statement {
+            kind: StatementKind::EndTrans,
+            source: output.source().clone(),
+        },
+    ))
+}
@@ -1,22 +1,137 @@
 use quote::quote_spanned;
 
 use super::Item;
+use crate::html_context::HtmlContext;
 use crate::parser::{Parser as _, alt, take};
 use crate::template::parser::Res;
 use crate::template::tokenizer::{TokenKind, TokenSlice};
-use crate::{BuiltTokens, Source};
+use crate::{BuiltTokens, Source, State};
+
+/// Elements whose contents are output as-is when minifying HTML.
+const RAW_TEXT_ELEMENTS: [&str; 4] = ["pre", "textarea", "script", "style"];
 
 #[derive(Debug)]
 pub(crate) struct Static<'a>(pub &'a str, pub Source<'a>);
 
 impl Static<'_> {
-    pub fn to_token(&self) -> BuiltTokens {
-        let text = &self.0;
+    pub fn to_token(&self, state: &mut State) -> BuiltTokens {
         let span = self.1.span_token();
+        let text = if state.minifies_html() {
+            minify_html(
+                self.0,
+                &mut state.html_raw_text_element,
+                &mut state.html_context,
+            )
+        } else {
+            if state.tracks_html_context() {
+                state.html_context.push_str(self.0);
+            }
+            self.0.to_string()
+        };
+        push_static_line(&mut state.static_line, &text);
+
         (quote_spanned! { span => #text }, text.len())
     }
 }
 
+/// Keep track of the static text after the last line break.
+fn push_static_line(static_line: &mut String, text: &str) {
+    if let Some(index) = text.rfind('\n') {
+        static_line.clear();
+        static_line.push_str(&text[index + 1..]);
+    } else {
+        static_line.push_str(text);
+    }
+}
+
+/// Collapse each run of whitespace in HTML to a single line break
+/// (if the run contained one) or space.
+/// The contents of raw text elements like `<pre>` and quoted attribute values
+/// are left untouched.
+/// `raw_text_element` tracks the raw text element that is open
+/// across static text separated by tags,
+/// and `context` is updated with the text to find attribute values.
+fn minify_html(
+    text: &str,
+    raw_text_element: &mut Option<&'static str>,
+    context: &mut HtmlContext,
+) -> String {
+    let lowercase_text = text.to_ascii_lowercase();
+    let mut minified = String::with_capacity(text.len());
+    let mut offset = 0;
+    while offset < text.len() {
+        if let Some(element) = *raw_text_element {
+            let end = find_tag(&lowercase_text[offset..], "</", element)
+                .map_or(text.len(), |end| offset + end);
+            minified.push_str(&text[offset..end]);
+            context.push_str(&text[offset..end]);
+            if end < text.len() {
+                *raw_text_element = None;
+            }
+            offset = end;
+            continue;
+        }
+
+        let opening_tag = RAW_TEXT_ELEMENTS
+            .iter()
+            .filter_map(|element| {
+                find_tag(&lowercase_text[offset..], "<", element)
+                    .map(|start| (offset + start, *element))
+            })
+            .min_by_key(|(start, _element)| *start);
+        let end = opening_tag.map_or(text.len(), |(start, element)| start + 1 + element.len());
+        collapse_whitespace(&text[offset..end], context, &mut minified);
+        *raw_text_element = opening_tag.map(|(_start, element)| element);
+        offset = end;
+    }
+
+    minified
+}
+
+/// Find the start of a tag like `<pre` or `</pre`
+/// that is followed by whitespace, `>`, `/`, or the end of the text.
+fn find_tag(lowercase_text: &str, prefix: &str, element: &str) -> Option<usize> {
+    let tag = format!("{prefix}{element}");
+    lowercase_text
+        .match_indices(&tag)
+        .find_map(|(start, _tag)| {
+            lowercase_text[start + tag.len()..]
+                .chars()
+                .next()
+                .is_none_or(|char| char == '>' || char == '/' || char.is_ascii_whitespace())
+                .then_some(start)
+        })
+}
+
+/// Append the text with each run of whitespace collapsed,
+/// other than within quoted attribute values.
+fn collapse_whitespace(text: &str, context: &mut HtmlContext, minified: &mut String) {
+    let mut rest = text;
+    while !rest.is_empty() {
+        let word_end = rest
+            .find(|char: char| char.is_ascii_whitespace())
+            .unwrap_or(rest.len());
+        let (word, after_word) = rest.split_at(word_end);
+        minified.push_str(word);
+        context.push_str(word);
+
+        let whitespace_end = after_word
+            .find(|char: char| !char.is_ascii_whitespace())
+            .unwrap_or(after_word.len());
+        let (whitespace, after_whitespace) = after_word.split_at(whitespace_end);
+        if context.is_quoted_attribute_value() {
+            minified.push_str(whitespace);
+        } else if whitespace.contains(['\n', '\r']) {
+            minified.push('\n');
+        } else if !whitespace.is_empty() {
+            minified.push(' ');
+        }
+        context.push_str(whitespace);
+
+        rest = after_whitespace;
+    }
+}
+
 /// Type of static text.
 #[derive(Debug)]
 pub(crate) enum StaticType {
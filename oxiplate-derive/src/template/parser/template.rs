@@ -2,8 +2,9 @@ use proc_macro2::TokenStream;
 use quote::{TokenStreamExt, quote};
 
 use super::item::{ItemToken, parse_tag};
-use super::r#static::parse_static;
-use super::{Item, Static};
+use super::statement::StatementKind;
+use super::r#static::{StaticType, parse_static};
+use super::{Item, Statement, Static};
 #[cfg(coverage_nightly)]
 use crate::Source;
 use crate::parser::{Parser as _, alt, opt, parse_all, take};
@@ -86,6 +87,44 @@ pub(crate) fn parse<'a, 'b: 'a>(state: &mut State<'b>, tokens: TokenSlice<'a>) -
     }
 }
 
+/// Parse a template that's being imported for the macros it defines.
+/// Only macros, comments, and whitespace are allowed outside of macros,
+/// so nothing is output unless there are errors.
+pub(crate) fn parse_macros<'a, 'b: 'a>(
+    state: &mut State<'b>,
+    tokens: TokenSlice<'a>,
+) -> BuiltTokens {
+    let template = match parse_all(parse_item).parse(tokens) {
+        Ok((_tokens, items_vec)) => Template(
+            items_vec
+                .into_iter()
+                .flatten()
+                .filter_map(|item| match item {
+                    Item::Statement(Statement {
+                        kind: StatementKind::Macro(_),
+                        ..
+                    })
+                    | Item::Comment(_)
+                    | Item::CompileError { .. } => Some(item),
+                    Item::Whitespace(_) | Item::Static(_, StaticType::Whitespace) => None,
+                    Item::Statement(_) | Item::Static(_, StaticType::Text) | Item::Writ(_) => {
+                        Some(Item::CompileError {
+                            message: "Only macros, comments, and whitespace are allowed in \
+                                      imported templates."
+                                .to_owned(),
+                            error_source: item.source().clone(),
+                            consumed_source: item.source().clone(),
+                        })
+                    }
+                })
+                .collect(),
+        ),
+        Err(error) => error.into(),
+    };
+
+    template.to_tokens(state)
+}
+
 pub fn try_parse<'a, 'b: 'a>(
     state: &mut State<'b>,
     tokens: TokenSlice<'a>,
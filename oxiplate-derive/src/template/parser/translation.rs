@@ -0,0 +1,105 @@
+use proc_macro2::TokenStream;
+
+use super::Res;
+use super::expression::{ExpressionAccess, Identifier, KeywordParser, String, expression};
+use crate::i18n::missing_locale_error;
+use crate::parser::{Parser as _, cut, many0, opt, take};
+use crate::template::tokenizer::{Token, TokenKind, TokenSlice};
+use crate::{BuiltTokens, Source, State};
+
+/// Translated message output by a writ
+/// like `{{ t("welcome", name = user.name) }}`.
+#[derive(Debug)]
+pub(crate) struct Translation<'a> {
+    id: String<'a>,
+
+    /// Values for the placeholders in the message.
+    arguments: Vec<(Identifier<'a>, ExpressionAccess<'a>)>,
+    source: Source<'a>,
+}
+
+impl<'a> Translation<'a> {
+    pub(crate) fn parse(tokens: TokenSlice<'a>) -> Res<'a, Self> {
+        let (tokens, (keyword, open_paren, id, arguments, trailing_comma, close_paren)) = (
+            KeywordParser::new("t"),
+            take(TokenKind::OpenParenthese),
+            String::parse,
+            many0((
+                take(TokenKind::Comma),
+                Identifier::parse,
+                take(TokenKind::Equal),
+                cut(
+                    "Expected a value for the placeholder",
+                    expression(true, true),
+                ),
+            )),
+            opt(take(TokenKind::Comma)),
+            take(TokenKind::CloseParenthese),
+        )
+            .parse(tokens)?;
+
+        let mut source = keyword
+            .source()
+            .clone()
+            .merge(open_paren.source(), "`(` expected after `t`")
+            .merge(id.source(), "Message ID expected after `(`");
+
+        let mut placeholders = Vec::with_capacity(arguments.len());
+        for (comma, name, equal, value) in arguments {
+            source = source
+                .merge(comma.source(), "Comma expected after previous argument")
+                .merge(name.source(), "Placeholder name expected after comma")
+                .merge(equal.source(), "`=` expected after placeholder name")
+                .merge(&value.source(), "Value expected after `=`");
+            placeholders.push((name, value));
+        }
+
+        source = source
+            .merge_some(
+                trailing_comma.map(Token::source),
+                "Comma expected after arguments",
+            )
+            .merge(close_paren.source(), "`)` expected after arguments");
+
+        Ok((
+            tokens,
+            Self {
+                id,
+                arguments: placeholders,
+                source,
+            },
+        ))
+    }
+
+    pub(crate) fn source(&self) -> &Source<'a> {
+        &self.source
+    }
+
+    /// Build the value of each placeholder before they're escaped.
+    pub(crate) fn values(&self, state: &mut State) -> Vec<(&str, BuiltTokens)> {
+        self.arguments
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.to_tokens(state)))
+            .collect()
+    }
+
+    /// Output the translation for the current locale,
+    /// escaping the `values()` of each placeholder with `escape()`.
+    pub(crate) fn to_tokens(
+        &self,
+        state: &mut State,
+        values: &[(&str, BuiltTokens)],
+        escape: impl Fn(&State, &TokenStream, usize) -> BuiltTokens,
+    ) -> BuiltTokens {
+        let span = self.source.span_token();
+        let Some(locale) = state.locale.clone() else {
+            return missing_locale_error(span);
+        };
+
+        locale.translate(state, self.id.as_str(), span, |state, name| {
+            let (_name, (value, estimated_length)) =
+                values.iter().find(|(argument, _value)| *argument == name)?;
+            Some(escape(state, value, *estimated_length))
+        })
+    }
+}
@@ -4,12 +4,15 @@ use proc_macro2::{Span, TokenStream};
 use quote::{quote, quote_spanned};
 use syn::spanned::Spanned;
 use syn::token::PathSep;
-use syn::{Path, PathSegment};
+use syn::{Ident, Path, PathSegment};
 
 use super::Item;
-use super::expression::{ExpressionAccess, Identifier, expression};
+use super::expression::{ExpressionAccess, Identifier, KeywordParser, expression};
 use super::item::tag_end;
-use crate::config::EscaperGroup;
+use super::statement::expand_macro;
+use super::translation::Translation;
+use crate::config::{EscaperGroup, LintLevel};
+use crate::html_context::HTML_ESCAPER;
 use crate::parser::{Parser as _, cut, opt, take};
 use crate::template::parser::Res;
 use crate::template::tokenizer::{TagKind, TokenKind, TokenSlice};
@@ -32,22 +35,77 @@ macro_rules! token_error {
 /// the _default_ escaper is assumed.
 #[derive(Debug)]
 pub(crate) struct Writ<'a> {
+    /// Whether each line after the first is indented
+    /// to line up with the start of the writ, from `indent:`.
+    indent: bool,
     escaper: Option<Escaper<'a>>,
-    expression: ExpressionAccess<'a>,
+    output: Output<'a>,
     source: Source<'a>,
 }
 
+/// What a writ outputs.
+#[derive(Debug)]
+enum Output<'a> {
+    Expression(ExpressionAccess<'a>),
+    Translation(Translation<'a>),
+}
+
 impl<'a> Writ<'a> {
     pub(crate) fn source(&self) -> &Source<'a> {
         &self.source
     }
 
-    pub(crate) fn to_token(&self, state: &State<'_>) -> BuiltTokens {
-        let mut estimated_length = 0;
+    /// Get the variable if it's the only thing being output, like `{{ name }}`.
+    pub(crate) fn as_variable(&self) -> Option<&Identifier<'a>> {
+        match &self.output {
+            Output::Expression(expression) => expression.as_identifier(),
+            Output::Translation(_) => None,
+        }
+    }
+
+    pub(crate) fn to_token(&self, state: &mut State<'_>) -> BuiltTokens {
+        let tokens = self.output_tokens(state);
+
+        if self.indent {
+            Self::indented(state, &self.source, tokens)
+        } else {
+            tokens
+        }
+    }
+
+    fn output_tokens(&self, state: &mut State<'_>) -> BuiltTokens {
+        let expression = match &self.output {
+            Output::Expression(expression) => expression,
+            Output::Translation(translation) => {
+                let values = translation.values(state);
+                return translation.to_tokens(state, &values, |state, text, estimated_length| {
+                    self.escape(state, text, estimated_length)
+                });
+            }
+        };
+
+        if let Some((namespace, name, arguments)) = expression.as_macro_call() {
+            if let Some(tokens) = expand_macro(state, namespace, name, arguments) {
+                if let Some(escaper) = &self.escaper {
+                    let span = escaper.escaper.source().span_token();
+                    return token_error!(
+                        span,
+                        "Escapers cannot be applied to macro calls; escape the values within the \
+                         macro instead",
+                    );
+                }
+
+                return tokens;
+            }
+        }
 
-        let (text, text_length) = &self.expression.to_tokens(state);
-        estimated_length += text_length;
+        let (text, estimated_length) = expression.to_tokens(state);
+
+        self.escape(state, &text, estimated_length)
+    }
 
+    /// Escape the text with the writ's escaper.
+    fn escape(&self, state: &State, text: &TokenStream, estimated_length: usize) -> BuiltTokens {
         let span = self.source.span_token();
 
         let escaper_type: EscaperType = match self.escaper_type(state) {
@@ -56,16 +114,37 @@ impl<'a> Writ<'a> {
         };
 
         match escaper_type {
-            EscaperType::Default => Self::escaper_default(state, span, text, estimated_length),
-            EscaperType::Specified(group, group_span, escaper) => Self::escaper_specified(
-                state,
-                &group,
-                group_span,
-                escaper,
-                span,
-                text,
-                estimated_length,
-            ),
+            EscaperType::Default => {
+                if state.tracks_html_context() && !state.config.require_specifying_escaper {
+                    match state.html_context.escaper() {
+                        Ok(Some(escaper)) => {
+                            return Self::escaper_contextual(escaper, span, text, estimated_length);
+                        }
+                        Ok(None) => (),
+                        Err(message) => {
+                            return (quote_spanned! {span=> compile_error!(#message); }, 0);
+                        }
+                    }
+                }
+
+                Self::escaper_default(state, span, text, estimated_length)
+            }
+            EscaperType::Specified(group, group_span, escaper) => {
+                let tokens = Self::escaper_specified(
+                    state,
+                    &group,
+                    group_span,
+                    escaper,
+                    span,
+                    text,
+                    estimated_length,
+                );
+                if state.tracks_html_context() && group.1.escaper == HTML_ESCAPER {
+                    Self::lint_escaper_mismatch(state, escaper.as_str(), span, tokens)
+                } else {
+                    tokens
+                }
+            }
             EscaperType::Raw => Self::escaper_raw(text, estimated_length),
         }
     }
@@ -257,9 +336,13 @@ impl<'a> Writ<'a> {
                 estimated_length,
             )
         } else {
+            // Nested templates escaped for the same group are output as-is.
             (
                 quote_spanned! {span=>
-                    (&&::oxiplate::UnescapedTextWrapper::new(&(#text))).oxiplate_escape(
+                    (&&::oxiplate::EscaperGroupWrapper::<_, #group>::new(
+                        &&::oxiplate::UnescapedTextWrapper::new(&(#text)),
+                    ))
+                    .oxiplate_escape(
                         oxiplate_formatter,
                         &<#group as ::oxiplate::Escaper>::DEFAULT,
                     )?
@@ -269,6 +352,29 @@ impl<'a> Writ<'a> {
         }
     }
 
+    /// Escape the text with the HTML escaper chosen for the writ's context.
+    fn escaper_contextual(
+        escaper: &str,
+        span: Span,
+        text: &TokenStream,
+        estimated_length: usize,
+    ) -> BuiltTokens {
+        let Ok(group) = syn::parse_str::<Path>(HTML_ESCAPER) else {
+            return token_error!(span, "Failed to build escape function call");
+        };
+        let escaper = Ident::new(escaper, span);
+
+        (
+            quote_spanned! {span=>
+                (&&::oxiplate::UnescapedTextWrapper::new(&(#text))).oxiplate_escape(
+                    oxiplate_formatter,
+                    &#group::#escaper,
+                )?
+            },
+            estimated_length,
+        )
+    }
+
     fn escaper_specified(
         state: &State,
         group: &(String, &EscaperGroup),
@@ -311,7 +417,72 @@ impl<'a> Writ<'a> {
         token_error!(span, r"Failed to build escape function call")
     }
 
-    fn escaper_raw(text: &TokenStream, estimated_length: usize) -> BuiltTokens {
+    /// Error if the HTML escaper doesn't match the context of the writ,
+    /// unless `escaper_mismatch` is set to `"allow"`.
+    fn lint_escaper_mismatch(
+        state: &State,
+        escaper: &str,
+        span: Span,
+        tokens: BuiltTokens,
+    ) -> BuiltTokens {
+        let Some(message) = state.html_context.escaper_mismatch(escaper) else {
+            return tokens;
+        };
+
+        match state.config.escaper_mismatch {
+            LintLevel::Allow => tokens,
+            LintLevel::Deny => {
+                let message = format!(
+                    "{message}. Set `escaper_mismatch = \"allow\"` in `/oxiplate.toml` if the \
+                     mismatch is intentional."
+                );
+                (quote_spanned! {span=> compile_error!(#message); }, 0)
+            }
+        }
+    }
+
+    /// Capture the output and write it back out
+    /// with each line after the first indented
+    /// to line up with the column `source` starts at,
+    /// based on the static text output before it on the same line.
+    /// Tabs are kept as-is so lines still line up regardless of tab width.
+    /// Empty lines are left empty.
+    pub(super) fn indented(
+        state: &State,
+        source: &Source,
+        (tokens, estimated_length): BuiltTokens,
+    ) -> BuiltTokens {
+        let span = source.span_token();
+        let indentation: String = state
+            .static_line
+            .chars()
+            .map(|char| if char == '\t' { '\t' } else { ' ' })
+            .collect();
+
+        (
+            quote_spanned! {span=>
+                {
+                    let mut oxiplate_string = alloc::string::String::with_capacity(#estimated_length);
+                    {
+                        let oxiplate_formatter = &mut oxiplate_string;
+                        #tokens
+                    }
+                    for (oxiplate_index, oxiplate_line) in oxiplate_string.split('\n').enumerate() {
+                        if oxiplate_index > 0 {
+                            oxiplate_formatter.write_str("\n")?;
+                            if !oxiplate_line.is_empty() {
+                                oxiplate_formatter.write_str(#indentation)?;
+                            }
+                        }
+                        oxiplate_formatter.write_str(oxiplate_line)?;
+                    }
+                }
+            },
+            estimated_length,
+        )
+    }
+
+    pub(super) fn escaper_raw(text: &TokenStream, estimated_length: usize) -> BuiltTokens {
         let span = text.span();
 
         #[cfg(not(feature = "_oxiplate"))]
@@ -332,6 +503,15 @@ impl<'a> Writ<'a> {
     }
 }
 
+impl<'a> Output<'a> {
+    fn source(&self) -> Source<'a> {
+        match self {
+            Output::Expression(expression) => expression.source(),
+            Output::Translation(translation) => translation.source().clone(),
+        }
+    }
+}
+
 impl<'a> From<Writ<'a>> for Item<'a> {
     fn from(writ: Writ<'a>) -> Self {
         Item::Writ(writ)
@@ -348,6 +528,15 @@ pub(super) fn writ<'a>(
     open_tag_source: Source<'a>,
 ) -> impl Fn(TokenSlice<'a>) -> Res<'a, (Item<'a>, Option<Item<'a>>)> {
     move |tokens| {
+        let (tokens, indent) =
+            opt((KeywordParser::new("indent"), take(TokenKind::Colon))).parse(tokens)?;
+        let indent_source = indent.as_ref().map(|(keyword, colon)| {
+            keyword
+                .source()
+                .clone()
+                .merge(colon.source(), "Colon expected after `indent`")
+        });
+
         let (tokens, escaper_info) = opt((
             opt((Identifier::parse, take(TokenKind::Period))),
             Identifier::parse,
@@ -374,8 +563,14 @@ pub(super) fn writ<'a>(
             escaper,
         });
 
-        let (tokens, output) =
-            cut("Expected an expression.", expression(true, true)).parse(tokens)?;
+        let (tokens, translation) = opt(Translation::parse).parse(tokens)?;
+        let (tokens, output) = if let Some(translation) = translation {
+            (tokens, Output::Translation(translation))
+        } else {
+            let (tokens, expression) =
+                cut("Expected an expression.", expression(true, true)).parse(tokens)?;
+            (tokens, Output::Expression(expression))
+        };
         let (tokens, (trailing_whitespace, end_tag)) = cut(
             "Expected the writ tag to be closed with `_}}`, `-}}`, or `}}`.",
             tag_end(TagKind::Writ),
@@ -384,7 +579,11 @@ pub(super) fn writ<'a>(
 
         let source = open_tag_source
             .clone()
-            .merge_some(escaper_source.as_ref(), "Escaper expected after whitespace")
+            .merge_some(
+                indent_source.as_ref(),
+                "`indent:` expected after whitespace",
+            )
+            .merge_some(escaper_source.as_ref(), "Escaper expected after `indent:`")
             .merge(&output.source(), "Expression expected after escaper")
             .merge(&end_tag, "End tag expected after whitespace");
 
@@ -392,8 +591,9 @@ pub(super) fn writ<'a>(
             tokens,
             (
                 Writ {
+                    indent: indent.is_some(),
                     escaper,
-                    expression: output,
+                    output,
                     source,
                 }
                 .into(),
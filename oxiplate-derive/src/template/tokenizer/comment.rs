@@ -1,12 +1,25 @@
 use super::Token;
+use crate::config::Syntax;
 use crate::template::tokenizer::Res;
 use crate::template::tokenizer::kind::TokenKind;
 use crate::tokenizer::BufferedSource;
 
-pub fn consume_comment<'a>(source: &mut BufferedSource<'a>) -> Res<'a> {
+pub fn consume_comment<'a>(source: &mut BufferedSource<'a>, syntax: &Syntax) -> Res<'a> {
+    while source.peek().is_some() && !is_comment_end(source, syntax) {
+        let _ = source.next();
+    }
+
     let source = source
-        .consume_until(|char| matches!(char, '#' | '-' | '_'))
+        .consume()
         .expect("Buffer should contain at least one character");
 
     (None, Ok(Token::new(TokenKind::Comment, &source, None)))
 }
+
+/// Whether the end of the comment is at the current position,
+/// with or without whitespace adjustment.
+fn is_comment_end(source: &BufferedSource, syntax: &Syntax) -> bool {
+    let mut source = source.clone();
+    source.next_if(|char| matches!(char, '-' | '_'));
+    source.peek_str(&syntax.comment.end)
+}
@@ -4,12 +4,12 @@ mod string;
 
 use super::Token;
 use crate::Source;
+use crate::config::Syntax;
 use crate::template::tokenizer::expression::char::consume_char;
 use crate::template::tokenizer::expression::number::{consume_alternative_base, consume_decimal};
 use crate::template::tokenizer::expression::string::{consume_raw_string, consume_string};
 use crate::template::tokenizer::{
-    Context, Res, TagKind, TokenKind, WhitespacePreference, consume_possible_tag_end,
-    consume_possible_tag_end_whitespace_adjustment, whitespace,
+    Context, Res, TagKind, TokenKind, consume_possible_tag_end, whitespace,
 };
 use crate::tokenizer::{BufferedSource, UnexpectedTokenError};
 
@@ -18,11 +18,26 @@ pub fn consume_expression_token<'a>(
     source: &mut BufferedSource<'a>,
     has_unclosed_char_pairs: bool,
     in_tag_kind: &TagKind,
+    syntax: &Syntax,
 ) -> Res<'a> {
-    let leading_whitespace = source
+    let mut leading_whitespace = source
         .consume_while(|char| matches!(char, whitespace!()))
         .ok();
 
+    // Tag ends within unclosed char pairs are treated as individual tokens,
+    // like the first `}` of `}}` in `{{ { value: true }}}`.
+    if !has_unclosed_char_pairs {
+        if let Some(token) = consume_possible_tag_end(
+            source,
+            &mut leading_whitespace,
+            in_tag_kind,
+            &[TagKind::Writ, TagKind::Statement, TagKind::Comment],
+            syntax,
+        ) {
+            return token;
+        }
+    }
+
     macro_rules! if_matches {
         ($char:literal => $if:ident else $else:ident) => {{
             if source.next_if(|char| char == $char) {
@@ -36,53 +51,28 @@ pub fn consume_expression_token<'a>(
     let kind = match source.next() {
         Some('"') => return consume_string(source, leading_whitespace),
         Some('#') => return consume_raw_string(source, leading_whitespace),
-        Some('\'') => return consume_char(source, leading_whitespace),
-        Some('}') => {
-            return consume_possible_tag_end(
-                source,
-                leading_whitespace,
-                TagKind::Writ,
-                in_tag_kind,
-                has_unclosed_char_pairs,
-            );
-        }
-        Some('%') => {
-            return consume_possible_tag_end(
-                source,
-                leading_whitespace,
-                TagKind::Statement,
-                in_tag_kind,
-                has_unclosed_char_pairs,
-            );
+        Some('\'') => {
+            if is_label(source) {
+                return consume_label(source, leading_whitespace);
+            }
+
+            return consume_char(source, leading_whitespace);
         }
+        Some('}') => TokenKind::CloseBrace,
+        Some('%') => TokenKind::Percent,
         Some('(') => TokenKind::OpenParenthese,
         Some(')') => TokenKind::CloseParenthese,
         Some('[') => TokenKind::OpenBracket,
         Some(']') => TokenKind::CloseBracket,
         Some('{') => TokenKind::OpenBrace,
         Some('+') => TokenKind::Plus,
-        Some('-') => {
-            return consume_possible_tag_end_whitespace_adjustment(
-                source,
-                leading_whitespace,
-                in_tag_kind,
-                has_unclosed_char_pairs,
-                WhitespacePreference::Remove,
-            );
-        }
-        Some('_') => {
-            return consume_possible_tag_end_whitespace_adjustment(
-                source,
-                leading_whitespace,
-                in_tag_kind,
-                has_unclosed_char_pairs,
-                WhitespacePreference::Replace,
-            );
-        }
+        Some('-') => TokenKind::Minus,
         Some('*') => TokenKind::Asterisk,
         Some('/') => TokenKind::ForwardSlash,
         Some('~') => TokenKind::Tilde,
         Some(',') => TokenKind::Comma,
+        Some('^') => TokenKind::Caret,
+        Some('?') => TokenKind::Question,
         Some(':') => if_matches!(':' => PathSeparator else Colon),
         Some('&') => if_matches!('&' => And else Ampersand),
         Some('!') => if_matches!('=' => NotEq else Exclamation),
@@ -97,7 +87,7 @@ pub fn consume_expression_token<'a>(
                 TokenKind::Period
             }
         }
-        Some('a'..='z' | 'A'..='Z') => return consume_ident(source, leading_whitespace),
+        Some('a'..='z' | 'A'..='Z' | '_') => return consume_ident(source, leading_whitespace),
         Some('0') => return consume_alternative_base(source, leading_whitespace),
         Some('1'..='9') => return consume_decimal(source, leading_whitespace),
         Some(_) => {
@@ -113,18 +103,17 @@ pub fn consume_expression_token<'a>(
             );
         }
         None => {
-            let message = match in_tag_kind {
-                TagKind::Writ => {
-                    "End of file encountered while parsing a writ. Expected `}}`, `-}}`, or `_}}`"
-                }
-                TagKind::Statement => {
-                    "End of file encountered while parsing a statement. Expected `%}`, `-%}`, or \
-                     `_%}`"
-                }
+            let tag = match in_tag_kind {
+                TagKind::Writ => "writ",
+                TagKind::Statement => "statement",
                 TagKind::Comment => {
                     unreachable!("Expressions should not be parsed in comments")
                 }
             };
+            let message = format!(
+                "End of file encountered while parsing a {tag}. {}",
+                syntax.delimiters(in_tag_kind).expected_end()
+            );
 
             return (
                 Some(Context::Static),
@@ -159,3 +148,30 @@ pub fn consume_ident<'a>(
 
     (None, Ok(Token::new(kind, &source, leading_whitespace)))
 }
+
+/// Whether the buffered `'` starts a label like `'outer`
+/// rather than a char literal like `'a'`.
+fn is_label(source: &BufferedSource) -> bool {
+    let mut lookahead = source.clone();
+    if !lookahead.next_if(|char| matches!(char, 'a'..='z' | 'A'..='Z' | '_')) {
+        return false;
+    }
+    lookahead.next_while(|char| matches!(char, 'a'..='z' | 'A'..='Z' | '0'..='9' | '_'));
+
+    matches!(lookahead.peek(), Some(char) if char != '\'')
+}
+
+/// Consume a label like `'outer`.
+fn consume_label<'a>(
+    source: &mut BufferedSource<'a>,
+    leading_whitespace: Option<Source<'a>>,
+) -> Res<'a> {
+    let source = source
+        .consume_while(|char| matches!(char, 'a'..='z' | 'A'..='Z' | '0'..='9' | '_'))
+        .expect("Buffer should contain at least one character");
+
+    (
+        None,
+        Ok(Token::new(TokenKind::Label, &source, leading_whitespace)),
+    )
+}
@@ -20,6 +20,8 @@ pub enum TokenKind {
     },
 
     Ident,
+    /// A loop label like `'outer`.
+    Label,
     /// `::`
     PathSeparator,
 
@@ -89,6 +91,10 @@ pub enum TokenKind {
     Period,
     /// `|`
     VerticalBar,
+    /// `^`
+    Caret,
+    /// `?`
+    Question,
     /// `:`
     Colon,
     /// `=`
@@ -119,6 +125,13 @@ pub enum WhitespacePreference {
 
     /// Rely on surrounding tags to make a decision,
     /// otherwise leave the whitespace unchanged.
-    /// Tag that suggests this: `{{ foo }}`
+    /// Tags that suggest this: `{{ foo }}` and `{%+ foo +%}`
     Indifferent,
+
+    /// Remove the indentation before the tag if it starts a line,
+    /// or the line break directly after the tag,
+    /// leaving the rest of the whitespace unchanged.
+    /// Statement and comment tags without whitespace control characters
+    /// suggest this when `lstrip_blocks` or `trim_blocks` is enabled.
+    TrimLine,
 }
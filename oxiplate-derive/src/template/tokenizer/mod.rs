@@ -3,13 +3,14 @@ mod expression;
 mod kind;
 mod r#static;
 
-use self::expression::consume_ident;
 pub use self::kind::{TagKind, TokenKind, WhitespacePreference};
 use crate::Source;
+use crate::config::Syntax;
 use crate::template::tokenizer::comment::consume_comment;
 use crate::template::tokenizer::expression::consume_expression_token;
 use crate::template::tokenizer::r#static::{
-    consume_possible_tag_start, consume_static_text, consume_static_whitespace,
+    consume_possible_tag_start, consume_static_text, consume_static_whitespace, is_raw_end,
+    is_tag_start,
 };
 pub use crate::tokenizer::Eof;
 use crate::tokenizer::{BufferedSource, UnexpectedTokenError};
@@ -38,8 +39,11 @@ macro_rules! whitespace {
 
 pub(super) use whitespace;
 
-pub fn tokens_and_eof(template: Source) -> (Vec<Result<Token, UnexpectedTokenError>>, Eof) {
-    let tokens = Tokens::new(template);
+pub fn tokens_and_eof<'a>(
+    template: Source<'a>,
+    syntax: &Syntax,
+) -> (Vec<Result<Token<'a>, UnexpectedTokenError<'a>>>, Eof<'a>) {
+    let tokens = Tokens::new(template, syntax.clone());
     let eof = tokens.source.eof();
 
     (tokens.collect(), eof)
@@ -50,72 +54,116 @@ pub struct Tokens<'a> {
     source: BufferedSource<'a>,
     context: Context,
     char_pair_stack: Vec<CharPairKind>,
+    raw_tag: RawTag,
+    syntax: Syntax,
 }
 
 impl<'a> Tokens<'a> {
-    pub fn new(template: Source<'a>) -> Self {
+    pub fn new(template: Source<'a>, syntax: Syntax) -> Self {
         Self {
             source: template.into(),
             context: Context::Static,
             char_pair_stack: vec![],
+            raw_tag: RawTag::None,
+            syntax,
         }
     }
+
+    /// Pass everything through as static text after `{% raw %}`
+    /// until the matching `{% endraw %}`.
+    fn update_raw_tag(&mut self, token: &Token<'a>) {
+        self.raw_tag = match (&self.raw_tag, token.kind()) {
+            (
+                _,
+                TokenKind::TagStart {
+                    kind: TagKind::Statement,
+                    ..
+                },
+            ) => RawTag::TagStart,
+            (RawTag::TagStart, TokenKind::Ident) if token.source().as_str() == "raw" => {
+                RawTag::Keyword
+            }
+            (
+                RawTag::Keyword,
+                TokenKind::TagEnd {
+                    kind: TagKind::Statement,
+                    ..
+                },
+            ) => {
+                self.context = Context::Raw;
+                RawTag::None
+            }
+            _ => RawTag::None,
+        };
+    }
 }
 
 impl<'a> Iterator for Tokens<'a> {
     type Item = Result<Token<'a>, UnexpectedTokenError<'a>>;
 
+    #[allow(clippy::too_many_lines)]
     fn next(&mut self) -> Option<Self::Item> {
         let (new_context, token): (Option<Context>, Self::Item) = match self.context {
-            Context::Static => match self.source.next()? {
-                '{' => consume_possible_tag_start(&mut self.source),
-                whitespace!() => consume_static_whitespace(&mut self.source),
-                _ => consume_static_text(&mut self.source),
-            },
+            Context::Static => {
+                if let Some(token) = consume_possible_tag_start(&mut self.source, &self.syntax) {
+                    token
+                } else {
+                    match self.source.next()? {
+                        whitespace!() => consume_static_whitespace(&mut self.source),
+                        _ => consume_static_text(&mut self.source, |source| {
+                            is_tag_start(source, &self.syntax)
+                        }),
+                    }
+                }
+            }
+            Context::Raw => {
+                if is_raw_end(&self.source, &self.syntax) {
+                    consume_possible_tag_start(&mut self.source, &self.syntax)
+                        .expect("`endraw` statement should have just been found")
+                } else {
+                    match self.source.next()? {
+                        whitespace!() => consume_static_whitespace(&mut self.source),
+                        _ => consume_static_text(&mut self.source, |source| {
+                            is_raw_end(source, &self.syntax)
+                        }),
+                    }
+                }
+            }
             Context::Comment => {
-                let (new_context, token) = match self.source.next() {
-                    Some('-') => consume_possible_tag_end_whitespace_adjustment(
-                        &mut self.source,
-                        None,
-                        &TagKind::Comment,
-                        !self.char_pair_stack.is_empty(),
-                        WhitespacePreference::Remove,
-                    ),
-                    Some('_') => consume_possible_tag_end_whitespace_adjustment(
-                        &mut self.source,
-                        None,
-                        &TagKind::Comment,
-                        !self.char_pair_stack.is_empty(),
-                        WhitespacePreference::Replace,
-                    ),
-                    Some('#') => consume_possible_tag_end(
-                        &mut self.source,
-                        None,
-                        TagKind::Comment,
-                        &TagKind::Comment,
-                        !self.char_pair_stack.is_empty(),
-                    ),
-                    Some(_char) => consume_comment(&mut self.source),
-                    None => (
+                if let Some(token) = consume_possible_tag_end(
+                    &mut self.source,
+                    &mut None,
+                    &TagKind::Comment,
+                    &[TagKind::Comment],
+                    &self.syntax,
+                ) {
+                    token
+                } else if self.source.next().is_some() {
+                    consume_comment(&mut self.source, &self.syntax)
+                } else {
+                    (
                         Some(Context::Static),
                         Err(UnexpectedTokenError::new(
-                            "End of file encountered while parsing a comment. Expected `#}`, \
-                             `-#}`, or `_#}`",
+                            format!(
+                                "End of file encountered while parsing a comment. {}",
+                                self.syntax.comment.expected_end()
+                            ),
                             self.source.eof().source().clone(),
                         )),
-                    ),
-                };
-                (new_context, token)
+                    )
+                }
             }
             Context::Statement => consume_expression_token(
                 &mut self.source,
                 !self.char_pair_stack.is_empty(),
                 &TagKind::Statement,
+                &self.syntax,
             ),
             Context::Writ => consume_expression_token(
                 &mut self.source,
                 !self.char_pair_stack.is_empty(),
                 &TagKind::Writ,
+                &self.syntax,
             ),
         };
 
@@ -128,6 +176,8 @@ impl<'a> Iterator for Tokens<'a> {
             err => return Some(err),
         };
 
+        self.update_raw_tag(&token);
+
         // Ensure all char pairs are matched.
         let char_pair_check = match token.kind() {
             TokenKind::OpenBrace => {
@@ -175,11 +225,24 @@ impl<'a> Iterator for Tokens<'a> {
 #[derive(Debug)]
 enum Context {
     Comment,
+    Raw,
     Statement,
     Static,
     Writ,
 }
 
+/// Progress through a possible `{% raw %}` tag.
+#[derive(Debug)]
+enum RawTag {
+    None,
+
+    /// `{%`
+    TagStart,
+
+    /// `{% raw`
+    Keyword,
+}
+
 #[derive(Debug)]
 enum CharPairKind {
     /// `{` and `}`
@@ -192,157 +255,62 @@ enum CharPairKind {
     Parenthese,
 }
 
+/// Consume the end of a tag if there is one at the current position,
+/// along with any `-`, `_`, or `+` before it for adjusting whitespace.
+/// Ends of the other kinds of tags in `possible_kinds` result in an error.
 fn consume_possible_tag_end<'a>(
     source: &mut BufferedSource<'a>,
-    leading_whitespace: Option<Source<'a>>,
-    tag_end_kind: TagKind,
+    leading_whitespace: &mut Option<Source<'a>>,
     in_tag_kind: &TagKind,
-    has_unclosed_char_pairs: bool,
-) -> Res<'a> {
-    if has_unclosed_char_pairs || source.peek() != Some('}') {
-        let source = source
-            .consume()
-            .expect("Buffer should contain `}`, `%`, or `#`");
-
-        let kind = match tag_end_kind {
-            TagKind::Writ => TokenKind::CloseBrace,
-            TagKind::Statement => TokenKind::Percent,
-            TagKind::Comment => TokenKind::Comment,
-        };
-
-        return (None, Ok(Token::new(kind, &source, leading_whitespace)));
-    }
-
-    let _ = source.next();
-    let source = source
-        .consume()
-        .expect("Buffer should contain `}}`, `%}`, or `#}`");
-
-    if tag_end_kind == *in_tag_kind {
-        // Ending current tag
-        (
-            Some(Context::Static),
-            Ok(Token::new(
-                TokenKind::TagEnd {
-                    kind: tag_end_kind,
-                    whitespace_preference: WhitespacePreference::Indifferent,
-                },
-                &source,
-                leading_whitespace,
-            )),
-        )
+    possible_kinds: &[TagKind],
+    syntax: &Syntax,
+) -> Option<Res<'a>> {
+    let mut lookahead = source.clone();
+    let whitespace_preference = if lookahead.next_if(|char| char == '-') {
+        Some(WhitespacePreference::Remove)
+    } else if lookahead.next_if(|char| char == '_') {
+        Some(WhitespacePreference::Replace)
+    } else if lookahead.next_if(|char| char == '+') {
+        Some(WhitespacePreference::Indifferent)
     } else {
-        // Ending wrong tag
-        let message = match in_tag_kind {
-            TagKind::Writ => "Expected `}}`, `-}}`, or `_}}`",
-            TagKind::Statement => "Expected `%}`, `-%}`, or `_%}`",
-            TagKind::Comment => {
-                unreachable!("Comment should treat anything other than `#}}` as comment text")
-            }
-        };
-        (
-            None,
-            Err(UnexpectedTokenError::new(
-                message,
-                source.append_to_leading_whitespace(
-                    leading_whitespace,
-                    "Tag end should follow whitespace",
-                ),
-            )),
-        )
-    }
-}
-
-fn consume_possible_tag_end_whitespace_adjustment<'a>(
-    source: &mut BufferedSource<'a>,
-    leading_whitespace: Option<Source<'a>>,
-    in_tag_kind: &TagKind,
-    has_unclosed_char_pairs: bool,
-    whitespace_preference: WhitespacePreference,
-) -> Res<'a> {
-    let tag_end_kind = match source.peek_2() {
-        Some(['}', '}']) => Some(TagKind::Writ),
-        Some(['%', '}']) => Some(TagKind::Statement),
-        Some(['#', '}']) => Some(TagKind::Comment),
-        _ => None,
+        None
     };
 
-    // Handle `-`, `_`, and non-comment close tags in comments.
-    if let TagKind::Comment = in_tag_kind {
-        if let None | Some(TagKind::Statement | TagKind::Writ) = tag_end_kind {
-            return (
-                None,
-                Ok(Token::new(
-                    TokenKind::Comment,
-                    &source.consume().expect("Buffer should contain `-` or `_`"),
-                    leading_whitespace,
-                )),
-            );
-        }
-    }
-
-    if has_unclosed_char_pairs || tag_end_kind.is_none() {
-        #[allow(clippy::enum_glob_use)]
-        use TagKind::*;
-        #[allow(clippy::enum_glob_use)]
-        use WhitespacePreference::*;
-        let kind = match (whitespace_preference, in_tag_kind) {
-            (Indifferent, _) => unreachable!(
-                "Tag end parser for whitespace adjustment should never be called when indifferent \
-                 about whitespace"
-            ),
-            (Remove, Writ | Statement) => TokenKind::Minus,
-            (Replace, Writ | Statement) => {
-                return consume_ident(source, leading_whitespace);
-            }
-            (Remove | Replace, Comment) => TokenKind::Comment,
-        };
-
-        let source = source.consume().expect("Buffer should contain `-` or `_`");
-
-        return (None, Ok(Token::new(kind, &source, leading_whitespace)));
-    }
+    let tag_end_kind = possible_kinds
+        .iter()
+        .find(|kind| lookahead.next_if_str(&syntax.delimiters(kind).end))?;
+    let whitespace_preference =
+        whitespace_preference.unwrap_or_else(|| syntax.default_end_preference(tag_end_kind));
 
-    let _ = source.next();
-    let _ = source.next();
+    *source = lookahead;
+    let source = source.consume().expect("Buffer should contain a tag end");
+    let leading_whitespace = leading_whitespace.take();
 
-    let tag_end_kind = tag_end_kind.expect("`None` should have just been checked for");
-    let source = source
-        .consume()
-        .expect("Buffer should contain `_}}`, `_%}`, `_#}`, `-}}`, `-%}`, or `-#}`");
-
-    if tag_end_kind == *in_tag_kind {
+    if tag_end_kind == in_tag_kind {
         // Ending current tag
-        (
+        Some((
             Some(Context::Static),
             Ok(Token::new(
                 TokenKind::TagEnd {
-                    kind: tag_end_kind,
+                    kind: tag_end_kind.clone(),
                     whitespace_preference,
                 },
                 &source,
                 leading_whitespace,
             )),
-        )
+        ))
     } else {
         // Ending wrong tag
-        let message = match in_tag_kind {
-            TagKind::Writ => "Expected `}}`, `-}}`, or `_}}`",
-            TagKind::Statement => "Expected `%}`, `-%}`, or `_%}`",
-            TagKind::Comment => {
-                unreachable!("Comment should treat anything other than `#}}` as comment text")
-            }
-        };
-        (
+        Some((
             None,
             Err(UnexpectedTokenError::new(
-                message,
+                syntax.delimiters(in_tag_kind).expected_end(),
                 source.append_to_leading_whitespace(
                     leading_whitespace,
-                    "Tag end with whitespace adjustment should follow whitespace",
+                    "Tag end should follow whitespace",
                 ),
             )),
-        )
+        ))
     }
 }
 
@@ -356,11 +324,10 @@ fn test() {
     let span = Span::mixed_site();
     let string = "a {# whoa #} \n\thello \t\n{{ name }} b";
     assert_eq!(
-        Tokens::new(Source::new(&SourceOwned::new(
-            &LitStr::new(string, span),
-            span,
-            None
-        )))
+        Tokens::new(
+            Source::new(&SourceOwned::new(&LitStr::new(string, span), span, None)),
+            Syntax::default()
+        )
         .into_iter()
         .map(|token| match token {
             Ok(token) => format!("{:?}", token),
@@ -1,3 +1,4 @@
+use crate::config::Syntax;
 use crate::template::tokenizer::kind::WhitespacePreference;
 use crate::template::tokenizer::{Context, Res, TagKind, TokenKind, whitespace};
 use crate::tokenizer::{BufferedSource, Token};
@@ -13,65 +14,94 @@ pub fn consume_static_whitespace<'a>(source: &mut BufferedSource<'a>) -> Res<'a>
     )
 }
 
-#[allow(clippy::unnested_or_patterns)]
-pub fn consume_static_text<'a>(source: &mut BufferedSource<'a>) -> Res<'a> {
+/// Consume static text up until whitespace
+/// or wherever `is_tag_start` finds the start of a tag.
+pub fn consume_static_text<'a>(
+    source: &mut BufferedSource<'a>,
+    is_tag_start: impl Fn(&BufferedSource) -> bool,
+) -> Res<'a> {
+    while let Some(char) = source.peek() {
+        if matches!(char, whitespace!()) || is_tag_start(source) {
+            break;
+        }
+
+        let _ = source.next();
+    }
+
     let source = source
-        .consume_until(|char| matches!(char, '{' | whitespace!()))
+        .consume()
         .expect("Buffer should contain at least one character");
 
     (None, Ok(Token::new(TokenKind::StaticText, &source, None)))
 }
 
-pub fn consume_possible_tag_start<'a>(source: &mut BufferedSource<'a>) -> Res<'a> {
-    let (new_context, kind) = match source.peek() {
-        Some('{') => {
-            let _ = source.next();
-            (Some(Context::Writ), TagKind::Writ)
-        }
-        Some('%') => {
-            let _ = source.next();
-            (Some(Context::Statement), TagKind::Statement)
-        }
-        Some('#') => {
-            let _ = source.next();
-            (Some(Context::Comment), TagKind::Comment)
-        }
-        _ => {
-            let (kind, source) = match source.peek_2() {
-                Some(['-', '}']) => {
-                    let _ = source.next();
-                    let _ = source.next();
-
-                    let source = source.consume().expect("Buffer should contain `{-}`");
-
-                    (
-                        TokenKind::WhitespaceAdjustmentTag {
-                            whitespace_preference: WhitespacePreference::Remove,
-                        },
-                        source,
-                    )
-                }
-                Some(['_', '}']) => {
-                    let _ = source.next();
-                    let _ = source.next();
-
-                    let source = source.consume().expect("Buffer should contain `{_}`");
-                    (
-                        TokenKind::WhitespaceAdjustmentTag {
-                            whitespace_preference: WhitespacePreference::Replace,
-                        },
-                        source,
-                    )
-                }
-                _ => {
-                    let source = source
-                        .consume()
-                        .expect("Buffer should contain at least `{`");
-                    (TokenKind::StaticText, source)
-                }
-            };
-            return (None, Ok(Token::new(kind, &source, None)));
-        }
+/// Whether a tag starts at the current position.
+pub fn is_tag_start(source: &BufferedSource, syntax: &Syntax) -> bool {
+    [&syntax.writ, &syntax.statement, &syntax.comment]
+        .iter()
+        .any(|delimiters| source.peek_str(&delimiters.start))
+        || next_if_whitespace_adjustment_tag(&mut source.clone(), syntax).is_some()
+}
+
+/// Consume `{-}` or `{_}` if present,
+/// or the equivalent for the configured writ delimiters.
+fn next_if_whitespace_adjustment_tag(
+    source: &mut BufferedSource,
+    syntax: &Syntax,
+) -> Option<WhitespacePreference> {
+    let (start, end) = syntax.whitespace_adjustment_tag_chars();
+
+    let mut lookahead = source.clone();
+    if lookahead.next() != Some(start) {
+        return None;
+    }
+
+    let whitespace_preference = match lookahead.next() {
+        Some('-') => WhitespacePreference::Remove,
+        Some('_') => WhitespacePreference::Replace,
+        _ => return None,
+    };
+
+    if lookahead.next() != Some(end) {
+        return None;
+    }
+
+    *source = lookahead;
+
+    Some(whitespace_preference)
+}
+
+/// Consume the start of a tag if there is one at the current position.
+pub fn consume_possible_tag_start<'a>(
+    source: &mut BufferedSource<'a>,
+    syntax: &Syntax,
+) -> Option<Res<'a>> {
+    let kind = [TagKind::Writ, TagKind::Statement, TagKind::Comment]
+        .into_iter()
+        .find(|kind| source.next_if_str(&syntax.delimiters(kind).start));
+
+    let Some(kind) = kind else {
+        let whitespace_preference = next_if_whitespace_adjustment_tag(source, syntax)?;
+        let source = source
+            .consume()
+            .expect("Buffer should contain a whitespace adjustment tag");
+
+        return Some((
+            None,
+            Ok(Token::new(
+                TokenKind::WhitespaceAdjustmentTag {
+                    whitespace_preference,
+                },
+                &source,
+                None,
+            )),
+        ));
+    };
+
+    let new_context = match kind {
+        TagKind::Writ => Context::Writ,
+        TagKind::Statement => Context::Statement,
+        TagKind::Comment => Context::Comment,
     };
 
     let whitespace_preference = match source.peek() {
@@ -83,15 +113,17 @@ pub fn consume_possible_tag_start<'a>(source: &mut BufferedSource<'a>) -> Res<'a
             let _ = source.next();
             WhitespacePreference::Replace
         }
-        _ => WhitespacePreference::Indifferent,
+        Some('+') => {
+            let _ = source.next();
+            WhitespacePreference::Indifferent
+        }
+        _ => syntax.default_start_preference(&kind),
     };
 
-    let source = source
-        .consume()
-        .expect("Buffer should contain at least `{`");
+    let source = source.consume().expect("Buffer should contain a tag start");
 
-    (
-        new_context,
+    Some((
+        Some(new_context),
         Ok(Token::new(
             TokenKind::TagStart {
                 kind,
@@ -100,5 +132,25 @@ pub fn consume_possible_tag_start<'a>(source: &mut BufferedSource<'a>) -> Res<'a
             &source,
             None,
         )),
-    )
+    ))
+}
+
+/// Whether the `endraw` statement starts at the current position.
+pub fn is_raw_end(source: &BufferedSource, syntax: &Syntax) -> bool {
+    let mut source = source.clone();
+    if !source.next_if_str(&syntax.statement.start) {
+        return false;
+    }
+
+    source.next_if(|char| matches!(char, '-' | '_' | '+'));
+    source.next_while(|char| matches!(char, whitespace!()));
+
+    if !source.next_if_str("endraw") {
+        return false;
+    }
+
+    source
+        .peek()
+        .is_some_and(|char| matches!(char, '-' | '_' | '+') || matches!(char, whitespace!()))
+        || source.peek_str(&syntax.statement.end)
 }
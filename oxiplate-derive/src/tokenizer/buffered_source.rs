@@ -72,6 +72,24 @@ impl<'a> BufferedSource<'a> {
         ])
     }
 
+    /// Whether the unbuffered source starts with the provided string.
+    #[must_use]
+    pub fn peek_str(&self, string: &str) -> bool {
+        let start = self.range.start + self.buffer_length;
+        self.original.code[start..self.range.end].starts_with(string)
+    }
+
+    /// Add the provided string to the buffer
+    /// if the unbuffered source starts with it.
+    pub fn next_if_str(&mut self, string: &str) -> bool {
+        if self.peek_str(string) {
+            self.buffer_length += string.len();
+            true
+        } else {
+            false
+        }
+    }
+
     #[must_use]
     pub fn next(&mut self) -> Option<char> {
         let char = self.peek();
@@ -135,13 +153,6 @@ impl<'a> BufferedSource<'a> {
         matched_count
     }
 
-    #[must_use]
-    pub fn consume_until(&mut self, matcher: fn(char) -> bool) -> Result<Source<'a>, ParseError> {
-        self.next_until(matcher);
-
-        self.consume()
-    }
-
     #[must_use]
     pub fn consume_while(&mut self, matcher: fn(char) -> bool) -> Result<Source<'a>, ParseError> {
         self.next_while(matcher);
@@ -2,6 +2,7 @@ mod buffered_source;
 mod slice;
 mod token;
 
+use std::borrow::Cow;
 use std::fmt::Debug;
 
 pub use self::buffered_source::BufferedSource;
@@ -30,15 +31,15 @@ impl<'a> Eof<'a> {
 
 #[derive(Clone, Debug)]
 pub struct UnexpectedTokenError<'a> {
-    message: &'a str,
+    message: Cow<'a, str>,
     source: Source<'a>,
     is_eof: bool,
 }
 
 impl<'a> UnexpectedTokenError<'a> {
-    pub fn new(message: &'a str, source: Source<'a>) -> Self {
+    pub fn new(message: impl Into<Cow<'a, str>>, source: Source<'a>) -> Self {
         Self {
-            message,
+            message: message.into(),
             source,
             is_eof: false,
         }
@@ -46,14 +47,14 @@ impl<'a> UnexpectedTokenError<'a> {
 
     pub fn eof(source: Source<'a>) -> Self {
         Self {
-            message: "End of file encountered",
+            message: "End of file encountered".into(),
             source,
             is_eof: true,
         }
     }
 
-    pub fn message(&self) -> &'a str {
-        self.message
+    pub fn message(&self) -> &str {
+        &self.message
     }
 
     pub fn source(&self) -> &Source<'a> {
@@ -0,0 +1,74 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{% for name in [first, second, \"Ginny\"] %}{{ name }} {% endfor %}")]
+struct Loop {
+    first: &'static str,
+    second: &'static str,
+}
+
+#[test]
+fn r#loop() {
+    assert_eq!(
+        format!(
+            "{}",
+            Loop {
+                first: "Luna",
+                second: "Hermione",
+            }
+        ),
+        "Luna Hermione Ginny "
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    "
+{%- let numbers = [
+    1,
+    2,
+    3,
+] -%}
+{{ numbers.len() }} {{ numbers[1] }} {{ [19, 89][index] }}"
+)]
+struct TrailingComma {
+    index: usize,
+}
+
+#[test]
+fn trailing_comma() {
+    assert_eq!(format!("{}", TrailingComma { index: 1 }), "3 2 89");
+}
+
+mod filters_for_oxiplate {
+    pub fn sum<const N: usize>(numbers: [usize; N]) -> usize {
+        numbers.iter().sum()
+    }
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{{ [] | sum }}")]
+struct Empty;
+
+#[test]
+fn empty() {
+    assert_eq!(format!("{}", Empty), "0");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{{ [a, b, a + b] | sum }}")]
+struct FilterArgument {
+    a: usize,
+    b: usize,
+}
+
+#[test]
+fn filter_argument() {
+    assert_eq!(format!("{}", FilterArgument { a: 10, b: 9 }), "38");
+}
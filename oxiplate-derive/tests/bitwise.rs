@@ -0,0 +1,68 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::vec::Vec;
+
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    "{-}
+{{ a }} & {{ b }} = {{ a & b }}
+{{ a }} | {{ b }} = {{ a | (b) }}
+{{ a }} | 0b100 = {{ a | 0b100 }}
+{{ a }} ^ {{ b }} = {{ a ^ b }}
+{{ a }} << 2 = {{ a << 2 }}
+{{ a }} >> 1 = {{ a >> 1 }}
+{{ a }} & {{ b }} == {{ b }} = {{ a & b == b }}"
+)]
+struct Bitwise {
+    a: u8,
+    b: u8,
+}
+
+#[test]
+fn bitwise() {
+    assert_eq!(
+        format!(
+            "{}",
+            Bitwise {
+                a: 0b1011,
+                b: 0b0110
+            }
+        ),
+        "11 & 6 = 2
+11 | 6 = 15
+11 | 0b100 = 15
+11 ^ 6 = 13
+11 << 2 = 44
+11 >> 1 = 5
+11 & 6 == 6 = false"
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    "{{ (flags >> 1) & 1 }} {{ nested.iter().map(|items: &Vec<Vec<u8>>| \
+     items.len()).max().unwrap_or(0) }}"
+)]
+struct Generics {
+    flags: u8,
+    nested: [Vec<Vec<u8>>; 2],
+}
+
+#[test]
+fn generics() {
+    assert_eq!(
+        format!(
+            "{}",
+            Generics {
+                flags: 0b10,
+                nested: [alloc::vec![alloc::vec![1]], alloc::vec![]],
+            }
+        ),
+        "1 1"
+    );
+}
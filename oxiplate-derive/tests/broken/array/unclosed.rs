@@ -0,0 +1,18 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{% for name in [first, second %}{{ name }}{% endfor %}")]
+struct Data {
+    first: &'static str,
+    second: &'static str,
+}
+
+fn main() {
+    print!(
+        "{}",
+        Data {
+            first: "Luna",
+            second: "Hermione",
+        }
+    );
+}
@@ -0,0 +1,11 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{% let is_large = |number| %}{{ numbers.iter().filter(is_large).count() }}")]
+struct Data {
+    numbers: Vec<usize>,
+}
+
+fn main() {
+    print!("{}", Data { numbers: vec![] });
+}
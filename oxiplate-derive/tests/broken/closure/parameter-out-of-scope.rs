@@ -0,0 +1,11 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{{ numbers.iter().filter(|number| *number > &3).count() }} {{ number }}")]
+struct Data {
+    numbers: Vec<usize>,
+}
+
+fn main() {
+    print!("{}", Data { numbers: vec![] });
+}
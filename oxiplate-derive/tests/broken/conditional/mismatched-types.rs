@@ -0,0 +1,18 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(r#"{{ if is_current { "active" } else if is_parent { 19 } else { "" } }}"#)]
+struct Data {
+    is_current: bool,
+    is_parent: bool,
+}
+
+fn main() {
+    print!(
+        "{}",
+        Data {
+            is_current: true,
+            is_parent: false,
+        }
+    );
+}
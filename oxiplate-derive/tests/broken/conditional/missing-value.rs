@@ -0,0 +1,11 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(r#"{{ if is_current { "active" } else "" }}"#)]
+struct Data {
+    is_current: bool,
+}
+
+fn main() {
+    print!("{}", Data { is_current: true });
+}
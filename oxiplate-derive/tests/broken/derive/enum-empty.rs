@@ -0,0 +1,6 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+enum Data {}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+enum Data {
+    #[oxiplate_inline("foo")]
+    Foo,
+    Bar,
+}
+
+fn main() {
+    print!("{}", Data::Foo);
+}
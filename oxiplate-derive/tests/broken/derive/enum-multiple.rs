@@ -0,0 +1,10 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+enum Data {
+    #[oxiplate_inline("foo")]
+    #[oxiplate_inline("bar")]
+    Foo,
+}
+
+fn main() {}
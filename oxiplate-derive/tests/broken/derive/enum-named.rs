@@ -0,0 +1,9 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+enum Data {
+    #[oxiplate_inline(name = "foo", "foo")]
+    Foo,
+}
+
+fn main() {}
@@ -8,5 +8,5 @@ enum Data {
 }
 
 fn main() {
-    print!("{}", Data);
+    print!("{}", Data::Foo);
 }
@@ -0,0 +1,12 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{{ value }}")]
+#[oxiplate_error(::core::fmt::Error)]
+struct Data {
+    value: usize,
+}
+
+fn main() {
+    print!("{}", Data { value: 19 });
+}
@@ -0,0 +1,8 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("hello")]
+#[oxiplate_inline("world")]
+struct Data;
+
+fn main() {}
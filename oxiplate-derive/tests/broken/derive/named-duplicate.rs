@@ -0,0 +1,8 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(name = "text", "hello")]
+#[oxiplate_inline(name = "text", "world")]
+struct Data;
+
+fn main() {}
@@ -0,0 +1,7 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate(name = "not valid", path = "external.html.oxip")]
+struct Data;
+
+fn main() {}
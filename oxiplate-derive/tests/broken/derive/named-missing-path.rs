@@ -0,0 +1,7 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate(name = "html", template = "external.html.oxip")]
+struct Data;
+
+fn main() {}
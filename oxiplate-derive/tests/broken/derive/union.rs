@@ -0,0 +1,12 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("hello")]
+union Data {
+    foo: u8,
+    bar: u16,
+}
+
+fn main() {
+    print!("{}", Data { foo: 1 });
+}
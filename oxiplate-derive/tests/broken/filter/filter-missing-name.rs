@@ -0,0 +1,9 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{% filter %}Hello world!{% endfilter %}")]
+struct Data;
+
+fn main() {
+    print!("{}", Data);
+}
@@ -0,0 +1,15 @@
+use oxiplate_derive::Oxiplate;
+
+mod filters_for_oxiplate {
+    pub fn upper(value: &str) -> String {
+        value.to_uppercase()
+    }
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{% filter upper %}Hello world!{% endfilter %}")]
+struct Data;
+
+fn main() {
+    print!("{}", Data);
+}
@@ -0,0 +1,11 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{% for 'rows: row in &rows %}{{ row }}{% break 'columns %}{% endfor %}")]
+struct Data {
+    rows: Vec<usize>,
+}
+
+fn main() {
+    print!("{}", Data { rows: vec![19, 89] });
+}
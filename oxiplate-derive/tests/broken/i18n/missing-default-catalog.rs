@@ -0,0 +1,19 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(r#"{{ t("greeting", name = name) }}"#)]
+#[oxiplate_locale(locale, default = "de")]
+struct Data {
+    locale: &'static str,
+    name: &'static str,
+}
+
+fn main() {
+    print!(
+        "{}",
+        Data {
+            locale: "fr",
+            name: "Marie",
+        }
+    );
+}
@@ -0,0 +1,9 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{% trans %}Hello{% endtrans %}")]
+struct Data;
+
+fn main() {
+    print!("{}", Data);
+}
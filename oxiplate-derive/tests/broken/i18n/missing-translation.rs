@@ -0,0 +1,12 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(r#"{{ t("farewell") }}"#)]
+#[oxiplate_locale(locale, default = "en")]
+struct Data {
+    locale: &'static str,
+}
+
+fn main() {
+    print!("{}", Data { locale: "fr" });
+}
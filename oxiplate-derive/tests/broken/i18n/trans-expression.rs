@@ -0,0 +1,23 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{% trans %}Hello, {{ user.name }}!{% endtrans %}")]
+#[oxiplate_locale(locale, default = "en")]
+struct Data {
+    locale: &'static str,
+    user: User,
+}
+
+struct User {
+    name: &'static str,
+}
+
+fn main() {
+    print!(
+        "{}",
+        Data {
+            locale: "fr",
+            user: User { name: "Marie" },
+        }
+    );
+}
@@ -0,0 +1,12 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{% trans %}Hello")]
+#[oxiplate_locale(locale, default = "en")]
+struct Data {
+    locale: &'static str,
+}
+
+fn main() {
+    print!("{}", Data { locale: "fr" });
+}
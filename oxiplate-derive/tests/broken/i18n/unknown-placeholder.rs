@@ -0,0 +1,19 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(r#"{{ t("greeting", user = name) }}"#)]
+#[oxiplate_locale(locale, default = "en")]
+struct Data {
+    locale: &'static str,
+    name: &'static str,
+}
+
+fn main() {
+    print!(
+        "{}",
+        Data {
+            locale: "fr",
+            name: "Marie",
+        }
+    );
+}
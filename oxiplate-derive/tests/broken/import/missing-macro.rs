@@ -0,0 +1,9 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(r#"{% from "macros.html.oxip" import greeting, missing %}"#)]
+struct Data;
+
+fn main() {
+    print!("{}", Data);
+}
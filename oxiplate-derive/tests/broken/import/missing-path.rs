@@ -0,0 +1,9 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(r#"{% import "missing.html.oxip" as ui %}"#)]
+struct Data;
+
+fn main() {
+    print!("{}", Data);
+}
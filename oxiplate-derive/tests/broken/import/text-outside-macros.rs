@@ -0,0 +1,9 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(r#"{% import "macros-with-text.html.oxip" as ui %}{{ ui.greeting() }}"#)]
+struct Data;
+
+fn main() {
+    print!("{}", Data);
+}
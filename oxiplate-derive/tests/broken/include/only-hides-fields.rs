@@ -0,0 +1,20 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(r#"{% include "card.html.oxip" with { title, url } only %}"#)]
+struct Data {
+    title: &'static str,
+    url: &'static str,
+    site_name: &'static str,
+}
+
+fn main() {
+    print!(
+        "{}",
+        Data {
+            title: "Hello",
+            url: "/hello",
+            site_name: "Oxiplate",
+        }
+    );
+}
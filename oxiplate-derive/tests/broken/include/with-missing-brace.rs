@@ -0,0 +1,11 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(r#"{% include "card.html.oxip" with title %}"#)]
+struct Data {
+    title: &'static str,
+}
+
+fn main() {
+    print!("{}", Data { title: "Hello" });
+}
@@ -0,0 +1,9 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(r#"{% include "include-count.html.oxip" with { count: "19" } only %}"#)]
+struct Data;
+
+fn main() {
+    print!("{}", Data);
+}
@@ -0,0 +1,9 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{% let (a, b) %}Hello world!{% endlet %}")]
+struct Data;
+
+fn main() {
+    print!("{}", Data);
+}
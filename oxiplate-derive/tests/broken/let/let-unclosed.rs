@@ -0,0 +1,9 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{% let title %}Hello world!")]
+struct Data;
+
+fn main() {
+    print!("{}", Data);
+}
@@ -0,0 +1,12 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    "{% macro link(label, href) %}<a href=\"{{ href }}\">{{ label }}</a>{% endmacro %}{{ \
+     link(\"Home\") }}"
+)]
+struct Data;
+
+fn main() {
+    print!("{}", Data);
+}
@@ -0,0 +1,9 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{% macro greeting %}Hello world!{% endmacro %}")]
+struct Data;
+
+fn main() {
+    print!("{}", Data);
+}
@@ -0,0 +1,21 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    "{% macro repeat(value: &str, count: usize) %}{% for _ in 0..count %}{{ value }}{% endfor \
+     %}{% endmacro %}{{ repeat(count, value) }}"
+)]
+struct Data {
+    value: &'static str,
+    count: usize,
+}
+
+fn main() {
+    print!(
+        "{}",
+        Data {
+            value: "Hello world!",
+            count: 3,
+        }
+    );
+}
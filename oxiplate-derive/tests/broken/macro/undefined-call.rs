@@ -0,0 +1,9 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{% call greeting() %}")]
+struct Data;
+
+fn main() {
+    print!("{}", Data);
+}
@@ -0,0 +1,11 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{{ count? }}")]
+struct Data {
+    count: u8,
+}
+
+fn main() {
+    print!("{}", Data { count: 19 });
+}
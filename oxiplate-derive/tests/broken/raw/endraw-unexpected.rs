@@ -0,0 +1,11 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{{ name }}{% endraw %}")]
+struct Data {
+    name: &'static str,
+}
+
+fn main() {
+    print!("{}", Data { name: "Sam" });
+}
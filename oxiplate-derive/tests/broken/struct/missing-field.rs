@@ -0,0 +1,16 @@
+use oxiplate_derive::Oxiplate;
+
+struct Link {
+    href: &'static str,
+    text: &'static str,
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{% let link = Link { href } %}{{ link.text }}")]
+struct Data {
+    href: &'static str,
+}
+
+fn main() {
+    print!("{}", Data { href: "/" });
+}
@@ -0,0 +1,13 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_syntax(writ = ["<<", ">>"])]
+#[oxiplate_syntax(writ = ["[[", "]]"])]
+#[oxiplate_inline("<< name >>")]
+struct Data {
+    name: &'static str,
+}
+
+fn main() {
+    print!("{}", Data { name: "world" });
+}
@@ -0,0 +1,19 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_syntax(statement = ["<%", "-%>"])]
+#[oxiplate_inline("<% if show -%>{{ name }}<% endif -%>")]
+struct Data {
+    name: &'static str,
+    show: bool,
+}
+
+fn main() {
+    print!(
+        "{}",
+        Data {
+            name: "world",
+            show: true,
+        }
+    );
+}
@@ -0,0 +1,10 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_syntax(comment = ["<#", "#>"])]
+#[oxiplate_inline("<# comment #}")]
+struct Data;
+
+fn main() {
+    print!("{}", Data);
+}
@@ -0,0 +1,10 @@
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_syntax(block = ["<%", "%>"])]
+#[oxiplate_inline("<% if true %>hello<% endif %>")]
+struct Data;
+
+fn main() {
+    print!("{}", Data);
+}
@@ -0,0 +1,64 @@
+#![no_std]
+// Casts are what's being tested, so the pedantic cast lints don't apply.
+#![allow(
+    clippy::cast_lossless,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+
+extern crate alloc;
+
+use alloc::format;
+
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{{ bytes as f64 / 1024. }} KiB")]
+struct Kibibytes {
+    bytes: u32,
+}
+
+#[test]
+fn kibibytes() {
+    assert_eq!(format!("{}", Kibibytes { bytes: 1536 }), "1.5 KiB");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{{ a as u16 + b as u16 }} {{ -value as u8 }} {{ (value as i32).abs() }}")]
+struct Calc {
+    a: u8,
+    b: u8,
+    value: i8,
+}
+
+#[test]
+fn calc() {
+    assert_eq!(
+        format!(
+            "{}",
+            Calc {
+                a: 200,
+                b: 100,
+                value: -19,
+            }
+        ),
+        "300 19 19"
+    );
+}
+
+mod filters_for_oxiplate {
+    pub fn len(value: &str) -> usize {
+        value.len()
+    }
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{{ (name | len) as f32 / 2. }} {{ name | len as f32 }}")]
+struct Filter {
+    name: &'static str,
+}
+
+#[test]
+fn filter() {
+    assert_eq!(format!("{}", Filter { name: "Luna" }), "2 4");
+}
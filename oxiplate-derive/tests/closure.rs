@@ -0,0 +1,140 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::vec::Vec;
+
+use oxiplate_derive::Oxiplate;
+
+mod filters_for_oxiplate {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
+    pub fn sort_by_key<T: Copy, K: Ord>(items: &[T], key: impl FnMut(&T) -> K) -> Vec<T> {
+        let mut items = items.to_vec();
+        items.sort_by_key(key);
+        items
+    }
+}
+
+struct Item {
+    name: &'static str,
+    visible: bool,
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    "{% for item in items.iter().filter(|item| item.visible) %}{{ item.name }} {% endfor %}"
+)]
+struct Filter {
+    items: Vec<Item>,
+}
+
+#[test]
+fn filter() {
+    assert_eq!(
+        format!(
+            "{}",
+            Filter {
+                items: alloc::vec![
+                    Item {
+                        name: "Luna",
+                        visible: true,
+                    },
+                    Item {
+                        name: "Ginny",
+                        visible: false,
+                    },
+                    Item {
+                        name: "Neville",
+                        visible: true,
+                    },
+                ],
+            }
+        ),
+        "Luna Neville "
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    "{{ numbers.iter().fold(0, |total, number| total + number * multiplier) }} {{ \
+     numbers.iter().enumerate().map(|(index, number)| index * number).max().unwrap_or(0) }} {{ \
+     numbers.iter().any(|number| number % 2 == 0) }}"
+)]
+struct Parameters {
+    numbers: Vec<usize>,
+    multiplier: usize,
+}
+
+#[test]
+fn parameters() {
+    assert_eq!(
+        format!(
+            "{}",
+            Parameters {
+                numbers: alloc::vec![1, 2, 3],
+                multiplier: 2,
+            }
+        ),
+        "12 6 true"
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    "{% for number in numbers.as_slice() | sort_by_key(|number: &isize| number.abs()) %}{{ number \
+     }} {% endfor %}{{ fallback.unwrap_or_else(|| numbers.len()) }}"
+)]
+struct FilterArgument {
+    numbers: Vec<isize>,
+    fallback: Option<usize>,
+}
+
+#[test]
+fn filter_argument() {
+    assert_eq!(
+        format!(
+            "{}",
+            FilterArgument {
+                numbers: alloc::vec![-3, 1, -2],
+                fallback: None,
+            }
+        ),
+        "1 -2 -3 3"
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{{ names.iter().filter(|name| name.len() > 4).count() }} {{ name }}")]
+struct Scope {
+    names: Vec<&'static str>,
+    name: &'static str,
+}
+
+#[test]
+fn scope() {
+    assert_eq!(
+        format!(
+            "{}",
+            Scope {
+                names: alloc::vec!["Luna", "Ginny", "Neville"],
+                name: "Hermione",
+            }
+        ),
+        "2 Hermione"
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{% let scale = |number: usize| number * factor %}{{ scale(2) }} {{ scale(5) }}")]
+struct Let {
+    factor: usize,
+}
+
+#[test]
+fn r#let() {
+    assert_eq!(format!("{}", Let { factor: 3 }), "6 15");
+}
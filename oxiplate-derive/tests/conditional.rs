@@ -0,0 +1,115 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+
+use oxiplate_derive::Oxiplate;
+
+mod filters_for_oxiplate {
+    extern crate alloc;
+
+    use alloc::string::{String, ToString as _};
+    use core::fmt::Display;
+
+    pub fn shout(expression: impl Display) -> String {
+        expression.to_string() + "!"
+    }
+
+    pub fn whisper(expression: impl Display) -> String {
+        expression.to_string().to_lowercase()
+    }
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(r#"<a class="{{ if is_current { "active" } else { "" } }}">Home</a>"#)]
+struct Class {
+    is_current: bool,
+}
+
+#[test]
+fn class() {
+    assert_eq!(
+        format!("{}", Class { is_current: true }),
+        r#"<a class="active">Home</a>"#
+    );
+    assert_eq!(
+        format!("{}", Class { is_current: false }),
+        r#"<a class="">Home</a>"#
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    "{{ if count == 0 { \"none\" } else if count == 1 { \"one\" } else if count < 5 { \"a few\" } \
+     else { \"many\" } }}"
+)]
+struct ElseIf {
+    count: usize,
+}
+
+#[test]
+fn else_if() {
+    assert_eq!(format!("{}", ElseIf { count: 0 }), "none");
+    assert_eq!(format!("{}", ElseIf { count: 1 }), "one");
+    assert_eq!(format!("{}", ElseIf { count: 3 }), "a few");
+    assert_eq!(format!("{}", ElseIf { count: 10 }), "many");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{{ if a > b { a * 2 } else { b + 1 } }} {{ if a > b { a } else { b }.pow(2) }}")]
+struct Values {
+    a: usize,
+    b: usize,
+}
+
+#[test]
+fn values() {
+    assert_eq!(format!("{}", Values { a: 3, b: 5 }), "6 25");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    "{{ if loud { name | shout } else { name | whisper } }} {{ if loud { name } else { \"nobody\" \
+     } | shout }}"
+)]
+struct Filters {
+    loud: bool,
+    name: &'static str,
+}
+
+#[test]
+fn filters() {
+    assert_eq!(
+        format!(
+            "{}",
+            Filters {
+                loud: true,
+                name: "Luna"
+            }
+        ),
+        "Luna! Luna!"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            Filters {
+                loud: false,
+                name: "Luna"
+            }
+        ),
+        "luna nobody!"
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{% let label = if admin { \"Admin\" } else { \"User\" } %}{{ label }}")]
+struct Let {
+    admin: bool,
+}
+
+#[test]
+fn r#let() {
+    assert_eq!(format!("{}", Let { admin: true }), "Admin");
+    assert_eq!(format!("{}", Let { admin: false }), "User");
+}
@@ -0,0 +1,15 @@
+#[test]
+#[ignore = "Broken tests are expensive and can fail on slight wording changes, so they should be \
+            run separately."]
+fn broken() {
+    unsafe {
+        std::env::set_var(
+            "CARGO_MANIFEST_DIR_OVERRIDE",
+            std::env::var("CARGO_MANIFEST_DIR").unwrap(),
+        );
+    }
+
+    let tests = trybuild::TestCases::new();
+
+    tests.compile_fail("tests/broken/*.rs");
+}
@@ -0,0 +1,3 @@
+fn main() {
+    // Nothing to do; see tests
+}
@@ -0,0 +1,100 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::vec::Vec;
+
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+enum Page {
+    #[oxiplate_inline("Loading...")]
+    Loading,
+
+    #[oxiplate_inline("Error: {{ message }} ({{ code }})")]
+    Error { message: &'static str, code: u16 },
+
+    #[oxiplate_inline(
+        "{% for item in items %}{{ item }}{% if item != last %}, {% endif %}{% endfor %}"
+    )]
+    List {
+        items: Vec<&'static str>,
+        last: &'static str,
+    },
+
+    #[oxiplate = "external.html.oxip"]
+    Detail {
+        title: &'static str,
+        message: &'static str,
+    },
+
+    #[oxiplate_inline("Unnamed fields are not in scope")]
+    Tuple(#[allow(dead_code)] &'static str),
+}
+
+#[test]
+fn unit() {
+    assert_eq!(format!("{}", Page::Loading), "Loading...");
+}
+
+#[test]
+fn named_fields() {
+    assert_eq!(
+        format!(
+            "{}",
+            Page::Error {
+                message: "Not found",
+                code: 404,
+            }
+        ),
+        "Error: Not found (404)"
+    );
+}
+
+#[test]
+fn statements() {
+    assert_eq!(
+        format!(
+            "{}",
+            Page::List {
+                items: ["a", "b", "c"].into(),
+                last: "c",
+            }
+        ),
+        "a, b, c"
+    );
+}
+
+#[test]
+fn external() {
+    assert_eq!(
+        format!(
+            "{}",
+            Page::Detail {
+                title: "Hello",
+                message: "world",
+            }
+        ),
+        "<h1>Hello</h1>\n<p>world</p>\n"
+    );
+}
+
+#[test]
+fn tuple() {
+    assert_eq!(
+        format!("{}", Page::Tuple("ignored")),
+        "Unnamed fields are not in scope"
+    );
+}
+
+#[derive(Oxiplate)]
+enum Keywords {
+    #[oxiplate_inline("{{ type }}")]
+    Field { r#type: &'static str },
+}
+
+#[test]
+fn raw_field_names() {
+    assert_eq!(format!("{}", Keywords::Field { r#type: "raw" }), "raw");
+}
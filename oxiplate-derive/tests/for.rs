@@ -2,6 +2,7 @@
 
 extern crate alloc;
 
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use alloc::{format, vec};
 
@@ -196,3 +197,119 @@ fn test_break_else() {
 
     assert_eq!(format!("{data}"), "");
 }
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    "
+{%- for value in values.iter().copied() if value > 20 -%}
+    {{ value }},
+{%- else -%}
+    No values :(
+{%- endfor %}"
+)]
+struct Filtered {
+    values: Vec<usize>,
+}
+
+#[test]
+fn test_filtered() {
+    let data = Filtered {
+        values: vec![19, 23, 89, 4],
+    };
+    assert_eq!(format!("{data}"), "23,89,");
+
+    let data = Filtered {
+        values: vec![19, 4],
+    };
+    assert_eq!(format!("{data}"), "No values :(");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    "
+{%- for (name, visible) in &people if visible -%}
+    {{ name }},
+{%- endfor %}"
+)]
+struct FilteredPattern {
+    people: Vec<(&'static str, bool)>,
+}
+
+#[test]
+fn test_filtered_pattern() {
+    let data = FilteredPattern {
+        people: vec![("Zoe", true), ("Alice", false), ("Sam", true)],
+    };
+
+    assert_eq!(format!("{data}"), "Zoe,Sam,");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    "
+{%- for name in names.clone() if !name.is_empty() -%}
+    {{ name }},
+{%- endfor %}"
+)]
+struct FilteredOwned {
+    names: Vec<String>,
+}
+
+#[test]
+fn test_filtered_owned() {
+    let data = FilteredOwned {
+        names: vec!["Zoe".to_string(), String::new(), "Sam".to_string()],
+    };
+
+    assert_eq!(format!("{data}"), "Zoe,Sam,");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    "
+{%- for 'rows: row in &rows -%}
+    {%- for value in row -%}
+        {%- if *value == 0 %}{% break 'rows %}{% endif -%}
+        {{ value }},
+    {%- endfor -%}
+    |
+{%- endfor %}"
+)]
+struct LabelledBreak {
+    rows: Vec<Vec<usize>>,
+}
+
+#[test]
+fn test_labelled_break() {
+    let data = LabelledBreak {
+        rows: vec![vec![19, 23], vec![89, 0, 42], vec![4]],
+    };
+
+    assert_eq!(format!("{data}"), "19,23,|89,");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    "
+{%- for 'rows: row in &rows -%}
+    {%- for value in row -%}
+        {%- if *value == 0 %}{% continue 'rows %}{% endif -%}
+        {{ value }},
+    {%- endfor -%}
+    |
+{%- else -%}
+    No rows :(
+{%- endfor %}"
+)]
+struct LabelledContinue {
+    rows: Vec<Vec<usize>>,
+}
+
+#[test]
+fn test_labelled_continue() {
+    let data = LabelledContinue {
+        rows: vec![vec![19, 23], vec![89, 0, 42], vec![4]],
+    };
+
+    assert_eq!(format!("{data}"), "19,23,|89,4,|");
+}
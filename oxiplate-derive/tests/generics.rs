@@ -0,0 +1,116 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+use core::fmt::Display;
+
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{{ value }}")]
+struct Bounded<T: Display> {
+    value: T,
+}
+
+#[test]
+fn bounded() {
+    assert_eq!(format!("{}", Bounded { value: 19 }), "19");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{{ value }}")]
+struct WhereClause<T>
+where
+    T: Display,
+{
+    value: T,
+}
+
+#[test]
+fn where_clause() {
+    assert_eq!(format!("{}", WhereClause { value: "hello" }), "hello");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{% for value in values %}{{ value }}{% endfor %} ({{ values.len() }})")]
+struct Const<const N: usize> {
+    values: [u8; N],
+}
+
+#[test]
+fn const_generic() {
+    assert_eq!(format!("{}", Const { values: [1, 2, 3] }), "123 (3)");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{{ value }}")]
+struct Defaulted<T: Display = &'static str> {
+    value: T,
+}
+
+#[test]
+fn defaulted() {
+    let data: Defaulted = Defaulted { value: "default" };
+    assert_eq!(format!("{data}"), "default");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{{ title }}: {{ value }}")]
+struct LifetimeAndType<'a, T: Display + ?Sized> {
+    title: &'a str,
+    value: &'a T,
+}
+
+#[test]
+fn lifetime_and_type() {
+    let data = LifetimeAndType {
+        title: "Count",
+        value: &42,
+    };
+    assert_eq!(format!("{data}"), "Count: 42");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate = "extends-deep.html.oxip"]
+struct Extends<'a, T>
+where
+    T: Display,
+{
+    title: &'a str,
+    message: T,
+}
+
+#[test]
+fn extends() {
+    let data = Extends {
+        title: "Oxiplate Example",
+        message: 19,
+    };
+
+    assert_eq!(
+        format!("{data}"),
+        "<!DOCTYPE html>\n<title>Oxiplate Example</title>\n<h2>Oxiplate Example</h2>\n  \
+         <div>19</div>\n"
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(r#"{% include "include-deep.html.oxip" %}"#)]
+struct Include<'a, T: Display> {
+    title: &'a str,
+    message: T,
+}
+
+#[test]
+fn include() {
+    let data = Include {
+        title: "Oxiplate Example",
+        message: "Hello world!",
+    };
+
+    assert_eq!(
+        format!("{}", data),
+        "<h1>Oxiplate Example</h1>\n<p>foo</p>\n\n<p>Hello world!</p>\n"
+    );
+}
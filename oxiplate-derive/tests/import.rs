@@ -0,0 +1,78 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    r#"
+{%- import "macros.html.oxip" as ui -%}
+{{ ui.link("Home", "/") }}
+{% call ui.link(name, url) %}"#
+)]
+struct Namespace {
+    name: &'static str,
+    url: &'static str,
+}
+
+#[test]
+fn namespace() {
+    let data = Namespace {
+        name: "Profile",
+        url: "/profile",
+    };
+
+    assert_eq!(
+        format!("{data}"),
+        "<a href=\"/\">Home</a>\n<a href=\"/profile\">Profile</a>"
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    r#"
+{%- from "macros.html.oxip" import greeting, link as anchor, -%}
+{{ greeting() }} {{ anchor("Home", "/") }}"#
+)]
+struct Names {
+    name: &'static str,
+}
+
+#[test]
+fn names() {
+    let data = Names { name: "Sam" };
+
+    assert_eq!(format!("{data}"), "Hello, Sam! <a href=\"/\">Home</a>");
+}
+
+struct Ui {
+    home: &'static str,
+}
+
+impl Ui {
+    fn link(&self) -> &'static str {
+        self.home
+    }
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    r#"
+{%- import "macros.html.oxip" as macros -%}
+{{ ui.link() }} {{ macros.link("Home", ui.link()) }}"#
+)]
+struct Field {
+    ui: Ui,
+}
+
+#[test]
+fn field() {
+    let data = Field {
+        ui: Ui { home: "/" },
+    };
+
+    assert_eq!(format!("{data}"), "/ <a href=\"/\">Home</a>");
+}
@@ -46,3 +46,102 @@ fn include_deep() {
         "<h1>Oxiplate Example</h1>\n<p>foo</p>\n\n<p>Hello world!</p>\n"
     );
 }
+
+struct Post {
+    title: &'static str,
+    url: &'static str,
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    r#"
+{%- for post in &posts -%}
+    {% include "card.html.oxip" with { title: post.title, url: post.url } %}
+{%- endfor %}"#
+)]
+struct IncludeWith {
+    site_name: &'static str,
+    posts: [Post; 2],
+}
+
+#[test]
+fn include_with() {
+    let data = IncludeWith {
+        site_name: "Oxiplate",
+        posts: [
+            Post {
+                title: "Hello",
+                url: "/hello",
+            },
+            Post {
+                title: "World",
+                url: "/world",
+            },
+        ],
+    };
+
+    assert_eq!(
+        format!("{}", data),
+        "<article><h2><a href=\"/hello\">Hello</a></h2>Oxiplate</article>\n<article><h2><a \
+         href=\"/world\">World</a></h2>Oxiplate</article>\n"
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    r#"
+{%- let url = "/hello" -%}
+{% include "card-only.html.oxip" with { title: post.title, url, } only %}"#
+)]
+struct IncludeWithOnly {
+    post: Post,
+}
+
+#[test]
+fn include_with_only() {
+    let data = IncludeWithOnly {
+        post: Post {
+            title: "Hello",
+            url: "/world",
+        },
+    };
+
+    assert_eq!(
+        format!("{}", data),
+        "<article><h2><a href=\"/hello\">Hello</a></h2></article>\n"
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    r#"steps:
+  - {% include "step.yml.oxip" indent %}
+  - {% include "step.yml.oxip" with { name: "test", run } indent %}
+"#
+)]
+struct IncludeIndent {
+    name: &'static str,
+    run: &'static str,
+}
+
+#[test]
+fn include_indent() {
+    let data = IncludeIndent {
+        name: "build",
+        run: "cargo build\ncargo doc",
+    };
+
+    assert_eq!(
+        format!("{}", data),
+        "steps:
+  - name: build
+    run: |
+      cargo build
+      cargo doc
+  - name: test
+    run: |
+      cargo build
+      cargo doc
+"
+    );
+}
@@ -0,0 +1,78 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("def main():\n    {{ indent: body }}\n")]
+struct Indent {
+    body: &'static str,
+}
+
+#[test]
+fn indent() {
+    let data = Indent {
+        body: "a = 1\n\nif a:\n    print(a)",
+    };
+
+    assert_eq!(
+        format!("{}", data),
+        "def main():\n    a = 1\n\n    if a:\n        print(a)\n"
+    );
+}
+
+/// Text before the writ counts towards the column,
+/// while tabs are kept so the lines still line up.
+#[derive(Oxiplate)]
+#[oxiplate_inline("\t- {{ indent: raw: items }}")]
+struct Column {
+    items: &'static str,
+}
+
+#[test]
+fn column() {
+    let data = Column {
+        items: "one\ntwo\nthree",
+    };
+
+    assert_eq!(format!("{}", data), "\t- one\n\t  two\n\t  three");
+}
+
+/// Removing whitespace before the writ
+/// moves the column to where the whitespace started.
+#[derive(Oxiplate)]
+#[oxiplate_inline("key:    {{- indent: value }}")]
+struct RemovedWhitespace {
+    value: &'static str,
+}
+
+#[test]
+fn removed_whitespace() {
+    let data = RemovedWhitespace { value: " a\n b" };
+
+    assert_eq!(format!("{}", data), "key: a\n     b");
+}
+
+/// Only static text before the writ counts towards the column,
+/// so tags earlier on the same line don't move it.
+#[derive(Oxiplate)]
+#[oxiplate_inline("  {% if show %}{{ label }}: {{ indent: body }}{% endif %}")]
+struct EarlierTags {
+    show: bool,
+    label: &'static str,
+    body: &'static str,
+}
+
+#[test]
+fn earlier_tags() {
+    let data = EarlierTags {
+        show: true,
+        label: "list",
+        body: "a\nb",
+    };
+
+    assert_eq!(format!("{}", data), "  list: a\n    b");
+}
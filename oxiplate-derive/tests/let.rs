@@ -241,3 +241,48 @@ fn destructure() {
         "19"
     );
 }
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    r#"
+{%- let title %}{{ name }} ({{ count }}){% endlet -%}
+<title>{{ title }}</title>
+<h1>{{ title }}</h1>"#
+)]
+struct Capture {
+    name: &'static str,
+    count: usize,
+}
+
+#[test]
+fn capture() {
+    assert_eq!(
+        format!(
+            "{}",
+            Capture {
+                name: "Inbox",
+                count: 19
+            }
+        ),
+        "<title>Inbox (19)</title>\n<h1>Inbox (19)</h1>"
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    r#"
+{%- let items %}{% for value in &values %}<li>{{ value }}{% endfor %}{% endlet -%}
+{% if items.is_empty() %}Nothing{% else %}<ul>{{ items }}</ul>{% endif %}"#
+)]
+struct CaptureEmpty {
+    values: Vec<usize>,
+}
+
+#[test]
+fn capture_empty() {
+    assert_eq!(format!("{}", CaptureEmpty { values: vec![] }), "Nothing");
+    assert_eq!(
+        format!("{}", CaptureEmpty { values: vec![1, 2] }),
+        "<ul><li>1<li>2</ul>"
+    );
+}
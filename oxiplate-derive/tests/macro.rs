@@ -0,0 +1,105 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    r#"
+{%- macro link(label, href) -%}
+    <a href="{{ href }}">{{ label }}</a>
+{%- endmacro -%}
+{{ link("Home", "/") }}
+{{ link(name, url) }}"#
+)]
+struct Writ {
+    name: &'static str,
+    url: &'static str,
+}
+
+#[test]
+fn writ() {
+    let data = Writ {
+        name: "Profile",
+        url: "/profile",
+    };
+
+    assert_eq!(
+        format!("{data}"),
+        "<a href=\"/\">Home</a>\n<a href=\"/profile\">Profile</a>"
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    r#"
+{%- macro greeting() -%}
+    Hello, {{ name }}!
+{%- endmacro -%}
+{% call greeting() %} {% call greeting() %}"#
+)]
+struct Call {
+    name: &'static str,
+}
+
+#[test]
+fn call() {
+    let data = Call { name: "Sam" };
+
+    assert_eq!(format!("{data}"), "Hello, Sam! Hello, Sam!");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    r#"
+{%- macro repeat(value: &str, count: usize,) -%}
+    {%- for _ in 0..count %}{{ value }}{% endfor -%}
+{%- endmacro -%}
+{{ repeat("ab", 3) }}"#
+)]
+struct Typed;
+
+#[test]
+fn typed() {
+    assert_eq!(format!("{}", Typed), "ababab");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    r#"
+{%- macro item(value) -%}
+    [{{ value }}]
+{%- endmacro -%}
+{%- macro list(values: &[usize]) -%}
+    {%- for value in values %}{% call item(value) %}{% endfor -%}
+{%- endmacro -%}
+{% let value = 19 -%}
+{{ list(&values) }} {{ value }}"#
+)]
+struct Nested {
+    values: [usize; 3],
+}
+
+#[test]
+fn nested() {
+    let data = Nested { values: [1, 2, 3] };
+
+    assert_eq!(format!("{data}"), "[1][2][3] 19");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    r#"
+{%- macro swap(a, b) %}{{ a }}{{ b }}{% endmacro -%}
+{% let a = 1 %}{% let b = 2 -%}
+{{ swap(b, a) }}"#
+)]
+struct Shadowing;
+
+#[test]
+fn shadowing() {
+    assert_eq!(format!("{}", Shadowing), "21");
+}
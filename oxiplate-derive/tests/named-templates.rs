@@ -0,0 +1,39 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("Hello {{ name }}!")]
+#[oxiplate_inline(name = "short", "Hi {{ name }}")]
+#[oxiplate(name = "external", path = "external.html.oxip")]
+struct Data {
+    name: &'static str,
+    title: &'static str,
+    message: &'static str,
+}
+
+#[test]
+fn named() -> Result<(), ::core::fmt::Error> {
+    let data = Data {
+        name: "Sam",
+        title: "Title",
+        message: "Message",
+    };
+
+    assert_eq!(format!("{data}"), "Hello Sam!");
+
+    let mut string = String::new();
+    data.render_short_into(&mut string)?;
+    assert_eq!(string, "Hi Sam");
+
+    let mut string = String::new();
+    data.render_external_into(&mut string)?;
+    assert_eq!(string, "<h1>Title</h1>\n<p>Message</p>\n");
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    "{% raw %}{{ name }} {% if true %}{# comment #}{% endif %} {-} ${{ secrets.TOKEN }}{% endraw \
+     %}"
+)]
+struct Tags;
+
+#[test]
+fn tags() {
+    assert_eq!(
+        format!("{}", Tags),
+        "{{ name }} {% if true %}{# comment #}{% endif %} {-} ${{ secrets.TOKEN }}"
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    "<p>{{ name }}</p>{% raw %}<p>{{ name }}</p>{% endraw %}<p>{{ name }}</p>{% raw %}{{ name \
+     }}{% endraw %}"
+)]
+struct Surrounded {
+    name: &'static str,
+}
+
+#[test]
+fn surrounded() {
+    assert_eq!(
+        format!("{}", Surrounded { name: "Sam" }),
+        "<p>Sam</p><p>{{ name }}</p><p>Sam</p>{{ name }}"
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    "<div>
+    {%- raw -%}
+        {{ name }}
+        {{- name -}}
+    {%- endraw -%}
+</div>
+<div>
+    {%_ raw _%} {{ name }} {%_ endraw _%}
+</div>"
+)]
+struct Whitespace;
+
+#[test]
+fn whitespace() {
+    assert_eq!(
+        format!("{}", Whitespace),
+        "<div>{{ name }}
+        {{- name -}}</div>
+<div>  {{ name }}  </div>"
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{% raw %}{% endif %}{%endraw%}{% raw %}{% endrawr %}{%- endraw %}")]
+struct Statements;
+
+#[test]
+fn statements() {
+    assert_eq!(format!("{}", Statements), "{% endif %}{% endrawr %}");
+}
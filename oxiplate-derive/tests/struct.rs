@@ -0,0 +1,120 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+
+use oxiplate_derive::Oxiplate;
+
+struct Link {
+    href: &'static str,
+    text: &'static str,
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    r#"
+{%- for link in [
+    Link { href: "/", text: "Home" },
+    Link { href, text: title },
+] -%}
+    <a href="{{ link.href }}">{{ link.text }}</a>
+{%- endfor %}"#
+)]
+struct Named {
+    href: &'static str,
+    title: &'static str,
+}
+
+#[test]
+fn named() {
+    assert_eq!(
+        format!(
+            "{}",
+            Named {
+                href: "/about",
+                title: "About",
+            }
+        ),
+        r#"<a href="/">Home</a><a href="/about">About</a>"#
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    r#"
+{%- let text = "Local" -%}
+{%- let link = Link { text, href: "/local", } -%}
+{{ link.text }} {{ link.href }}"#
+)]
+struct LocalShorthand;
+
+#[test]
+fn local_shorthand() {
+    assert_eq!(format!("{}", LocalShorthand), "Local /local");
+}
+
+struct Meters(f32);
+
+mod filters_for_oxiplate {
+    use super::Meters;
+
+    pub fn feet(Meters(meters): Meters) -> f32 {
+        meters * 3.25
+    }
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{{ Meters(distance) | feet }}")]
+struct Tuple {
+    distance: f32,
+}
+
+#[test]
+fn tuple() {
+    assert_eq!(format!("{}", Tuple { distance: 2. }), "6.5");
+}
+
+#[derive(PartialEq)]
+enum Kind {
+    Link,
+    Button,
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    r#"
+{%- if kind == Kind::Link -%}
+    <a>
+{%- endif -%}
+<{{ if kind == Kind::Button { "button" } else { "span" } }}>
+{%- if let Some(value) = Some(value) %} {{ value }}{% endif %}"#
+)]
+struct Variant {
+    kind: Kind,
+    value: usize,
+}
+
+#[test]
+fn variant() {
+    assert_eq!(
+        format!(
+            "{}",
+            Variant {
+                kind: Kind::Link,
+                value: 19,
+            }
+        ),
+        "<a><span> 19"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            Variant {
+                kind: Kind::Button,
+                value: 89,
+            }
+        ),
+        "<button> 89"
+    );
+}
@@ -0,0 +1,61 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+
+use oxiplate_derive::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_syntax(writ = ["<<", ">>"], statement = ["<%", "%>"], comment = ["<#", "#>"])]
+#[oxiplate_inline("<# Greeting #><% if show %>Hello, << name >>!<% endif %> {{ name }}")]
+struct Delimiters {
+    show: bool,
+    name: &'static str,
+}
+
+#[test]
+fn delimiters() {
+    assert_eq!(
+        format!(
+            "{}",
+            Delimiters {
+                show: true,
+                name: "world"
+            }
+        ),
+        "Hello, world! {{ name }}"
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_syntax(writ = ["<<", ">>"])]
+#[oxiplate_inline("{% if show %}\n    <<- name _>>\n\n{% endif %}<->\n!")]
+struct Whitespace {
+    show: bool,
+    name: &'static str,
+}
+
+#[test]
+fn whitespace() {
+    assert_eq!(
+        format!(
+            "{}",
+            Whitespace {
+                show: true,
+                name: "world"
+            }
+        ),
+        "world !"
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_syntax(statement = ["[%", "%]"])]
+#[oxiplate_inline("[% raw %]{{ name }} {% endraw %}[% endraw %]")]
+struct Raw;
+
+#[test]
+fn raw() {
+    assert_eq!(format!("{}", Raw), "{{ name }} {% endraw %}");
+}
@@ -0,0 +1,54 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use core::fmt::Write as _;
+use core::num::ParseIntError;
+
+use oxiplate_derive::Oxiplate;
+
+struct Input {
+    value: &'static str,
+}
+
+impl Input {
+    fn parse(&self) -> Result<u8, ParseIntError> {
+        self.value.parse()
+    }
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{{ input.parse()? + 1 }} {{ input.parse()? as char }}")]
+struct Data {
+    input: Input,
+}
+
+#[test]
+fn ok() {
+    assert_eq!(
+        format!(
+            "{}",
+            Data {
+                input: Input { value: "65" },
+            }
+        ),
+        "66 A"
+    );
+}
+
+#[test]
+fn err() {
+    let mut output = String::new();
+    let result = write!(
+        output,
+        "{}",
+        Data {
+            input: Input {
+                value: "sixty-five"
+            },
+        }
+    );
+    assert!(result.is_err());
+}
@@ -113,3 +113,50 @@ struct WhitespaceOnly;
 fn whitespace_only() {
     assert_eq!(" \t\r\n{", format!("{}", WhitespaceOnly));
 }
+
+#[derive(Oxiplate)]
+#[oxiplate_syntax(trim_blocks = true, lstrip_blocks = true)]
+#[oxiplate_inline(
+    "    {# Comment #}
+<ul>
+    {% for item in items %}
+    <li>{{ item }}</li>
+    {% endfor %}
+    {%+ if items.is_empty() +%}
+    {%- endif %}
+</ul>
+"
+)]
+struct TrimAndStripBlocks {
+    items: [&'static str; 2],
+}
+
+#[test]
+fn trim_and_strip_blocks() {
+    assert_eq!(
+        format!("{}", TrimAndStripBlocks { items: ["a", "b"] }),
+        "<ul>\n    <li>a</li>\n    <li>b</li>\n    </ul>\n"
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_syntax(trim_blocks = true)]
+#[oxiplate_inline("{% if show %}\r\n    {{ name }}\r\n{% endif %}\r\n")]
+struct TrimBlocksCrlf {
+    show: bool,
+    name: &'static str,
+}
+
+#[test]
+fn trim_blocks_crlf() {
+    assert_eq!(
+        format!(
+            "{}",
+            TrimBlocksCrlf {
+                show: true,
+                name: "Luna"
+            }
+        ),
+        "    Luna\r\n"
+    );
+}
@@ -0,0 +1,133 @@
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::fmt::{self, Debug, Display, Formatter, Write};
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::{EscapedRender, Escaper, FastCowStr, FastEscape, Render};
+
+/// Text that has already been escaped for the escaper group `G`,
+/// like the output captured by a block `let` statement.
+/// Written as-is by writs using the default escaper of `G`,
+/// and escaped like any other text otherwise.
+///
+/// ```rust
+/// # use oxiplate_traits as oxiplate;
+/// use oxiplate::{EscapedString, Escaper};
+/// # enum YourEscaper { Text }
+/// # impl Escaper for YourEscaper {
+/// #     const DEFAULT: Self = Self::Text;
+/// #     fn escape<W: std::fmt::Write + ?Sized>(&self, f: &mut W, value: &str) -> std::fmt::Result {
+/// #         f.write_str(value)
+/// #     }
+/// # }
+/// let title = EscapedString::<YourEscaper>::new(String::from("Tom &amp; Jerry"));
+/// assert_eq!(title, "Tom &amp; Jerry");
+/// assert!(!title.is_empty());
+/// ```
+pub struct EscapedString<G> {
+    string: String,
+    group: PhantomData<G>,
+}
+
+impl<G> EscapedString<G> {
+    /// Wrap text that has already been escaped for the escaper group `G`.
+    #[must_use]
+    #[inline]
+    pub fn new(string: String) -> Self {
+        Self {
+            string,
+            group: PhantomData,
+        }
+    }
+
+    /// Extract the escaped text.
+    #[must_use]
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.string
+    }
+}
+
+impl<G> Deref for EscapedString<G> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.string
+    }
+}
+
+impl<G> Debug for EscapedString<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.string, f)
+    }
+}
+
+impl<G> Display for EscapedString<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.string)
+    }
+}
+
+impl<G> PartialEq<str> for EscapedString<G> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.string == other
+    }
+}
+
+impl<G> PartialEq<&str> for EscapedString<G> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.string == *other
+    }
+}
+
+impl<G: Escaper> Render for EscapedString<G> {
+    const ESTIMATED_LENGTH: usize = 0;
+
+    #[inline]
+    fn render_into<W: Write>(&self, writer: &mut W) -> fmt::Result {
+        writer.write_str(&self.string)
+    }
+}
+
+impl<G: Escaper> EscapedRender<G> for EscapedString<G> {}
+
+impl<'a, G, W: Write + ?Sized> FastEscape<'a, W> for EscapedString<G> {
+    #[inline]
+    fn oxiplate_fast_escape(&'a self, f: &mut W, escaper: &impl Escaper) -> fmt::Result {
+        #[cfg(feature = "_debug-fast-escape-type-priority")]
+        f.write_str("EscapedString(")?;
+
+        escaper.escape(f, &self.string)?;
+
+        #[cfg(feature = "_debug-fast-escape-type-priority")]
+        f.write_str(")")?;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn oxiplate_fast_raw(&'a self, f: &mut W) -> fmt::Result {
+        #[cfg(feature = "_debug-fast-escape-type-priority")]
+        f.write_str("EscapedString(")?;
+
+        f.write_str(&self.string)?;
+
+        #[cfg(feature = "_debug-fast-escape-type-priority")]
+        f.write_str(")")?;
+
+        Ok(())
+    }
+}
+
+impl<'a, G> FastCowStr<'a> for EscapedString<G> {
+    #[inline]
+    fn oxiplate_cow_str(&'a self) -> Cow<'a, str> {
+        Cow::Borrowed(&self.string)
+    }
+}
@@ -6,14 +6,18 @@
 #![warn(missing_docs)]
 
 mod cow_str;
+mod escaped_string;
 mod escaper;
 mod render;
+mod render_fn;
 mod unescaped_text;
 
 pub use cow_str::{CowStr, CowStrWrapper, FastCowStr, ToCowStr, ToCowStrWrapper};
+pub use escaped_string::EscapedString;
 pub use escaper::Escaper;
-pub use render::Render;
-pub use unescaped_text::{FastEscape, UnescapedText, UnescapedTextWrapper};
+pub use render::{EscapedRender, Render, TryRender};
+pub use render_fn::RenderFn;
+pub use unescaped_text::{EscaperGroupWrapper, FastEscape, UnescapedText, UnescapedTextWrapper};
 
 /// Macro to efficiently convert a value to a `CowStrWrapper`
 /// for unit testing filters that deal with string-like values.
@@ -3,6 +3,8 @@ extern crate alloc;
 use alloc::string::String;
 use core::fmt::{self, Error, Write};
 
+use crate::Escaper;
+
 /// Optimized render function trait.
 pub trait Render {
     /// Estimated output length of the template.
@@ -26,3 +28,71 @@ pub trait Render {
     /// If strings cannot be written to the formatter.
     fn render_into<W: Write>(&self, writer: &mut W) -> fmt::Result;
 }
+
+impl<T: Render + ?Sized> Render for &T {
+    const ESTIMATED_LENGTH: usize = T::ESTIMATED_LENGTH;
+
+    #[inline]
+    fn render_into<W: Write>(&self, writer: &mut W) -> fmt::Result {
+        T::render_into(self, writer)
+    }
+}
+
+/// Render function trait for templates that can fail with their own error type.
+///
+/// Implemented automatically by `#[derive(Oxiplate)]`
+/// for templates with `#[oxiplate_error(YourError)]`
+/// instead of [`Render`].
+pub trait TryRender {
+    /// Error returned when rendering fails,
+    /// including when strings cannot be written to the writer.
+    type Error: From<Error>;
+
+    /// Estimated output length of the template.
+    const ESTIMATED_LENGTH: usize;
+
+    /// Render the template into a string.
+    ///
+    /// # Errors
+    ///
+    /// If the template fails to render
+    /// or strings cannot be written to the formatter.
+    fn render(&self) -> Result<String, Self::Error> {
+        let mut string = String::with_capacity(Self::ESTIMATED_LENGTH);
+        self.render_into(&mut string)?;
+        Ok(string)
+    }
+
+    /// Render the template into a writer.
+    ///
+    /// # Errors
+    ///
+    /// If the template fails to render
+    /// or strings cannot be written to the formatter.
+    fn render_into<W: Write>(&self, writer: &mut W) -> Result<(), Self::Error>;
+}
+
+impl<T: TryRender + ?Sized> TryRender for &T {
+    type Error = T::Error;
+
+    const ESTIMATED_LENGTH: usize = T::ESTIMATED_LENGTH;
+
+    #[inline]
+    fn render_into<W: Write>(&self, writer: &mut W) -> Result<(), Self::Error> {
+        T::render_into(self, writer)
+    }
+}
+
+/// Marker for templates whose output is already escaped
+/// for the escaper group `G`.
+///
+/// When a writ using the default escaper of `G` outputs one of these templates,
+/// the template is rendered straight into the outer writer
+/// rather than having its output escaped a second time.
+/// Templates from any other escaper group are escaped like any other value.
+///
+/// Implemented automatically by `#[derive(Oxiplate)]`
+/// for templates with an escaper group.
+pub trait EscapedRender<G: Escaper>: Render {}
+
+impl<G: Escaper, T: EscapedRender<G> + ?Sized> EscapedRender<G> for &T {}
@@ -0,0 +1,54 @@
+use core::fmt::{self, Display, Formatter, Write};
+
+use crate::Render;
+
+/// Template rendered by a function rather than a struct.
+/// Built by `oxiplate::render!()`.
+///
+/// ```rust
+/// # use oxiplate_traits as oxiplate;
+/// use oxiplate::{Render, RenderFn};
+///
+/// let template = RenderFn::<_, _, 11>::new(("world",), |f, (name,)| {
+///     f.write_str("Hello ")?;
+///     f.write_str(name)
+/// });
+///
+/// assert_eq!("Hello world", template.render()?);
+/// # Ok::<(), ::std::fmt::Error>(())
+/// ```
+pub struct RenderFn<D, F, const ESTIMATED_LENGTH: usize> {
+    data: D,
+    render: F,
+}
+
+impl<D, F, const ESTIMATED_LENGTH: usize> RenderFn<D, F, ESTIMATED_LENGTH> {
+    /// Wrap the data and the function that renders it.
+    pub fn new(data: D, render: F) -> Self
+    where
+        F: Fn(&mut dyn Write, &D) -> fmt::Result,
+    {
+        Self { data, render }
+    }
+}
+
+impl<D, F, const ESTIMATED_LENGTH: usize> Render for RenderFn<D, F, ESTIMATED_LENGTH>
+where
+    F: Fn(&mut dyn Write, &D) -> fmt::Result,
+{
+    const ESTIMATED_LENGTH: usize = ESTIMATED_LENGTH;
+
+    #[inline]
+    fn render_into<W: Write>(&self, writer: &mut W) -> fmt::Result {
+        (self.render)(writer, &self.data)
+    }
+}
+
+impl<D, F, const ESTIMATED_LENGTH: usize> Display for RenderFn<D, F, ESTIMATED_LENGTH>
+where
+    F: Fn(&mut dyn Write, &D) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        (self.render)(f, &self.data)
+    }
+}
@@ -8,8 +8,11 @@ use alloc::rc::Rc;
 use alloc::string::{String, ToString};
 use alloc::sync::Arc;
 use core::fmt::{Display, Result, Write};
+use core::marker::PhantomData;
+use core::ops::Deref;
 
 use crate::escaper::Escaper;
+use crate::render::EscapedRender;
 
 /// Wrapper around unescaped text
 /// that will implement `UnescapedText`
@@ -36,6 +39,50 @@ impl<'a, T> UnescapedTextWrapper<'a, T> {
     }
 }
 
+/// Wrapper around an [`UnescapedTextWrapper`]
+/// that is tagged with the escaper group of the writ.
+/// Values that implement [`EscapedRender`] for the same group
+/// are rendered directly into the writer,
+/// and all other values fall back to `UnescapedTextWrapper`.
+/// Must borrow twice before calling `oxiplate_escape()`.
+///
+/// ```rust
+/// # use oxiplate_traits as oxiplate;
+/// use oxiplate::{EscaperGroupWrapper, Escaper, UnescapedText, UnescapedTextWrapper};
+/// # enum YourEscaper { Text }
+/// # impl Escaper for YourEscaper {
+/// #     const DEFAULT: Self = Self::Text;
+/// #     fn escape<W: std::fmt::Write + ?Sized>(&self, f: &mut W, value: &str) -> std::fmt::Result {
+/// #         f.write_str(&value.replace('<', "&lt;"))
+/// #     }
+/// # }
+/// let text = "<b>";
+/// let mut string = String::new();
+/// let formatter = &mut string;
+/// (&&EscaperGroupWrapper::<_, YourEscaper>::new(&&UnescapedTextWrapper::new(&text)))
+///     .oxiplate_escape(formatter, &YourEscaper::DEFAULT)?;
+/// assert_eq!("&lt;b>", string);
+/// # Ok::<(), ::std::fmt::Error>(())
+/// ```
+pub struct EscaperGroupWrapper<'a, T, G>(&'a &'a UnescapedTextWrapper<'a, T>, PhantomData<G>);
+
+impl<'a, T, G: Escaper> EscaperGroupWrapper<'a, T, G> {
+    /// Tag the wrapped unescaped text with an escaper group.
+    #[must_use]
+    pub fn new(value: &'a &'a UnescapedTextWrapper<'a, T>) -> Self {
+        Self(value, PhantomData)
+    }
+}
+
+impl<'a, T, G> Deref for EscaperGroupWrapper<'a, T, G> {
+    type Target = &'a &'a UnescapedTextWrapper<'a, T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
 /// Trait with a specialized implementation
 /// for items that implement `FastEscape`,
 /// a trait that allows for more efficient conversions to `&str`.
@@ -118,6 +165,36 @@ impl<'a, T: ToString + Display, W: Write + ?Sized> UnescapedText<'a, W>
     }
 }
 
+impl<'a, T: EscapedRender<G>, G: Escaper, W: Write + ?Sized> UnescapedText<'a, W>
+    for &EscaperGroupWrapper<'a, T, G>
+{
+    #[inline]
+    fn oxiplate_escape(&'a self, mut f: &mut W, _escaper: &impl Escaper) -> Result {
+        #[cfg(feature = "_debug-fast-escape-type-priority")]
+        f.write_str("EscapedRender(")?;
+
+        self.0.0.render_into(&mut f)?;
+
+        #[cfg(feature = "_debug-fast-escape-type-priority")]
+        f.write_str(")")?;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn oxiplate_raw(&'a self, mut f: &mut W) -> Result {
+        #[cfg(feature = "_debug-fast-escape-type-priority")]
+        f.write_str("EscapedRender(")?;
+
+        self.0.0.render_into(&mut f)?;
+
+        #[cfg(feature = "_debug-fast-escape-type-priority")]
+        f.write_str(")")?;
+
+        Ok(())
+    }
+}
+
 /// Trait that allows for more efficient conversions to `&str`.
 pub trait FastEscape<'a, W: Write + ?Sized> {
     /// Helper function to ensure the provided escaper implements [`Escaper`]
@@ -39,11 +39,54 @@ use core::iter::Peekable;
 /// ```
 pub fn r#loop<E: IntoIterator>(expression: E) -> impl Iterator<Item = (Loop, E::Item)> {
     let iterator = IntoIterator::into_iter(expression);
-    LoopIter::new(iterator)
+    LoopIter::new(iterator, 0)
+}
+
+/// Like [`loop`](r#loop), but also provides access to the previous and next items
+/// via [`Loop::previous()`], [`Loop::next()`], and [`Loop::changed()`].
+/// Items are cloned to make them available,
+/// so this is best used with iterators of references like `&values`.
+///
+/// ```
+/// use std::fmt::Error;
+///
+/// use oxiplate::prelude::*;
+///
+/// #[derive(Oxiplate)]
+/// #[oxiplate_inline(html: r#"
+/// {%- for (loop, name) in &names | loop_neighbors -%}
+///     {% if loop.changed(&name, |name| name.len()) %}[{{ name.len() }}] {% endif -%}
+///     {{ name }}{% if loop.next().is_some() %}, {% endif %}
+/// {%- endfor %}"#)]
+/// struct Data {
+///     names: Vec<&'static str>,
+/// }
+///
+/// fn main() -> Result<(), Error> {
+///     assert_eq!(
+///         Data {
+///             names: vec!["Ada", "Sam", "Alan", "Emma", "Grace"]
+///         }
+///         .render()?,
+///         "[3] Ada, Sam, [4] Alan, Emma, [5] Grace"
+///     );
+///     Ok(())
+/// }
+/// ```
+pub fn loop_neighbors<E>(expression: E) -> impl Iterator<Item = (Loop<Neighbors<E::Item>>, E::Item)>
+where
+    E: IntoIterator,
+    E::Item: Clone,
+{
+    let iterator = IntoIterator::into_iter(expression);
+    NeighborsIter {
+        iter: LoopIter::new(iterator, 0),
+        previous: None,
+    }
 }
 
 /// Loop iteration info.
-pub struct Loop {
+pub struct Loop<N = ()> {
     /// Iteration number starting from 0.
     pub index0: usize,
 
@@ -55,6 +98,111 @@ pub struct Loop {
 
     /// Whether this iteration is the last.
     pub is_last: bool,
+
+    /// Nesting level of the loop starting from 0.
+    /// Loops created with [`Loop::nested()`] are one level deeper than their parent.
+    pub depth0: usize,
+
+    /// Nesting level of the loop starting from 1.
+    pub depth1: usize,
+
+    /// Number of items in the loop, if known.
+    length: Option<usize>,
+
+    /// Previous and next items, if requested.
+    neighbors: N,
+}
+
+impl<N> Loop<N> {
+    /// Number of items in the loop.
+    /// Only known when the iterator reports an exact size,
+    /// like iterators that implement `ExactSizeIterator`.
+    #[must_use]
+    pub fn length(&self) -> Option<usize> {
+        self.length
+    }
+
+    /// Number of iterations remaining after this one.
+    /// Only known when the length of the loop is.
+    /// Stops at 0 if the iterator yields more items than its size hint reported.
+    #[must_use]
+    pub fn revindex0(&self) -> Option<usize> {
+        self.length.map(|length| length.saturating_sub(self.index1))
+    }
+
+    /// Number of iterations remaining including this one.
+    /// Only known when the length of the loop is.
+    /// Stops at 0 if the iterator yields more items than its size hint reported.
+    #[must_use]
+    pub fn revindex1(&self) -> Option<usize> {
+        self.length.map(|length| length.saturating_sub(self.index0))
+    }
+
+    /// Pick a value based on the current iteration,
+    /// starting over from the first value after the last one is used.
+    ///
+    /// ```oxip
+    /// <tr class="{{ attr: loop.cycle(["odd", "even"]) }}">
+    /// ```
+    pub fn cycle<T, const LENGTH: usize>(&self, values: [T; LENGTH]) -> T {
+        const {
+            assert!(
+                LENGTH > 0,
+                "At least one value must be provided to cycle through"
+            );
+        }
+
+        let Some(value) = values.into_iter().nth(self.index0 % LENGTH) else {
+            unreachable!("Index is always within the bounds of the values");
+        };
+
+        value
+    }
+
+    /// Wrap an iterator for a loop nested within this one,
+    /// like [`loop`](r#loop) but one level deeper.
+    ///
+    /// ```oxip
+    /// {% for (outer, row) in &rows | loop %}
+    ///     {% for (inner, cell) in outer.nested(row) %}
+    ///         {{ inner.depth1 }}
+    ///     {% endfor %}
+    /// {% endfor %}
+    /// ```
+    pub fn nested<E: IntoIterator>(&self, expression: E) -> impl Iterator<Item = (Loop, E::Item)> {
+        let iterator = IntoIterator::into_iter(expression);
+        LoopIter::new(iterator, self.depth1)
+    }
+}
+
+/// Previous and next items for [`loop_neighbors`].
+pub struct Neighbors<T> {
+    previous: Option<T>,
+    next: Option<T>,
+}
+
+impl<T> Loop<Neighbors<T>> {
+    /// Item from the previous iteration, if any.
+    #[must_use]
+    pub fn previous(&self) -> Option<&T> {
+        self.neighbors.previous.as_ref()
+    }
+
+    /// Item for the next iteration, if any.
+    #[must_use]
+    pub fn next(&self) -> Option<&T> {
+        self.neighbors.next.as_ref()
+    }
+
+    /// Whether the key for the current item differs from the previous item's,
+    /// or this is the first iteration.
+    /// Useful for outputting headers when groups of items change.
+    pub fn changed<K: PartialEq>(&self, item: &T, key: impl Fn(&T) -> K) -> bool {
+        self.neighbors
+            .previous
+            .as_ref()
+            .is_none_or(|previous| key(previous) != key(item))
+    }
 }
 
 /// Iterator for `Loop` that is peekable
@@ -65,6 +213,8 @@ where
 {
     iter: Peekable<I>,
     index1: usize,
+    depth0: usize,
+    length: Option<usize>,
 }
 
 impl<I> LoopIter<I>
@@ -72,10 +222,17 @@ where
     I: Iterator,
 {
     /// Create a new instance of `LoopIter`.
-    fn new(iter: I) -> LoopIter<I> {
+    fn new(iter: I, depth0: usize) -> LoopIter<I> {
+        let length = match iter.size_hint() {
+            (lower, Some(upper)) if lower == upper => Some(lower),
+            _ => None,
+        };
+
         LoopIter {
             iter: iter.peekable(),
             index1: 0,
+            depth0,
+            length,
         }
     }
 
@@ -103,6 +260,49 @@ where
                 index1: self.index1,
                 is_first: self.index1 == 1,
                 is_last: self.peek().is_none(),
+                depth0: self.depth0,
+                depth1: self.depth0 + 1,
+                length: self.length,
+                neighbors: (),
+            },
+            item,
+        ))
+    }
+}
+
+/// Iterator for `Loop` that also tracks the previous and next items.
+struct NeighborsIter<I>
+where
+    I: Iterator,
+{
+    iter: LoopIter<I>,
+    previous: Option<I::Item>,
+}
+
+impl<I> Iterator for NeighborsIter<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = (Loop<Neighbors<I::Item>>, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (info, item) = self.iter.next()?;
+        let neighbors = Neighbors {
+            previous: self.previous.replace(item.clone()),
+            next: self.iter.peek().cloned(),
+        };
+
+        Some((
+            Loop {
+                index0: info.index0,
+                index1: info.index1,
+                is_first: info.is_first,
+                is_last: info.is_last,
+                depth0: info.depth0,
+                depth1: info.depth1,
+                length: info.length,
+                neighbors,
             },
             item,
         ))
@@ -56,7 +56,7 @@ mod trim;
 mod upper;
 
 pub use default::default;
-pub use r#loop::r#loop;
+pub use r#loop::{Loop, Neighbors, r#loop, loop_neighbors};
 pub use lower::lower;
 pub use trim::{trim, trim_end, trim_start};
 pub use upper::upper;
@@ -8,12 +8,22 @@
 pub mod escapers;
 pub mod filters;
 
-pub use oxiplate_derive::Oxiplate;
+pub use oxiplate_derive::{Oxiplate, render};
 pub use oxiplate_traits::{
-    CowStr, CowStrWrapper, Escaper, FastCowStr, FastEscape, Render, ToCowStr, ToCowStrWrapper,
-    UnescapedText, UnescapedTextWrapper,
+    CowStr, CowStrWrapper, EscapedRender, EscapedString, Escaper, EscaperGroupWrapper, FastCowStr,
+    FastEscape, Render, RenderFn, ToCowStr, ToCowStrWrapper, TryRender, UnescapedText,
+    UnescapedTextWrapper,
 };
 
+/// Items used by code generated by the derive macro.
+/// Not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    extern crate alloc;
+
+    pub use alloc::string::String;
+}
+
 /// Default Oxiplate experience that uses only built-in filters.
 ///
 /// To use your own filters:
@@ -55,5 +65,5 @@ pub use oxiplate_traits::{
 /// # }
 /// ```
 pub mod prelude {
-    pub use super::{Oxiplate, Render, filters as filters_for_oxiplate};
+    pub use super::{Oxiplate, Render, TryRender, filters as filters_for_oxiplate};
 }
@@ -0,0 +1,13 @@
+use oxiplate::prelude::*;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "{{ value }}")]
+#[oxiplate_error(std::fmt::Error)]
+#[oxiplate_error(std::fmt::Error)]
+struct Data {
+    value: usize,
+}
+
+fn main() {
+    print!("{}", Data { value: 19 });
+}
@@ -0,0 +1,14 @@
+use oxiplate::prelude::*;
+
+struct TemplateError;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "{{ value }}")]
+#[oxiplate_error(TemplateError)]
+struct Data {
+    value: usize,
+}
+
+fn main() {
+    print!("{}", Data { value: 19 });
+}
@@ -0,0 +1,17 @@
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"<input{% if disabled %} disabled{% endif %}={{ value }}>"#)]
+struct BranchedTag {
+    disabled: bool,
+    value: &'static str,
+}
+
+pub fn main() {
+    BranchedTag {
+        disabled: false,
+        value: "1",
+    }
+    .render()
+    .unwrap();
+}
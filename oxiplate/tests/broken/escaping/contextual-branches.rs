@@ -0,0 +1,17 @@
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"{% if a %}<a title="{% endif %}{{ x }}"#)]
+struct Branches {
+    a: bool,
+    x: &'static str,
+}
+
+pub fn main() {
+    Branches {
+        a: false,
+        x: "<script>alert(1)</script>",
+    }
+    .render()
+    .unwrap();
+}
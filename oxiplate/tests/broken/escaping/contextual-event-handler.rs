@@ -0,0 +1,11 @@
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"<button onclick="greet({{ value }})">Hi</button>"#)]
+struct EventHandler {
+    value: &'static str,
+}
+
+pub fn main() {
+    EventHandler { value: "alert(1)" }.render().unwrap();
+}
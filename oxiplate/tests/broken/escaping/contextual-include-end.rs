@@ -0,0 +1,11 @@
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"{% include "open-attribute.html.oxip" %}{{ x }}">"#)]
+struct IncludeEnd {
+    x: &'static str,
+}
+
+pub fn main() {
+    IncludeEnd { x: "<script>" }.render().unwrap();
+}
@@ -0,0 +1,15 @@
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"<p title="{% include "include-value.html.oxip" with { v: x } %}"></p>"#)]
+struct Include {
+    x: &'static str,
+}
+
+pub fn main() {
+    Include {
+        x: r#"" onmouseover="alert(1)"#,
+    }
+    .render()
+    .unwrap();
+}
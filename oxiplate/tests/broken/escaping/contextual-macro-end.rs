@@ -0,0 +1,11 @@
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"{% macro open() %}<p title="{% endmacro %}{% call open() %}{{ x }}">"#)]
+struct MacroEnd {
+    x: &'static str,
+}
+
+pub fn main() {
+    MacroEnd { x: "<script>" }.render().unwrap();
+}
@@ -0,0 +1,15 @@
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"{% macro m(value) %}{{ value }}{% endmacro %}<p title="{{ m(x) }}"></p>"#)]
+struct Macro {
+    x: &'static str,
+}
+
+pub fn main() {
+    Macro {
+        x: r#"" onmouseover="alert(1)"#,
+    }
+    .render()
+    .unwrap();
+}
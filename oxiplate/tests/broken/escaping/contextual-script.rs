@@ -0,0 +1,11 @@
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"<script>const name = "{{ value }}";</script>"#)]
+struct Script {
+    value: &'static str,
+}
+
+pub fn main() {
+    Script { value: "alert(1)" }.render().unwrap();
+}
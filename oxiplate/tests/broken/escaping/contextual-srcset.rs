@@ -0,0 +1,15 @@
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"<img srcset="{{ image }} 2x">"#)]
+struct Srcset {
+    image: &'static str,
+}
+
+pub fn main() {
+    Srcset {
+        image: "javascript:alert(1)",
+    }
+    .render()
+    .unwrap();
+}
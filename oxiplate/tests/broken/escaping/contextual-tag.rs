@@ -0,0 +1,11 @@
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"<p {{ value }}>Hello</p>"#)]
+struct Tag {
+    value: &'static str,
+}
+
+pub fn main() {
+    Tag { value: "onclick=alert(1)" }.render().unwrap();
+}
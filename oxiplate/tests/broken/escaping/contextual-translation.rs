@@ -0,0 +1,18 @@
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"{{ t("unbalanced") }}{{ value }}"#)]
+#[oxiplate_locale(locale, default = "en")]
+struct Translation {
+    locale: &'static str,
+    value: &'static str,
+}
+
+pub fn main() {
+    Translation {
+        locale: "fr",
+        value: "1",
+    }
+    .render()
+    .unwrap();
+}
@@ -0,0 +1,11 @@
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"<p class={{ value }}>Hello</p>"#)]
+struct Unquoted {
+    value: &'static str,
+}
+
+pub fn main() {
+    Unquoted { value: "a onclick=alert(1)" }.render().unwrap();
+}
@@ -0,0 +1,11 @@
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"<a href="{{ value }}">Home</a>"#)]
+struct Url {
+    value: &'static str,
+}
+
+pub fn main() {
+    Url { value: "javascript:alert(1)" }.render().unwrap();
+}
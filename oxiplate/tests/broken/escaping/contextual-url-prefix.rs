@@ -0,0 +1,17 @@
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"<a href="java{{ rest }}">Home</a><a href="{{ raw: empty }}{{ rest }}">Home</a>"#)]
+struct Url {
+    rest: &'static str,
+    empty: &'static str,
+}
+
+pub fn main() {
+    Url {
+        rest: "script:alert(1)",
+        empty: "",
+    }
+    .render()
+    .unwrap();
+}
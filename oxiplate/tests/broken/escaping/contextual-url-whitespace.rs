@@ -0,0 +1,11 @@
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"<a href=" {{ url }}">Home</a>"#)]
+struct Url {
+    url: &'static str,
+}
+
+pub fn main() {
+    Url { url: "javascript:alert(1)" }.render().unwrap();
+}
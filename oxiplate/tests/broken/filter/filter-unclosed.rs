@@ -0,0 +1,9 @@
+use oxiplate::prelude::*;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "{% filter upper %}Hello world!")]
+struct Data;
+
+fn main() {
+    print!("{}", Data.render().unwrap());
+}
@@ -0,0 +1,9 @@
+use oxiplate::prelude::*;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "{% if true %}{% filter upper %}Hello world!{% endif %}{% endfilter %}")]
+struct Data;
+
+fn main() {
+    print!("{}", Data.render().unwrap());
+}
@@ -0,0 +1,18 @@
+use oxiplate::prelude::*;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"{% filter wrap(prefix, "") %}Hello world!{% endfilter %}"#)]
+struct Data {
+    prefix: &'static str,
+}
+
+fn main() {
+    print!(
+        "{}",
+        Data {
+            prefix: "<script>alert(1)</script>",
+        }
+        .render()
+        .unwrap()
+    );
+}
@@ -0,0 +1,11 @@
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(
+    html: "{% macro greeting() %}Hello world!{% endmacro %}{{ text: greeting() }}"
+)]
+struct Data;
+
+fn main() {
+    print!("{}", Data.render().unwrap());
+}
@@ -0,0 +1,5 @@
+use oxiplate::{Render, render};
+
+fn main() {
+    let _ = render!(html: "{{ name }}", "Sam").render();
+}
@@ -0,0 +1,79 @@
+#![no_std]
+
+extern crate alloc;
+
+use oxiplate::prelude::*;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"<p class="{{ attr: if is_error { "error" } else { "note" } }}">{{ message }}</p>"#)]
+struct Escaped {
+    is_error: bool,
+    message: &'static str,
+}
+
+#[test]
+fn escaped() {
+    assert_eq!(
+        Escaped {
+            is_error: true,
+            message: "1 < 2",
+        }
+        .render()
+        .unwrap(),
+        r#"<p class="error">1 &lt; 2</p>"#
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "{{ >if show_label { label } else { name } | upper }}")]
+struct Cow {
+    show_label: bool,
+    label: &'static str,
+    name: &'static str,
+}
+
+#[test]
+fn cow() {
+    assert_eq!(
+        Cow {
+            show_label: true,
+            label: "Tom & Jerry",
+            name: "tj",
+        }
+        .render()
+        .unwrap(),
+        "TOM &amp; JERRY"
+    );
+    assert_eq!(
+        Cow {
+            show_label: false,
+            label: "Tom & Jerry",
+            name: "tj",
+        }
+        .render()
+        .unwrap(),
+        "TJ"
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "{{ >if short { name } else { full_name } | >trim | upper }}")]
+struct ChainedFilters {
+    short: bool,
+    name: &'static str,
+    full_name: &'static str,
+}
+
+#[test]
+fn chained_filters() {
+    assert_eq!(
+        ChainedFilters {
+            short: false,
+            name: "Luna",
+            full_name: " Luna Lovegood ",
+        }
+        .render()
+        .unwrap(),
+        "LUNA LOVEGOOD"
+    );
+}
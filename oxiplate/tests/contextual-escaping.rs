@@ -0,0 +1,106 @@
+#![no_std]
+
+extern crate alloc;
+
+use oxiplate::prelude::*;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"<a href="/users/{{ id }}" title="{{ name }}" data-name='{{ name }}'>{{ name }}</a>
+<!-- {{ name }} -->
+<textarea name="bio">{{ name }}</textarea>
+<P CLASS="{{ name }}">{{ name }}</P>"#)]
+struct Link {
+    id: usize,
+    name: &'static str,
+}
+
+/// Writs without an escaper are escaped for the HTML context they're in.
+#[test]
+fn contexts() {
+    assert_eq!(
+        Link {
+            id: 19,
+            name: r#"<Sam> "Sammy" O'Neil -->"#,
+        }
+        .render()
+        .unwrap(),
+        r#"<a href="/users/19" title="<Sam> &#34;Sammy&#34; O&#39;Neil -->" data-name='<Sam> &#34;Sammy&#34; O&#39;Neil -->'>&lt;Sam> "Sammy" O'Neil --></a>
+<!-- ‹Sam› "Sammy" O'Neil −−› -->
+<textarea name="bio">&lt;Sam> "Sammy" O'Neil --></textarea>
+<P CLASS="<Sam> &#34;Sammy&#34; O&#39;Neil -->">&lt;Sam> "Sammy" O'Neil --></P>"#
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"<p{% if highlight %} class="highlight {{ class }}"{% endif %}>{{ class }}</p>
+<script>const ready = true;</script>
+<style>p { color: red; }</style>
+{{ class }}"#)]
+struct Conditional {
+    highlight: bool,
+    class: &'static str,
+}
+
+/// The context is tracked through statements and past raw text elements.
+#[test]
+fn conditional() {
+    assert_eq!(
+        Conditional {
+            highlight: true,
+            class: r#"a"b<c"#,
+        }
+        .render()
+        .unwrap(),
+        r#"<p class="highlight a&#34;b<c">a"b&lt;c</p>
+<script>const ready = true;</script>
+<style>p { color: red; }</style>
+a"b&lt;c"#
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"<a href="{{ raw: url }}" title="{{ text: title }}">{{ attr: title }}</a>"#)]
+struct Explicit {
+    url: &'static str,
+    title: &'static str,
+}
+
+/// Escapers that are specified are used as-is.
+#[test]
+fn explicit() {
+    assert_eq!(
+        Explicit {
+            url: "https://example.com/?a=1&b=2",
+            title: r#"<"Home">"#,
+        }
+        .render()
+        .unwrap(),
+        r#"<a href="https://example.com/?a=1&b=2" title="&lt;"Home">"><&#34;Home&#34;></a>"#
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"<input{% if disabled %} disabled{% endif %} value="{% for value in values %}{{ value }} {% endfor %}">
+{% match values.first() %}{% case Some(value) %}<b title="{{ value }}">{% case None %}<b>{% endmatch %}{{ value }}</b>"#)]
+struct Branches {
+    disabled: bool,
+    values: [&'static str; 2],
+    value: &'static str,
+}
+
+/// Branches that end at different points within a tag continue to be tracked,
+/// and other branches end in the same context they started in.
+#[test]
+fn branches() {
+    assert_eq!(
+        Branches {
+            disabled: true,
+            values: ["a\"b", "<c>"],
+            value: "<d>",
+        }
+        .render()
+        .unwrap(),
+        r#"<input disabled value="a&#34;b <c> ">
+<b title="a&#34;b">&lt;d></b>"#
+    );
+}
@@ -0,0 +1,13 @@
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "<!-- {{ attr: note }} --><p>{{ note }}</p>")]
+struct Page<'a> {
+    note: &'a str,
+}
+
+fn main() {
+    let page = Page { note: "Draft" };
+
+    assert_eq!(page.render().unwrap(), "<!-- Draft --><p>Draft</p>");
+}
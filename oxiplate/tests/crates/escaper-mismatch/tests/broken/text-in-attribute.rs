@@ -0,0 +1,13 @@
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"<a title="{{ text: title }}">{{ title }}</a>"#)]
+struct Link<'a> {
+    title: &'a str,
+}
+
+fn main() {
+    let link = Link { title: "Home" };
+
+    assert_eq!(link.render().unwrap(), r#"<a title="Home">Home</a>"#);
+}
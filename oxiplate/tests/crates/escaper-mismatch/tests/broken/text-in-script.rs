@@ -0,0 +1,13 @@
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "<script>let name = '{{ text: name }}';</script>")]
+struct Script<'a> {
+    name: &'a str,
+}
+
+fn main() {
+    let script = Script { name: "Ferris" };
+
+    assert_eq!(script.render().unwrap(), "<script>let name = 'Ferris';</script>");
+}
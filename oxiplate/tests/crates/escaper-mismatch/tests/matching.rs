@@ -0,0 +1,23 @@
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"<!-- {{ comment: note }} --><a title="{{ attr: title }}">{{ text: title }}</a>{{ raw: html }}"#)]
+struct Matching<'a> {
+    note: &'a str,
+    title: &'a str,
+    html: &'a str,
+}
+
+#[test]
+fn matching() {
+    let data = Matching {
+        note: "<!-- -->",
+        title: r#""Tom" & <Jerry>"#,
+        html: "<br>",
+    };
+
+    assert_eq!(
+        data.render().unwrap(),
+        r#"<!-- ‹ǃ−− −−› --><a title="&#34;Tom&#34; &amp; <Jerry>">"Tom" &amp; &lt;Jerry></a><br>"#
+    );
+}
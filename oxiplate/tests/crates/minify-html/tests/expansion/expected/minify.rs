@@ -0,0 +1,413 @@
+#![feature(prelude_import)]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2024::*;
+use oxiplate::prelude::*;
+#[oxiplate = "page.html.oxip"]
+struct Page {
+    title: &'static str,
+    items: [&'static str; 2],
+    notes: &'static str,
+}
+impl ::core::fmt::Display for Page {
+    fn fmt(
+        &self,
+        oxiplate_formatter: &mut ::core::fmt::Formatter<'_>,
+    ) -> ::core::fmt::Result {
+        ::oxiplate::Render::render_into(self, oxiplate_formatter)
+    }
+}
+impl ::oxiplate::EscapedRender<::oxiplate::escapers::html::HtmlEscaper> for Page {}
+impl ::oxiplate::Render for Page {
+    const ESTIMATED_LENGTH: usize = 314usize;
+    #[inline]
+    fn render_into<W: ::core::fmt::Write>(
+        &self,
+        oxiplate_formatter: &mut W,
+    ) -> ::core::fmt::Result {
+        extern crate alloc;
+        use ::core::fmt::Write as _;
+        use ::oxiplate::{ToCowStr as _, UnescapedText as _};
+        oxiplate_formatter.write_str("<!DOCTYPE html>\n<html>\n<head>\n<title>")?;
+        (&&::oxiplate::EscaperGroupWrapper::<
+            _,
+            ::oxiplate::escapers::html::HtmlEscaper,
+        >::new(&&::oxiplate::UnescapedTextWrapper::new(&(self.title))))
+            .oxiplate_escape(
+                oxiplate_formatter,
+                &<::oxiplate::escapers::html::HtmlEscaper as ::oxiplate::Escaper>::DEFAULT,
+            )?;
+        oxiplate_formatter
+            .write_str(
+                "</title>\n<style>\n            p   { color: red; }\n        </style>\n</head>\n<body>\n<h1>",
+            )?;
+        (&&::oxiplate::EscaperGroupWrapper::<
+            _,
+            ::oxiplate::escapers::html::HtmlEscaper,
+        >::new(&&::oxiplate::UnescapedTextWrapper::new(&(self.title))))
+            .oxiplate_escape(
+                oxiplate_formatter,
+                &<::oxiplate::escapers::html::HtmlEscaper as ::oxiplate::Escaper>::DEFAULT,
+            )?;
+        oxiplate_formatter
+            .write_str(
+                "</h1>\n<PRE class=\"example\">\n  keep   this\n        </PRE>\n",
+            )?;
+        for item in self.items {
+            oxiplate_formatter.write_str("\n<p>")?;
+            (&&::oxiplate::EscaperGroupWrapper::<
+                _,
+                ::oxiplate::escapers::html::HtmlEscaper,
+            >::new(&&::oxiplate::UnescapedTextWrapper::new(&(item))))
+                .oxiplate_escape(
+                    oxiplate_formatter,
+                    &<::oxiplate::escapers::html::HtmlEscaper as ::oxiplate::Escaper>::DEFAULT,
+                )?;
+            oxiplate_formatter.write_str("</p>\n")?;
+        }
+        oxiplate_formatter.write_str("\n<textarea name=\"notes\">  ")?;
+        (&&::oxiplate::EscaperGroupWrapper::<
+            _,
+            ::oxiplate::escapers::html::HtmlEscaper,
+        >::new(&&::oxiplate::UnescapedTextWrapper::new(&(self.notes))))
+            .oxiplate_escape(
+                oxiplate_formatter,
+                &<::oxiplate::escapers::html::HtmlEscaper as ::oxiplate::Escaper>::DEFAULT,
+            )?;
+        oxiplate_formatter
+            .write_str(
+                "  </textarea>\n<script>\n            let   count = 1;\n        </script>\n</body>\n</html>\n",
+            )?;
+        Ok(())
+    }
+}
+extern crate test;
+#[rustc_test_marker = "page"]
+#[doc(hidden)]
+pub const page: test::TestDescAndFn = test::TestDescAndFn {
+    desc: test::TestDesc {
+        name: test::StaticTestName("page"),
+        ignore: false,
+        ignore_message: ::core::option::Option::None,
+        source_file: "oxiplate/tests/crates/minify-html/tests/minify.rs",
+        start_line: 12usize,
+        start_col: 4usize,
+        end_line: 12usize,
+        end_col: 8usize,
+        compile_fail: false,
+        no_run: false,
+        should_panic: test::ShouldPanic::No,
+        test_type: test::TestType::IntegrationTest,
+    },
+    testfn: test::StaticTestFn(#[coverage(off)] || test::assert_test_result(page())),
+};
+#[rustc_test_entrypoint_marker]
+fn page() {
+    {
+        match (
+            &r#"<!DOCTYPE html>
+<html>
+<head>
+<title>Minified</title>
+<style>
+            p   { color: red; }
+        </style>
+</head>
+<body>
+<h1>Minified</h1>
+<PRE class="example">
+  keep   this
+        </PRE>
+
+<p>Jane &amp; Joe</p>
+
+<p>Luna</p>
+
+<textarea name="notes">  &lt;none>  </textarea>
+<script>
+            let   count = 1;
+        </script>
+</body>
+</html>
+"#,
+            &Page {
+                title: "Minified",
+                items: ["Jane & Joe", "Luna"],
+                notes: "<none>",
+            }
+                .render()
+                .unwrap(),
+        ) {
+            (left_val, right_val) => {
+                if !(*left_val == *right_val) {
+                    let kind = ::core::panicking::AssertKind::Eq;
+                    ::core::panicking::assert_failed(
+                        kind,
+                        &*left_val,
+                        &*right_val,
+                        ::core::option::Option::None,
+                    );
+                }
+            }
+        }
+    };
+}
+/// Whitespace-heavy text to compare against the minified output.
+const LIST: &str = "<ul>\n    <li>One</li>\n    <li>Two  and  three</li>\n</ul>\n";
+#[oxiplate_inline(html:"<ul>\n    <li>One</li>\n    <li>Two  and  three</li>\n</ul>\n")]
+struct List;
+impl ::core::fmt::Display for List {
+    fn fmt(
+        &self,
+        oxiplate_formatter: &mut ::core::fmt::Formatter<'_>,
+    ) -> ::core::fmt::Result {
+        ::oxiplate::Render::render_into(self, oxiplate_formatter)
+    }
+}
+impl ::oxiplate::EscapedRender<::oxiplate::escapers::html::HtmlEscaper> for List {}
+impl ::oxiplate::Render for List {
+    const ESTIMATED_LENGTH: usize = 47usize;
+    #[inline]
+    fn render_into<W: ::core::fmt::Write>(
+        &self,
+        oxiplate_formatter: &mut W,
+    ) -> ::core::fmt::Result {
+        extern crate alloc;
+        use ::core::fmt::Write as _;
+        use ::oxiplate::{ToCowStr as _, UnescapedText as _};
+        oxiplate_formatter
+            .write_str("<ul>\n<li>One</li>\n<li>Two and three</li>\n</ul>\n")?;
+        Ok(())
+    }
+}
+extern crate test;
+#[rustc_test_marker = "byte_savings"]
+#[doc(hidden)]
+pub const byte_savings: test::TestDescAndFn = test::TestDescAndFn {
+    desc: test::TestDesc {
+        name: test::StaticTestName("byte_savings"),
+        ignore: false,
+        ignore_message: ::core::option::Option::None,
+        source_file: "oxiplate/tests/crates/minify-html/tests/minify.rs",
+        start_line: 59usize,
+        start_col: 4usize,
+        end_line: 59usize,
+        end_col: 16usize,
+        compile_fail: false,
+        no_run: false,
+        should_panic: test::ShouldPanic::No,
+        test_type: test::TestType::IntegrationTest,
+    },
+    testfn: test::StaticTestFn(
+        #[coverage(off)]
+        || test::assert_test_result(byte_savings()),
+    ),
+};
+/// Static text is minified when the template is built,
+/// so the savings are reflected in the estimated length.
+#[rustc_test_entrypoint_marker]
+fn byte_savings() {
+    {
+        match (
+            &"<ul>\n<li>One</li>\n<li>Two and three</li>\n</ul>\n",
+            &List.render().unwrap(),
+        ) {
+            (left_val, right_val) => {
+                if !(*left_val == *right_val) {
+                    let kind = ::core::panicking::AssertKind::Eq;
+                    ::core::panicking::assert_failed(
+                        kind,
+                        &*left_val,
+                        &*right_val,
+                        ::core::option::Option::None,
+                    );
+                }
+            }
+        }
+    };
+    {
+        match (&10, &(LIST.len() - List::ESTIMATED_LENGTH)) {
+            (left_val, right_val) => {
+                if !(*left_val == *right_val) {
+                    let kind = ::core::panicking::AssertKind::Eq;
+                    ::core::panicking::assert_failed(
+                        kind,
+                        &*left_val,
+                        &*right_val,
+                        ::core::option::Option::None,
+                    );
+                }
+            }
+        }
+    };
+}
+#[oxiplate_inline(json:"{\n    \"name\":  {{ name }}\n}")]
+struct Json {
+    name: &'static str,
+}
+impl ::core::fmt::Display for Json {
+    fn fmt(
+        &self,
+        oxiplate_formatter: &mut ::core::fmt::Formatter<'_>,
+    ) -> ::core::fmt::Result {
+        ::oxiplate::Render::render_into(self, oxiplate_formatter)
+    }
+}
+impl ::oxiplate::EscapedRender<::oxiplate::escapers::json::JsonEscaper> for Json {}
+impl ::oxiplate::Render for Json {
+    const ESTIMATED_LENGTH: usize = 18usize;
+    #[inline]
+    fn render_into<W: ::core::fmt::Write>(
+        &self,
+        oxiplate_formatter: &mut W,
+    ) -> ::core::fmt::Result {
+        extern crate alloc;
+        use ::core::fmt::Write as _;
+        use ::oxiplate::{ToCowStr as _, UnescapedText as _};
+        oxiplate_formatter.write_str("{\n    \"name\":  ")?;
+        (&&::oxiplate::EscaperGroupWrapper::<
+            _,
+            ::oxiplate::escapers::json::JsonEscaper,
+        >::new(&&::oxiplate::UnescapedTextWrapper::new(&(self.name))))
+            .oxiplate_escape(
+                oxiplate_formatter,
+                &<::oxiplate::escapers::json::JsonEscaper as ::oxiplate::Escaper>::DEFAULT,
+            )?;
+        oxiplate_formatter.write_str("\n}")?;
+        Ok(())
+    }
+}
+extern crate test;
+#[rustc_test_marker = "json"]
+#[doc(hidden)]
+pub const json: test::TestDescAndFn = test::TestDescAndFn {
+    desc: test::TestDesc {
+        name: test::StaticTestName("json"),
+        ignore: false,
+        ignore_message: ::core::option::Option::None,
+        source_file: "oxiplate/tests/crates/minify-html/tests/minify.rs",
+        start_line: 75usize,
+        start_col: 4usize,
+        end_line: 75usize,
+        end_col: 8usize,
+        compile_fail: false,
+        no_run: false,
+        should_panic: test::ShouldPanic::No,
+        test_type: test::TestType::IntegrationTest,
+    },
+    testfn: test::StaticTestFn(#[coverage(off)] || test::assert_test_result(json())),
+};
+/// Templates that aren't escaped for HTML are left alone.
+#[rustc_test_entrypoint_marker]
+fn json() {
+    {
+        match (&"{\n    \"name\":  Luna\n}", &Json { name: "Luna" }.render().unwrap()) {
+            (left_val, right_val) => {
+                if !(*left_val == *right_val) {
+                    let kind = ::core::panicking::AssertKind::Eq;
+                    ::core::panicking::assert_failed(
+                        kind,
+                        &*left_val,
+                        &*right_val,
+                        ::core::option::Option::None,
+                    );
+                }
+            }
+        }
+    };
+}
+#[oxiplate_inline(html:r#"<input   value="a   b"
+    title='  {{ title }}  and   more '   data-pattern="[a-z]  +"
+    pattern=x   y>  <p   class="one
+    two">Done</p>"#)]
+struct Attributes {
+    title: &'static str,
+}
+impl ::core::fmt::Display for Attributes {
+    fn fmt(
+        &self,
+        oxiplate_formatter: &mut ::core::fmt::Formatter<'_>,
+    ) -> ::core::fmt::Result {
+        ::oxiplate::Render::render_into(self, oxiplate_formatter)
+    }
+}
+impl ::oxiplate::EscapedRender<::oxiplate::escapers::html::HtmlEscaper> for Attributes {}
+impl ::oxiplate::Render for Attributes {
+    const ESTIMATED_LENGTH: usize = 114usize;
+    #[inline]
+    fn render_into<W: ::core::fmt::Write>(
+        &self,
+        oxiplate_formatter: &mut W,
+    ) -> ::core::fmt::Result {
+        extern crate alloc;
+        use ::core::fmt::Write as _;
+        use ::oxiplate::{ToCowStr as _, UnescapedText as _};
+        oxiplate_formatter.write_str("<input value=\"a   b\"\ntitle=\'  ")?;
+        (&&::oxiplate::UnescapedTextWrapper::new(&(self.title)))
+            .oxiplate_escape(
+                oxiplate_formatter,
+                &::oxiplate::escapers::html::HtmlEscaper::attr,
+            )?;
+        oxiplate_formatter
+            .write_str(
+                "  and   more \' data-pattern=\"[a-z]  +\"\npattern=x y> <p class=\"one\n    two\">Done</p>",
+            )?;
+        Ok(())
+    }
+}
+extern crate test;
+#[rustc_test_marker = "attributes"]
+#[doc(hidden)]
+pub const attributes: test::TestDescAndFn = test::TestDescAndFn {
+    desc: test::TestDesc {
+        name: test::StaticTestName("attributes"),
+        ignore: false,
+        ignore_message: ::core::option::Option::None,
+        source_file: "oxiplate/tests/crates/minify-html/tests/minify.rs",
+        start_line: 94usize,
+        start_col: 4usize,
+        end_line: 94usize,
+        end_col: 14usize,
+        compile_fail: false,
+        no_run: false,
+        should_panic: test::ShouldPanic::No,
+        test_type: test::TestType::IntegrationTest,
+    },
+    testfn: test::StaticTestFn(
+        #[coverage(off)]
+        || test::assert_test_result(attributes()),
+    ),
+};
+/// Whitespace within quoted attribute values is part of the value,
+/// so it's left untouched.
+#[rustc_test_entrypoint_marker]
+fn attributes() {
+    {
+        match (
+            &r#"<input value="a   b"
+title='  Luna  and   more ' data-pattern="[a-z]  +"
+pattern=x y> <p class="one
+    two">Done</p>"#,
+            &Attributes { title: "Luna" }.render().unwrap(),
+        ) {
+            (left_val, right_val) => {
+                if !(*left_val == *right_val) {
+                    let kind = ::core::panicking::AssertKind::Eq;
+                    ::core::panicking::assert_failed(
+                        kind,
+                        &*left_val,
+                        &*right_val,
+                        ::core::option::Option::None,
+                    );
+                }
+            }
+        }
+    };
+}
+#[rustc_main]
+#[coverage(off)]
+#[doc(hidden)]
+pub fn main() -> () {
+    extern crate test;
+    test::test_main_static(&[&attributes, &byte_savings, &json, &page])
+}
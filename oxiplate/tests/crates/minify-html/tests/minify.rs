@@ -0,0 +1,102 @@
+use oxiplate::prelude::*;
+
+#[derive(Oxiplate)]
+#[oxiplate = "page.html.oxip"]
+struct Page {
+    title: &'static str,
+    items: [&'static str; 2],
+    notes: &'static str,
+}
+
+#[test]
+fn page() {
+    assert_eq!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<title>Minified</title>
+<style>
+            p   { color: red; }
+        </style>
+</head>
+<body>
+<h1>Minified</h1>
+<PRE class="example">
+  keep   this
+        </PRE>
+
+<p>Jane &amp; Joe</p>
+
+<p>Luna</p>
+
+<textarea name="notes">  &lt;none>  </textarea>
+<script>
+            let   count = 1;
+        </script>
+</body>
+</html>
+"#,
+        Page {
+            title: "Minified",
+            items: ["Jane & Joe", "Luna"],
+            notes: "<none>",
+        }
+        .render()
+        .unwrap()
+    );
+}
+
+/// Whitespace-heavy text to compare against the minified output.
+const LIST: &str = "<ul>\n    <li>One</li>\n    <li>Two  and  three</li>\n</ul>\n";
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "<ul>\n    <li>One</li>\n    <li>Two  and  three</li>\n</ul>\n")]
+struct List;
+
+/// Static text is minified when the template is built,
+/// so the savings are reflected in the estimated length.
+#[test]
+fn byte_savings() {
+    assert_eq!(
+        "<ul>\n<li>One</li>\n<li>Two and three</li>\n</ul>\n",
+        List.render().unwrap()
+    );
+    assert_eq!(10, LIST.len() - List::ESTIMATED_LENGTH);
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(json: "{\n    \"name\":  {{ name }}\n}")]
+struct Json {
+    name: &'static str,
+}
+
+/// Templates that aren't escaped for HTML are left alone.
+#[test]
+fn json() {
+    assert_eq!(
+        "{\n    \"name\":  Luna\n}",
+        Json { name: "Luna" }.render().unwrap()
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"<input   value="a   b"
+    title='  {{ title }}  and   more '   data-pattern="[a-z]  +"
+    pattern=x   y>  <p   class="one
+    two">Done</p>"#)]
+struct Attributes {
+    title: &'static str,
+}
+
+/// Whitespace within quoted attribute values is part of the value,
+/// so it's left untouched.
+#[test]
+fn attributes() {
+    assert_eq!(
+        r#"<input value="a   b"
+title='  Luna  and   more ' data-pattern="[a-z]  +"
+pattern=x y> <p class="one
+    two">Done</p>"#,
+        Attributes { title: "Luna" }.render().unwrap()
+    );
+}
@@ -0,0 +1,96 @@
+use oxiplate::prelude::*;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline("{# Comment #}{% if show %}<p>{$ raw: name $}</p>{% endif %}")]
+struct Inline {
+    show: bool,
+    name: &'static str,
+}
+
+#[test]
+fn inline() {
+    assert_eq!(
+        "<p>Jane & Joe</p>",
+        Inline {
+            show: true,
+            name: "Jane & Joe",
+        }
+        .render()
+        .unwrap()
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "<p>\n    {$- name -$}\n</p>{-}\n{$ name _$}\n\n!")]
+struct Whitespace {
+    name: &'static str,
+}
+
+#[test]
+fn whitespace() {
+    assert_eq!(
+        "<p>Jane</p>Jane !",
+        Whitespace { name: "Jane" }.render().unwrap()
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "<# Comment #><% if show %><p>{$ name $}</p><% endif %>")]
+struct InlineGroup {
+    show: bool,
+    name: &'static str,
+}
+
+#[test]
+fn inline_escaper_group() {
+    assert_eq!(
+        "<p>Jane &amp; Joe</p>",
+        InlineGroup {
+            show: true,
+            name: "Jane & Joe",
+        }
+        .render()
+        .unwrap()
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate = "page.html.oxip"]
+struct Page {
+    show: bool,
+    name: &'static str,
+}
+
+#[test]
+fn escaper_group() {
+    assert_eq!(
+        "\n<p>Jane &amp; Joe</p>\n",
+        Page {
+            show: true,
+            name: "Jane & Joe",
+        }
+        .render()
+        .unwrap()
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_syntax(writ = ["[[", "]]"])]
+#[oxiplate_inline(html: "<% if show %><p>[[ name ]]</p><% endif %>")]
+struct Attribute {
+    show: bool,
+    name: &'static str,
+}
+
+#[test]
+fn attribute() {
+    assert_eq!(
+        "<p>Jane &amp; Joe</p>",
+        Attribute {
+            show: true,
+            name: "Jane & Joe",
+        }
+        .render()
+        .unwrap()
+    );
+}
@@ -0,0 +1,109 @@
+use oxiplate::prelude::*;
+
+#[derive(Oxiplate)]
+#[oxiplate = "list.html.oxip"]
+struct List {
+    items: [&'static str; 2],
+}
+
+#[test]
+fn external() {
+    assert_eq!(
+        "<ul>\n    <li>Jane &amp; Joe</li>\n    <li>Luna</li>\n</ul>\n",
+        List {
+            items: ["Jane & Joe", "Luna"]
+        }
+        .render()
+        .unwrap()
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "
+    {% if show %}
+        <p>{{ name }}</p>
+    {% endif %}
+    <p>{{ name }}</p>
+")]
+struct Inline {
+    show: bool,
+    name: &'static str,
+}
+
+#[test]
+fn inline() {
+    assert_eq!(
+        "\n        <p>Luna</p>\n    <p>Luna</p>\n",
+        Inline {
+            show: true,
+            name: "Luna",
+        }
+        .render()
+        .unwrap()
+    );
+}
+
+/// Indentation is only removed before tags that start a line,
+/// and writs are left alone.
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "{% if show %}\n<p>{{ name }} {% if show %}!{% endif %}</p>\n    {{ name }}\n{% endif %}")]
+struct MidLine {
+    show: bool,
+    name: &'static str,
+}
+
+#[test]
+fn mid_line() {
+    assert_eq!(
+        "<p>Luna !</p>\n    Luna\n",
+        MidLine {
+            show: true,
+            name: "Luna",
+        }
+        .render()
+        .unwrap()
+    );
+}
+
+/// `+` keeps the whitespace around a tag as-is,
+/// while `-` and `_` still work like they usually do.
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "<p>\n    {%+ if show +%}\n    {{ name }}\n    {%- endif _%}\n</p>")]
+struct Explicit {
+    show: bool,
+    name: &'static str,
+}
+
+#[test]
+fn explicit() {
+    assert_eq!(
+        "<p>\n    \n    Luna </p>",
+        Explicit {
+            show: true,
+            name: "Luna",
+        }
+        .render()
+        .unwrap()
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_syntax(trim_blocks = false, lstrip_blocks = false)]
+#[oxiplate_inline(html: "<p>\n    {% if show %}\n    {{ name }}\n    {% endif %}\n</p>")]
+struct Attribute {
+    show: bool,
+    name: &'static str,
+}
+
+#[test]
+fn attribute() {
+    assert_eq!(
+        "<p>\n    \n    Luna\n    \n</p>",
+        Attribute {
+            show: true,
+            name: "Luna",
+        }
+        .render()
+        .unwrap()
+    );
+}
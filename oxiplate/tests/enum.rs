@@ -0,0 +1,34 @@
+#![no_std]
+
+extern crate alloc;
+
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+enum Message {
+    #[oxiplate_inline(html: "<p>{{ text }}</p>")]
+    Html { text: &'static str },
+
+    #[oxiplate_inline(json: r#"{"text":"{{ text }}"}"#)]
+    Json { text: &'static str },
+}
+
+#[test]
+fn escaper_per_variant() -> Result<(), ::core::fmt::Error> {
+    assert_eq!(
+        Message::Html {
+            text: r#"<b>"hi"</b>"#,
+        }
+        .render()?,
+        r#"<p>&lt;b>"hi"&lt;/b></p>"#
+    );
+    assert_eq!(
+        Message::Json {
+            text: r#"<b>"hi"</b>"#,
+        }
+        .render()?,
+        r#"{"text":"<b>\"hi\"</b>"}"#
+    );
+
+    Ok(())
+}
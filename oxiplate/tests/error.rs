@@ -0,0 +1,126 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
+
+use oxiplate::prelude::*;
+
+#[derive(Debug, PartialEq)]
+enum TemplateError {
+    Format(fmt::Error),
+    MissingName(&'static str),
+}
+
+impl From<fmt::Error> for TemplateError {
+    fn from(error: fmt::Error) -> Self {
+        Self::Format(error)
+    }
+}
+
+struct MissingName;
+
+impl From<MissingName> for TemplateError {
+    fn from(_error: MissingName) -> Self {
+        Self::MissingName("User has no name")
+    }
+}
+
+struct User {
+    name: Option<&'static str>,
+}
+
+impl User {
+    fn name(&self) -> Result<&'static str, MissingName> {
+        self.name.ok_or(MissingName)
+    }
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "<p>Hello, {{ user.name()? }}!</p>")]
+#[oxiplate_error(TemplateError)]
+struct Greeting {
+    user: User,
+}
+
+#[test]
+fn ok() {
+    let greeting = Greeting {
+        user: User {
+            name: Some("<Luna>"),
+        },
+    };
+    assert_eq!(
+        greeting.render(),
+        Ok(String::from("<p>Hello, &lt;Luna>!</p>"))
+    );
+    assert_eq!(format!("{greeting}"), "<p>Hello, &lt;Luna>!</p>");
+}
+
+#[test]
+fn err() {
+    let greeting = Greeting {
+        user: User { name: None },
+    };
+    assert_eq!(
+        greeting.render(),
+        Err(TemplateError::MissingName("User has no name"))
+    );
+
+    let mut output = String::new();
+    assert_eq!(
+        greeting.render_into(&mut output),
+        Err(TemplateError::MissingName("User has no name"))
+    );
+    assert_eq!(output, "<p>Hello, ");
+}
+
+struct FailingWriter;
+
+impl fmt::Write for FailingWriter {
+    fn write_str(&mut self, _string: &str) -> fmt::Result {
+        Err(fmt::Error)
+    }
+}
+
+#[test]
+fn writer_error() {
+    let greeting = Greeting {
+        user: User { name: Some("Luna") },
+    };
+    assert_eq!(
+        greeting.render_into(&mut FailingWriter),
+        Err(TemplateError::Format(fmt::Error))
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"{% include "fallible.html.oxip" %}"#)]
+#[oxiplate_inline(name = "text", "{{ raw: user.name()? }}")]
+#[oxiplate_error(TemplateError)]
+struct Included {
+    user: User,
+}
+
+#[test]
+fn included() {
+    let included = Included {
+        user: User { name: None },
+    };
+    assert_eq!(
+        included.render(),
+        Err(TemplateError::MissingName("User has no name"))
+    );
+    assert_eq!(
+        included.render_text(),
+        Err(TemplateError::MissingName("User has no name"))
+    );
+
+    let included = Included {
+        user: User { name: Some("Luna") },
+    };
+    assert_eq!(included.render(), Ok(String::from("<p>Luna</p>\n")));
+    assert_eq!(included.render_text(), Ok(String::from("Luna")));
+}
@@ -122,3 +122,22 @@ Hello world &lt;<script><!--
 "
     );
 }
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "<pre>\n    {{ indent: code }}\n</pre>")]
+struct Indent {
+    code: &'static str,
+}
+
+/// Values are escaped before being indented.
+#[test]
+fn indent() {
+    assert_eq!(
+        "<pre>\n    if a &lt; b {\n        a\n    }\n</pre>",
+        Indent {
+            code: "if a < b {\n    a\n}"
+        }
+        .render()
+        .unwrap()
+    );
+}
@@ -0,0 +1,362 @@
+#![feature(prelude_import)]
+#![no_std]
+extern crate core;
+#[prelude_import]
+use core::prelude::rust_2024::*;
+extern crate alloc;
+use oxiplate::prelude::*;
+#[oxiplate_inline(
+    html:r#"<a href="/users/{{ id }}" title="{{ name }}" data-name='{{ name }}'>{{ name }}</a>
+<!-- {{ name }} -->
+<textarea name="bio">{{ name }}</textarea>
+<P CLASS="{{ name }}">{{ name }}</P>"#
+)]
+struct Link {
+    id: usize,
+    name: &'static str,
+}
+impl ::core::fmt::Display for Link {
+    fn fmt(
+        &self,
+        oxiplate_formatter: &mut ::core::fmt::Formatter<'_>,
+    ) -> ::core::fmt::Result {
+        ::oxiplate::Render::render_into(self, oxiplate_formatter)
+    }
+}
+impl ::oxiplate::EscapedRender<::oxiplate::escapers::html::HtmlEscaper> for Link {}
+impl ::oxiplate::Render for Link {
+    const ESTIMATED_LENGTH: usize = 112usize;
+    #[inline]
+    fn render_into<W: ::core::fmt::Write>(
+        &self,
+        oxiplate_formatter: &mut W,
+    ) -> ::core::fmt::Result {
+        extern crate alloc;
+        use ::core::fmt::Write as _;
+        use ::oxiplate::{ToCowStr as _, UnescapedText as _};
+        oxiplate_formatter.write_str("<a href=\"/users/")?;
+        (&&::oxiplate::UnescapedTextWrapper::new(&(self.id)))
+            .oxiplate_escape(
+                oxiplate_formatter,
+                &::oxiplate::escapers::html::HtmlEscaper::attr,
+            )?;
+        oxiplate_formatter.write_str("\" title=\"")?;
+        (&&::oxiplate::UnescapedTextWrapper::new(&(self.name)))
+            .oxiplate_escape(
+                oxiplate_formatter,
+                &::oxiplate::escapers::html::HtmlEscaper::attr,
+            )?;
+        oxiplate_formatter.write_str("\" data-name=\'")?;
+        (&&::oxiplate::UnescapedTextWrapper::new(&(self.name)))
+            .oxiplate_escape(
+                oxiplate_formatter,
+                &::oxiplate::escapers::html::HtmlEscaper::attr,
+            )?;
+        oxiplate_formatter.write_str("\'>")?;
+        (&&::oxiplate::EscaperGroupWrapper::<
+            _,
+            ::oxiplate::escapers::html::HtmlEscaper,
+        >::new(&&::oxiplate::UnescapedTextWrapper::new(&(self.name))))
+            .oxiplate_escape(
+                oxiplate_formatter,
+                &<::oxiplate::escapers::html::HtmlEscaper as ::oxiplate::Escaper>::DEFAULT,
+            )?;
+        oxiplate_formatter.write_str("</a>\n<!-- ")?;
+        (&&::oxiplate::UnescapedTextWrapper::new(&(self.name)))
+            .oxiplate_escape(
+                oxiplate_formatter,
+                &::oxiplate::escapers::html::HtmlEscaper::comment,
+            )?;
+        oxiplate_formatter.write_str(" -->\n<textarea name=\"bio\">")?;
+        (&&::oxiplate::EscaperGroupWrapper::<
+            _,
+            ::oxiplate::escapers::html::HtmlEscaper,
+        >::new(&&::oxiplate::UnescapedTextWrapper::new(&(self.name))))
+            .oxiplate_escape(
+                oxiplate_formatter,
+                &<::oxiplate::escapers::html::HtmlEscaper as ::oxiplate::Escaper>::DEFAULT,
+            )?;
+        oxiplate_formatter.write_str("</textarea>\n<P CLASS=\"")?;
+        (&&::oxiplate::UnescapedTextWrapper::new(&(self.name)))
+            .oxiplate_escape(
+                oxiplate_formatter,
+                &::oxiplate::escapers::html::HtmlEscaper::attr,
+            )?;
+        oxiplate_formatter.write_str("\">")?;
+        (&&::oxiplate::EscaperGroupWrapper::<
+            _,
+            ::oxiplate::escapers::html::HtmlEscaper,
+        >::new(&&::oxiplate::UnescapedTextWrapper::new(&(self.name))))
+            .oxiplate_escape(
+                oxiplate_formatter,
+                &<::oxiplate::escapers::html::HtmlEscaper as ::oxiplate::Escaper>::DEFAULT,
+            )?;
+        oxiplate_formatter.write_str("</P>")?;
+        Ok(())
+    }
+}
+extern crate test;
+#[rustc_test_marker = "contexts"]
+#[doc(hidden)]
+pub const contexts: test::TestDescAndFn = test::TestDescAndFn {
+    desc: test::TestDesc {
+        name: test::StaticTestName("contexts"),
+        ignore: false,
+        ignore_message: ::core::option::Option::None,
+        source_file: "oxiplate/tests/contextual-escaping.rs",
+        start_line: 19usize,
+        start_col: 4usize,
+        end_line: 19usize,
+        end_col: 12usize,
+        compile_fail: false,
+        no_run: false,
+        should_panic: test::ShouldPanic::No,
+        test_type: test::TestType::IntegrationTest,
+    },
+    testfn: test::StaticTestFn(#[coverage(off)] || test::assert_test_result(contexts())),
+};
+/// Writs without an escaper are escaped for the HTML context they're in.
+#[rustc_test_entrypoint_marker]
+fn contexts() {
+    {
+        match (
+            &Link {
+                id: 19,
+                name: r#"<Sam> "Sammy" O'Neil -->"#,
+            }
+                .render()
+                .unwrap(),
+            &r#"<a href="/users/19" title="<Sam> &#34;Sammy&#34; O&#39;Neil -->" data-name='<Sam> &#34;Sammy&#34; O&#39;Neil -->'>&lt;Sam> "Sammy" O'Neil --></a>
+<!-- ‹Sam› "Sammy" O'Neil −−› -->
+<textarea name="bio">&lt;Sam> "Sammy" O'Neil --></textarea>
+<P CLASS="<Sam> &#34;Sammy&#34; O&#39;Neil -->">&lt;Sam> "Sammy" O'Neil --></P>"#,
+        ) {
+            (left_val, right_val) => {
+                if !(*left_val == *right_val) {
+                    let kind = ::core::panicking::AssertKind::Eq;
+                    ::core::panicking::assert_failed(
+                        kind,
+                        &*left_val,
+                        &*right_val,
+                        ::core::option::Option::None,
+                    );
+                }
+            }
+        }
+    };
+}
+#[oxiplate_inline(
+    html:r#"<p{% if highlight %} class="highlight {{ class }}"{% endif %}>{{ class }}</p>
+<script>const ready = true;</script>
+<style>p { color: red; }</style>
+{{ class }}"#
+)]
+struct Conditional {
+    highlight: bool,
+    class: &'static str,
+}
+impl ::core::fmt::Display for Conditional {
+    fn fmt(
+        &self,
+        oxiplate_formatter: &mut ::core::fmt::Formatter<'_>,
+    ) -> ::core::fmt::Result {
+        ::oxiplate::Render::render_into(self, oxiplate_formatter)
+    }
+}
+impl ::oxiplate::EscapedRender<::oxiplate::escapers::html::HtmlEscaper> for Conditional {}
+impl ::oxiplate::Render for Conditional {
+    const ESTIMATED_LENGTH: usize = 100usize;
+    #[inline]
+    fn render_into<W: ::core::fmt::Write>(
+        &self,
+        oxiplate_formatter: &mut W,
+    ) -> ::core::fmt::Result {
+        extern crate alloc;
+        use ::core::fmt::Write as _;
+        use ::oxiplate::{ToCowStr as _, UnescapedText as _};
+        oxiplate_formatter.write_str("<p")?;
+        if self.highlight {
+            oxiplate_formatter.write_str(" class=\"highlight ")?;
+            (&&::oxiplate::UnescapedTextWrapper::new(&(self.class)))
+                .oxiplate_escape(
+                    oxiplate_formatter,
+                    &::oxiplate::escapers::html::HtmlEscaper::attr,
+                )?;
+            oxiplate_formatter.write_str("\"")?;
+        }
+        oxiplate_formatter.write_str(">")?;
+        (&&::oxiplate::EscaperGroupWrapper::<
+            _,
+            ::oxiplate::escapers::html::HtmlEscaper,
+        >::new(&&::oxiplate::UnescapedTextWrapper::new(&(self.class))))
+            .oxiplate_escape(
+                oxiplate_formatter,
+                &<::oxiplate::escapers::html::HtmlEscaper as ::oxiplate::Escaper>::DEFAULT,
+            )?;
+        oxiplate_formatter
+            .write_str(
+                "</p>\n<script>const ready = true;</script>\n<style>p { color: red; }</style>\n",
+            )?;
+        (&&::oxiplate::EscaperGroupWrapper::<
+            _,
+            ::oxiplate::escapers::html::HtmlEscaper,
+        >::new(&&::oxiplate::UnescapedTextWrapper::new(&(self.class))))
+            .oxiplate_escape(
+                oxiplate_formatter,
+                &<::oxiplate::escapers::html::HtmlEscaper as ::oxiplate::Escaper>::DEFAULT,
+            )?;
+        Ok(())
+    }
+}
+extern crate test;
+#[rustc_test_marker = "conditional"]
+#[doc(hidden)]
+pub const conditional: test::TestDescAndFn = test::TestDescAndFn {
+    desc: test::TestDesc {
+        name: test::StaticTestName("conditional"),
+        ignore: false,
+        ignore_message: ::core::option::Option::None,
+        source_file: "oxiplate/tests/contextual-escaping.rs",
+        start_line: 46usize,
+        start_col: 4usize,
+        end_line: 46usize,
+        end_col: 15usize,
+        compile_fail: false,
+        no_run: false,
+        should_panic: test::ShouldPanic::No,
+        test_type: test::TestType::IntegrationTest,
+    },
+    testfn: test::StaticTestFn(
+        #[coverage(off)]
+        || test::assert_test_result(conditional()),
+    ),
+};
+/// The context is tracked through statements and past raw text elements.
+#[rustc_test_entrypoint_marker]
+fn conditional() {
+    {
+        match (
+            &Conditional {
+                highlight: true,
+                class: r#"a"b<c"#,
+            }
+                .render()
+                .unwrap(),
+            &r#"<p class="highlight a&#34;b<c">a"b&lt;c</p>
+<script>const ready = true;</script>
+<style>p { color: red; }</style>
+a"b&lt;c"#,
+        ) {
+            (left_val, right_val) => {
+                if !(*left_val == *right_val) {
+                    let kind = ::core::panicking::AssertKind::Eq;
+                    ::core::panicking::assert_failed(
+                        kind,
+                        &*left_val,
+                        &*right_val,
+                        ::core::option::Option::None,
+                    );
+                }
+            }
+        }
+    };
+}
+#[oxiplate_inline(
+    html:r#"<a href="{{ raw: url }}" title="{{ text: title }}">{{ attr: title }}</a>"#
+)]
+struct Explicit {
+    url: &'static str,
+    title: &'static str,
+}
+impl ::core::fmt::Display for Explicit {
+    fn fmt(
+        &self,
+        oxiplate_formatter: &mut ::core::fmt::Formatter<'_>,
+    ) -> ::core::fmt::Result {
+        ::oxiplate::Render::render_into(self, oxiplate_formatter)
+    }
+}
+impl ::oxiplate::EscapedRender<::oxiplate::escapers::html::HtmlEscaper> for Explicit {}
+impl ::oxiplate::Render for Explicit {
+    const ESTIMATED_LENGTH: usize = 27usize;
+    #[inline]
+    fn render_into<W: ::core::fmt::Write>(
+        &self,
+        oxiplate_formatter: &mut W,
+    ) -> ::core::fmt::Result {
+        extern crate alloc;
+        use ::core::fmt::Write as _;
+        use ::oxiplate::{ToCowStr as _, UnescapedText as _};
+        oxiplate_formatter.write_str("<a href=\"")?;
+        (&&::oxiplate::UnescapedTextWrapper::new(&(self.url)))
+            .oxiplate_raw(oxiplate_formatter)?;
+        oxiplate_formatter.write_str("\" title=\"")?;
+        (&&::oxiplate::UnescapedTextWrapper::new(&(self.title)))
+            .oxiplate_escape(
+                oxiplate_formatter,
+                &::oxiplate::escapers::html::HtmlEscaper::text,
+            )?;
+        oxiplate_formatter.write_str("\">")?;
+        (&&::oxiplate::UnescapedTextWrapper::new(&(self.title)))
+            .oxiplate_escape(
+                oxiplate_formatter,
+                &::oxiplate::escapers::html::HtmlEscaper::attr,
+            )?;
+        oxiplate_formatter.write_str("</a>")?;
+        Ok(())
+    }
+}
+extern crate test;
+#[rustc_test_marker = "explicit"]
+#[doc(hidden)]
+pub const explicit: test::TestDescAndFn = test::TestDescAndFn {
+    desc: test::TestDesc {
+        name: test::StaticTestName("explicit"),
+        ignore: false,
+        ignore_message: ::core::option::Option::None,
+        source_file: "oxiplate/tests/contextual-escaping.rs",
+        start_line: 70usize,
+        start_col: 4usize,
+        end_line: 70usize,
+        end_col: 12usize,
+        compile_fail: false,
+        no_run: false,
+        should_panic: test::ShouldPanic::No,
+        test_type: test::TestType::IntegrationTest,
+    },
+    testfn: test::StaticTestFn(#[coverage(off)] || test::assert_test_result(explicit())),
+};
+/// Escapers that are specified are used as-is.
+#[rustc_test_entrypoint_marker]
+fn explicit() {
+    {
+        match (
+            &Explicit {
+                url: "https://example.com/?a=1&b=2",
+                title: r#"<"Home">"#,
+            }
+                .render()
+                .unwrap(),
+            &r#"<a href="https://example.com/?a=1&b=2" title="&lt;"Home">"><&#34;Home&#34;></a>"#,
+        ) {
+            (left_val, right_val) => {
+                if !(*left_val == *right_val) {
+                    let kind = ::core::panicking::AssertKind::Eq;
+                    ::core::panicking::assert_failed(
+                        kind,
+                        &*left_val,
+                        &*right_val,
+                        ::core::option::Option::None,
+                    );
+                }
+            }
+        }
+    };
+}
+#[rustc_main]
+#[coverage(off)]
+#[doc(hidden)]
+pub fn main() -> () {
+    extern crate test;
+    test::test_main_static(&[&conditional, &contexts, &explicit])
+}
@@ -0,0 +1,101 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+
+use oxiplate::{Oxiplate, Render};
+
+mod filters_for_oxiplate {
+    use alloc::borrow::Cow;
+    use alloc::format;
+
+    use oxiplate::CowStr;
+    pub use oxiplate::filters::*;
+
+    pub fn wrap<'a, E: CowStr<'a>>(expression: E, before: &str, after: &str) -> Cow<'a, str> {
+        format!("{before}{}{after}", expression.cow_str()).into()
+    }
+
+    pub fn truncate<'a, E: CowStr<'a>>(expression: E, length: usize) -> Cow<'a, str> {
+        match expression.cow_str() {
+            Cow::Borrowed(string) => Cow::Borrowed(&string[..length.min(string.len())]),
+            Cow::Owned(string) => Cow::Owned(string[..length.min(string.len())].into()),
+        }
+    }
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "{% filter upper %}Hello, {{ name }}!{% endfilter %}")]
+struct Upper {
+    name: &'static str,
+}
+
+#[test]
+fn upper() {
+    assert_eq!(
+        format!(
+            "{}",
+            Upper {
+                name: "Tom & Jerry"
+            }
+        ),
+        "HELLO, TOM &AMP; JERRY!"
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "<p>{% filter >trim | lower %}
+    {{ greeting }}
+{% endfilter %}</p>")]
+struct Chained {
+    greeting: &'static str,
+}
+
+#[test]
+fn chained() {
+    assert_eq!(
+        format!(
+            "{}",
+            Chained {
+                greeting: "Hello <World>"
+            }
+        ),
+        "<p>hello &lt;world></p>"
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"{% filter >trim | wrap("[", "]") %} {{ value }} {% endfilter %}"#)]
+struct Arguments {
+    value: usize,
+}
+
+#[test]
+fn arguments() {
+    assert_eq!(format!("{}", Arguments { value: 19 }), "[19]");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "{% filter upper %}{% for name in &names %}{{ name }} {% endfor %}{% endfilter %}")]
+struct Loop {
+    names: [&'static str; 2],
+}
+
+#[test]
+fn loop_contents() {
+    assert_eq!(Loop { names: ["a", "b"] }.render().unwrap(), "A B ");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "{% filter truncate(12) %}{{ name }}{% endfilter %}")]
+struct Escaped {
+    name: &'static str,
+}
+
+/// Filters run on the escaped contents,
+/// so they can cut escaped entities in half.
+#[test]
+fn escaped() {
+    assert_eq!(format!("{}", Escaped { name: "Tom & <3" }), "Tom &amp; &l");
+}
@@ -0,0 +1,32 @@
+#![no_std]
+
+extern crate alloc;
+
+use core::fmt::Display;
+
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "<p>{{ name }}</p>")]
+struct Child<'a> {
+    name: &'a str,
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "<main>{{ raw: content }}</main>{% for line in lines %}{{ line }}{% endfor %}")]
+struct Page<T: Render + Display, const N: usize> {
+    content: T,
+    lines: [&'static str; N],
+}
+
+#[test]
+fn render_bound_and_const() -> Result<(), ::core::fmt::Error> {
+    let page = Page {
+        content: Child { name: "<Sam>" },
+        lines: ["a", "&", "b"],
+    };
+
+    assert_eq!(page.render()?, "<main><p>&lt;Sam></p></main>a&amp;b");
+
+    Ok(())
+}
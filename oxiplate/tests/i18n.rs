@@ -0,0 +1,124 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+
+use oxiplate::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "<p>{% trans %}Hello {{ name }}{% endtrans %}</p>")]
+#[oxiplate_locale(locale, default = "en")]
+struct Trans {
+    locale: &'static str,
+    name: &'static str,
+}
+
+#[test]
+fn trans() {
+    let data = Trans {
+        locale: "fr",
+        name: "<Marie>",
+    };
+    assert_eq!(format!("{data}"), "<p>Bonjour &lt;Marie></p>");
+
+    let data = Trans {
+        locale: "en",
+        name: "<Mary>",
+    };
+    assert_eq!(format!("{data}"), "<p>Hello &lt;Mary></p>");
+}
+
+/// Locales without a catalog fall back to the default locale.
+#[test]
+fn fallback() {
+    let data = Trans {
+        locale: "de",
+        name: "Marie",
+    };
+
+    assert_eq!(format!("{data}"), "<p>Hello Marie</p>");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"<p>{{ t("welcome", name = user.name, count = user.messages) }}</p>"#)]
+#[oxiplate_locale(locale, default = "en")]
+struct Function {
+    locale: alloc::string::String,
+    user: User,
+}
+
+struct User {
+    name: &'static str,
+    messages: usize,
+}
+
+#[test]
+fn function() {
+    let data = Function {
+        locale: "fr".into(),
+        user: User {
+            name: "Jean & Marie",
+            messages: 3,
+        },
+    };
+
+    assert_eq!(
+        format!("{data}"),
+        "<p>Bienvenue, Jean &amp; Marie ! Vous avez 3 nouveaux messages.</p>"
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"
+{%- for locale in locales -%}
+    <p lang="{{ attr: locale }}">{% trans %}Use { and } for braces{% endtrans %}</p>
+{%- endfor %}"#)]
+#[oxiplate_locale(locale, default = "en")]
+struct Braces {
+    locales: [&'static str; 2],
+}
+
+/// Braces in the template are escaped in the message ID,
+/// and the locale can come from a local variable.
+#[test]
+fn braces() {
+    let data = Braces {
+        locales: ["en", "fr"],
+    };
+
+    assert_eq!(
+        format!("{data}"),
+        r#"<p lang="en">Use { and } for braces</p><p lang="fr">Utilisez { et } pour les accolades</p>"#
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"{% trans %}<a title="{{ name }}">Hi</a>{% endtrans %}"#)]
+#[oxiplate_locale(locale, default = "en")]
+struct Markup {
+    locale: &'static str,
+    name: &'static str,
+}
+
+/// Placeholders are escaped for where each translation puts them.
+#[test]
+fn markup() {
+    let data = Markup {
+        locale: "en",
+        name: r#"" onmouseover="alert(1)"#,
+    };
+    assert_eq!(
+        format!("{data}"),
+        r#"<a title="&#34; onmouseover=&#34;alert(1)">Hi</a>"#
+    );
+
+    let data = Markup {
+        locale: "fr",
+        name: r#"<" onmouseover="alert(1)"#,
+    };
+    assert_eq!(
+        format!("{data}"),
+        r#"<a title="Salut">&lt;" onmouseover="alert(1)</a>"#
+    );
+}
@@ -0,0 +1,49 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+
+use oxiplate::Oxiplate;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"
+{%- import "macros.html.oxip" as ui -%}
+<nav>{{ ui.link(label, href) }}</nav>"#)]
+struct Namespace {
+    label: &'static str,
+    href: &'static str,
+}
+
+#[test]
+fn namespace() {
+    let data = Namespace {
+        label: "<Home>",
+        href: r#"/?a="b""#,
+    };
+
+    assert_eq!(
+        format!("{data}"),
+        r#"<nav><a href="/?a=&#34;b&#34;">&lt;Home></a></nav>"#
+    );
+}
+
+/// Macros are escaped based on the template they're defined in,
+/// rather than the one importing them.
+#[derive(Oxiplate)]
+#[oxiplate_inline(json: r#"
+{%- from "macros.html.oxip" import link -%}
+{"link":"{% call link(label, "/") %}","label":"{{ label }}"}"#)]
+struct Names {
+    label: &'static str,
+}
+
+#[test]
+fn names() {
+    let data = Names { label: "<Home>" };
+
+    assert_eq!(
+        format!("{data}"),
+        r#"{"link":"<a href="/">&lt;Home></a>","label":"<Home>"}"#
+    );
+}
@@ -46,3 +46,42 @@ fn include_deep() {
         "<h1>Oxiplate Example</h1>\n<p>foo</p>\n\n<p>Hello world!</p>\n"
     );
 }
+
+struct User {
+    name: &'static str,
+    url: &'static str,
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"
+{%- for user in &users -%}
+    {% include "profile-link.html.oxip" with { name: user.name, url: user.url } only %}
+{%- endfor %}"#)]
+struct IncludeWithOnly {
+    users: [User; 2],
+}
+
+#[test]
+fn include_with_only() {
+    let data = IncludeWithOnly {
+        users: [
+            User {
+                name: "Tom & Jerry",
+                url: "/tom-and-jerry",
+            },
+            User {
+                name: "<Sam>",
+                url: "/sam",
+            },
+        ],
+    };
+
+    assert_eq!(
+        format!("{}", data),
+        r#"<!-- Profile link for Tom & Jerry -->
+<a href="/tom-and-jerry">Tom &amp; Jerry</a>
+<!-- Profile link for <Sam> -->
+<a href="/sam">&lt;Sam></a>
+"#
+    );
+}
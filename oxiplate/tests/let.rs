@@ -0,0 +1,41 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+
+use oxiplate::prelude::*;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"
+{%- let title %}{{ name }} & Co.{% endlet -%}
+<title>{{ title }}</title>
+<meta content="{{ attr: title }}">
+<h1>{{ >title | upper }}</h1>"#)]
+struct Capture {
+    name: &'static str,
+}
+
+#[test]
+fn capture() {
+    assert_eq!(
+        Capture { name: "<Sam>" }.render().unwrap(),
+        r#"<title>&lt;Sam> & Co.</title>
+<meta content="&amp;lt;Sam> &amp; Co.">
+<h1>&amp;LT;SAM> &amp; CO.</h1>"#
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"
+{%- let section %}{{ name }}{% endlet -%}
+{% if section == "Home" %}<b>{{ section }}</b>{% else %}{{ section }}{% endif %}"#)]
+struct Condition {
+    name: &'static str,
+}
+
+#[test]
+fn condition() {
+    assert_eq!(format!("{}", Condition { name: "Home" }), "<b>Home</b>");
+    assert_eq!(format!("{}", Condition { name: "About" }), "About");
+}
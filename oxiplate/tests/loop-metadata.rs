@@ -0,0 +1,199 @@
+use oxiplate::prelude::*;
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "
+{%- for (loop, value) in &values | loop -%}
+    {{ value }}: {{ loop.revindex1().unwrap_or_default() }}/{{ loop.length().unwrap_or_default() }}
+    {%- if let Some(0) = loop.revindex0() %} (last){% endif %}
+{% endfor %}"
+)]
+struct Length {
+    values: Vec<usize>,
+}
+
+#[test]
+fn length() {
+    let data = Length {
+        values: vec![19, 89, 42],
+    };
+
+    assert_eq!(format!("{data}"), "19: 3/3\n89: 2/3\n42: 1/3 (last)\n");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "
+{%- for (loop, value) in values.iter().filter(|value| **value > 20) | loop -%}
+    {{ value }}{% if loop.length().is_none() %}?{% endif %}
+{% endfor %}"
+)]
+struct UnknownLength {
+    values: Vec<usize>,
+}
+
+#[test]
+fn unknown_length() {
+    let data = UnknownLength {
+        values: vec![19, 89, 42],
+    };
+
+    assert_eq!(format!("{data}"), "89?\n42?\n");
+}
+
+/// Iterator that claims to have fewer items than it yields.
+struct WrongSizeHint(usize);
+
+impl Iterator for WrongSizeHint {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0 = self.0.checked_sub(1)?;
+        Some(self.0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "
+{%- for (loop, value) in WrongSizeHint(count) | loop -%}
+    {{ value }}: {{ loop.revindex0().unwrap_or_default() }} {{ loop.revindex1().unwrap_or_default() }}
+{% endfor %}"
+)]
+struct WrongLength {
+    count: usize,
+}
+
+#[test]
+fn wrong_length() {
+    let data = WrongLength { count: 3 };
+
+    assert_eq!(format!("{data}"), "2: 0 1\n1: 0 0\n0: 0 0\n");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"
+{%- for (loop, value) in &values | loop -%}
+    <li class="{{ attr: loop.cycle(["odd", "even"]) }}">{{ value }}</li>
+{% endfor %}"#
+)]
+struct Cycle {
+    values: Vec<usize>,
+}
+
+#[test]
+fn cycle() {
+    let data = Cycle {
+        values: vec![19, 89, 42],
+    };
+
+    assert_eq!(
+        format!("{data}"),
+        r#"<li class="odd">19</li>
+<li class="even">89</li>
+<li class="odd">42</li>
+"#
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "
+{%- for (outer, row) in &rows | loop -%}
+    {{ outer.depth1 }}:
+    {%- for (inner, cell) in outer.nested(row) %} {{ inner.depth1 }}.{{ inner.index1 }}={{ cell }}{% endfor %}
+{% endfor %}"
+)]
+struct Depth {
+    rows: Vec<Vec<usize>>,
+}
+
+#[test]
+fn depth() {
+    let data = Depth {
+        rows: vec![vec![19, 89], vec![42]],
+    };
+
+    assert_eq!(format!("{data}"), "1: 2.1=19 2.2=89\n1: 2.1=42\n");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "
+{%- for (loop, value) in &values | loop_neighbors -%}
+    {{ loop.previous().map_or(0, |previous| **previous) }} < {{ value }} < {{ loop.next().map_or(0, |next| **next) }}
+{% endfor %}"
+)]
+struct Neighbors {
+    values: Vec<usize>,
+}
+
+#[test]
+fn neighbors() {
+    let data = Neighbors {
+        values: vec![19, 89, 42],
+    };
+
+    assert_eq!(
+        format!("{data}"),
+        "0 < 19 < 89\n19 < 89 < 42\n89 < 42 < 0\n"
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "
+{%- for (loop, name) in &names | loop_neighbors -%}
+    {% if loop.changed(&name, |name| name.chars().next()) %}# {{ name.chars().next().unwrap_or_default() }}
+{% endif -%}
+    {{ name }}
+{% endfor %}"
+)]
+struct Changed {
+    names: Vec<&'static str>,
+}
+
+#[test]
+fn changed() {
+    let data = Changed {
+        names: vec!["Ada", "Alan", "Grace"],
+    };
+
+    assert_eq!(format!("{data}"), "# A\nAda\nAlan\n# G\nGrace\n");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "
+{%- for (loop, value) in values.iter().copied() | loop if value > 20 -%}
+    {{ loop.index1 }}: {{ value }}{% if loop.is_last %} (last){% endif %}
+{% endfor %}"
+)]
+struct Filtered {
+    values: Vec<usize>,
+}
+
+#[test]
+fn filtered() {
+    let data = Filtered {
+        values: vec![89, 19, 42, 4],
+    };
+
+    assert_eq!(format!("{data}"), "1: 89\n2: 42 (last)\n");
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "
+{%- for (loop, value) in values.iter().copied() | loop_neighbors if value > 20 -%}
+    {{ value }}{% if let Some(next) = loop.next() %} -> {{ next }}{% endif %}
+{% endfor %}"
+)]
+struct FilteredNeighbors {
+    values: Vec<usize>,
+}
+
+#[test]
+fn filtered_neighbors() {
+    let data = FilteredNeighbors {
+        values: vec![89, 19, 42, 4],
+    };
+
+    assert_eq!(format!("{data}"), "89 -> 42\n42\n");
+}
@@ -0,0 +1,31 @@
+#![no_std]
+
+extern crate alloc;
+
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"
+{%- macro link(label, href) -%}
+    <a href="{{ attr: href }}">{{ label }}</a>
+{%- endmacro -%}
+{{ link(name, url) }}
+{% call link("<Home>", "/") %}"#)]
+struct Escaping {
+    name: &'static str,
+    url: &'static str,
+}
+
+#[test]
+fn escaping() {
+    let data = Escaping {
+        name: "Tom & Jerry",
+        url: r#"/search?q="tom""#,
+    };
+
+    assert_eq!(
+        data.render().unwrap(),
+        r#"<a href="/search?q=&#34;tom&#34;">Tom &amp; Jerry</a>
+<a href="/">&lt;Home></a>"#
+    );
+}
@@ -0,0 +1,53 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate(name = "html", path = "welcome.html.oxip")]
+#[oxiplate(name = "text", path = "welcome.txt.oxip")]
+struct Welcome {
+    name: &'static str,
+}
+
+#[test]
+fn named_paths() -> Result<(), ::core::fmt::Error> {
+    let data = Welcome { name: "<Sam>" };
+
+    assert_eq!(data.render_html()?, "<h1>Welcome, &lt;Sam>!</h1>\n");
+    assert_eq!(data.render_text()?, "Welcome, <Sam>!\n");
+
+    Ok(())
+}
+
+#[test]
+fn render_into() -> Result<(), ::core::fmt::Error> {
+    let data = Welcome { name: "Sam" };
+
+    let mut string = String::new();
+    data.render_text_into(&mut string)?;
+    data.render_html_into(&mut string)?;
+    assert_eq!(string, "Welcome, Sam!\n<h1>Welcome, Sam!</h1>\n");
+
+    Ok(())
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "<p>{{ name }}</p>")]
+#[oxiplate_inline(name = "json", json: r#"{"name":"{{ name }}"}"#)]
+struct Unnamed {
+    name: &'static str,
+}
+
+#[test]
+fn unnamed_and_named() -> Result<(), ::core::fmt::Error> {
+    let data = Unnamed { name: r#"<"Sam">"# };
+
+    assert_eq!(data.render()?, r#"<p>&lt;"Sam"></p>"#);
+    assert_eq!(data.render_json()?, r#"{"name":"<\"Sam\">"}"#);
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::vec::Vec;
+
+use oxiplate::{Oxiplate, Render};
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"<a href="{{ attr: url }}">{{ name }}</a>"#)]
+struct Link {
+    name: &'static str,
+    url: &'static str,
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "<nav>{{ home }}</nav>")]
+struct Nav {
+    home: Link,
+}
+
+#[test]
+fn same_group() {
+    let data = Nav {
+        home: Link {
+            name: "Home & Garden",
+            url: "/home?a&b",
+        },
+    };
+
+    assert_eq!(
+        data.render().unwrap(),
+        r#"<nav><a href="/home?a&amp;b">Home &amp; Garden</a></nav>"#
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "<ul>{% for link in &links %}<li>{{ link }}{% endfor %}</ul>")]
+struct Menu {
+    links: Vec<Link>,
+}
+
+#[test]
+fn reference() {
+    let data = Menu {
+        links: alloc::vec![
+            Link {
+                name: "Home",
+                url: "/",
+            },
+            Link {
+                name: "About",
+                url: "/about/",
+            },
+        ],
+    };
+
+    assert_eq!(
+        format!("{data}"),
+        r#"<ul><li><a href="/">Home</a><li><a href="/about/">About</a></ul>"#
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: r#"<pre>{{ text: home }}</pre><p title="{{ attr: home }}"></p>"#)]
+struct SpecifiedEscaper {
+    home: Link,
+}
+
+#[test]
+fn specified_escaper() {
+    let data = SpecifiedEscaper {
+        home: Link {
+            name: "Home",
+            url: "/",
+        },
+    };
+
+    assert_eq!(
+        format!("{data}"),
+        r#"<pre>&lt;a href="/">Home&lt;/a></pre><p title="<a href=&#34;/&#34;>Home</a>"></p>"#
+    );
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(json: r#"{"name": "{{ name }}"}"#)]
+struct Json {
+    name: &'static str,
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "<code>{{ data }}</code>")]
+struct DifferentGroup {
+    data: Json,
+}
+
+#[test]
+fn different_group() {
+    let data = DifferentGroup {
+        data: Json { name: "<Sam>" },
+    };
+
+    assert_eq!(format!("{data}"), r#"<code>{"name": "&lt;Sam>"}</code>"#);
+}
@@ -0,0 +1,51 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+
+use oxiplate::prelude::*;
+use oxiplate::render;
+
+#[test]
+fn arguments() -> Result<(), ::core::fmt::Error> {
+    let user = ("<Sam>", 19);
+    let template = render!(html: "Hello {{ name }}! You have {{ count }} messages.", name = user.0, count = user.1);
+
+    assert_eq!(template.render()?, "Hello &lt;Sam>! You have 19 messages.");
+
+    Ok(())
+}
+
+#[test]
+fn captured() -> Result<(), ::core::fmt::Error> {
+    let name = "Sam";
+    let items = ["a", "b"];
+
+    assert_eq!(
+        render!(html: "{{ >name | upper() }}:{% for item in items %} {{ item }}{% endfor %}")
+            .render()?,
+        "SAM: a b"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn display() {
+    let name = "world";
+    assert_eq!(
+        format!("{}", render!("Hello {{ raw: name }}!")),
+        "Hello world!"
+    );
+}
+
+#[test]
+fn estimated_length() {
+    fn estimated_length<R: Render>(_template: &R) -> usize {
+        R::ESTIMATED_LENGTH
+    }
+
+    let name = "world";
+    assert_eq!(estimated_length(&render!("Hello {{ raw: name }}!")), 8);
+}
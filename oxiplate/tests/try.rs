@@ -0,0 +1,49 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use oxiplate::prelude::*;
+
+struct User {
+    name: Option<&'static str>,
+}
+
+impl User {
+    fn name(&self) -> Result<&'static str, &'static str> {
+        self.name.ok_or("User has no name")
+    }
+}
+
+#[derive(Oxiplate)]
+#[oxiplate_inline(html: "<p>Hello, {{ user.name()? }}!</p>")]
+struct Greeting {
+    user: User,
+}
+
+#[test]
+fn ok() {
+    assert_eq!(
+        Greeting {
+            user: User {
+                name: Some("<Luna>"),
+            },
+        }
+        .render()
+        .unwrap(),
+        "<p>Hello, &lt;Luna>!</p>"
+    );
+}
+
+#[test]
+fn err() {
+    let greeting = Greeting {
+        user: User { name: None },
+    };
+    assert!(greeting.render().is_err());
+
+    let mut output = String::new();
+    assert!(greeting.render_into(&mut output).is_err());
+    assert_eq!(output, "<p>Hello, ");
+}